@@ -0,0 +1,194 @@
+//! A Binary Tree, with externally supplied token.
+//!
+//! The `BinaryTree` is built upon the nodes of the `TripodTree`, yet never balances itself: its shape is exactly the
+//! shape it was built with, as befits expression trees, Huffman codes, or decision trees.
+//!
+//! Like the `TripodTree`, each element in the tree is indexed by a number in [0, N), where N is the number of elements,
+//! according to the in-order traversal of the tree.
+
+mod cursor;
+
+pub use cursor::CursorMut;
+pub use crate::tripod_tree::{Cursor, Iter, Side};
+
+use ghost_cell::GhostToken;
+
+use crate::{
+    invariant::InvariantError,
+    tripod_tree::{TripodTree, Unbalanced},
+};
+
+/// A safe implementation of a binary tree, whose shape is entirely controlled by its user.
+///
+/// Each node contains 1 element as well as 4 pointers: up, left, right, and the tripod pointer.
+pub struct BinaryTree<'brand, T> {
+    tree: TripodTree<'brand, T, Unbalanced>,
+}
+
+impl<'brand, T> BinaryTree<'brand, T> {
+    /// Creates a new, empty, instance.
+    pub const fn new() -> Self { Self { tree: TripodTree::new(), } }
+
+    /// Creates a new instance, with a single value.
+    pub fn singleton(value: T, token: &mut GhostToken<'brand>) -> Self {
+        Self { tree: TripodTree::singleton(value, token), }
+    }
+
+    /// Creates a new instance, with `value` at the root, and `left` and `right` as its left and right sub-trees.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(1).
+    /// -   Space: O(1).
+    ///
+    /// A single memory allocation occurs, for `value`.
+    pub fn from_parts(left: Self, value: T, right: Self, token: &mut GhostToken<'brand>) -> Self {
+        let mut result = Self::singleton(value, token);
+
+        {
+            let mut cursor = result.cursor_mut(token);
+            cursor.set_child(Side::Left, left);
+            cursor.set_child(Side::Right, right);
+        }
+
+        result
+    }
+
+    /// Splits the tree into its left sub-tree, the value at its root, and its right sub-tree, if not empty.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(1).
+    /// -   Space: O(1).
+    ///
+    /// A single memory deallocation occurs, for the root.
+    pub fn into_parts(self, token: &mut GhostToken<'brand>) -> Option<(Self, T, Self)> {
+        let mut tree = self;
+
+        let (left, right, value) = {
+            let mut cursor = tree.cursor_mut(token);
+
+            let left = cursor.take_child(Side::Left);
+            let right = cursor.take_child(Side::Right);
+
+            (left, right, cursor.remove_leaf()?)
+        };
+
+        Some((left, value, right))
+    }
+
+    /// Creates an iterator over the entire tree, from front to back, that is in-order.
+    ///
+    /// #   Complexity
+    ///
+    /// The complexity of this method itself is O(1).
+    ///
+    /// The complexity of calling `next` on the resulting iterator is O(D) in the depth of the tree.
+    pub fn iter<'a>(&'a self, token: &'a GhostToken<'brand>) -> Iter<'a, 'brand, T> { self.tree.iter(token) }
+
+    /// Creates a cursor pointing to the root element.
+    pub fn cursor<'a>(&'a self, token: &'a GhostToken<'brand>) -> Cursor<'a, 'brand, T> { self.tree.cursor(token) }
+
+    /// Creates a mutable cursor pointing to the root element.
+    pub fn cursor_mut<'a>(&'a mut self, token: &'a mut GhostToken<'brand>) -> CursorMut<'a, 'brand, T> {
+        CursorMut::new(token, self)
+    }
+
+    /// Returns whether the tree is empty, or not.
+    pub fn is_empty(&self) -> bool { self.tree.is_empty() }
+
+    /// Returns the number of elements in the tree.
+    pub fn len(&self, token: &GhostToken<'brand>) -> usize { self.tree.len(token) }
+
+    /// Clears the tree of all elements.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(N) in the number of elements.
+    /// -   Space: O(1).
+    pub fn clear(&mut self, token: &mut GhostToken<'brand>) { self.tree.clear(token) }
+
+    /// Returns a reference to the root element, if any.
+    pub fn root<'a>(&'a self, token: &'a GhostToken<'brand>) -> Option<&'a T> { self.cursor(token).current() }
+
+    /// Checks the structural invariants of the tree, returning the first violation found, if any.
+    ///
+    /// The following invariants are checked, for each node:
+    ///
+    /// -   The size of the node is 1 + the sizes of its left and right sub-trees.
+    /// -   The up pointer of each child points back to the node, and the root has no up pointer.
+    /// -   The tripod of the node is retracted.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(N) in the number of elements.
+    /// -   Space: O(1).
+    pub fn check_invariants(&self, token: &GhostToken<'brand>) -> Result<(), InvariantError> {
+        self.tree.check_invariants(token)
+    }
+}
+
+impl<'brand, T> Default for BinaryTree<'brand, T> {
+    fn default() -> Self { Self::new() }
+}
+
+#[cfg(test)]
+mod tests {
+
+use std::{string::String, vec::Vec};
+
+use super::*;
+
+#[test]
+fn binary_tree_new() {
+    GhostToken::new(|token| {
+        let tree = BinaryTree::<'_, String>::new();
+
+        assert!(tree.is_empty());
+        assert_eq!(0, tree.len(&token));
+        assert_eq!(None, tree.root(&token));
+        assert_eq!(Ok(()), tree.check_invariants(&token));
+    });
+}
+
+#[test]
+fn binary_tree_parts() {
+    GhostToken::new(|mut token| {
+        //  (1 + 2) * 3, which no balanced tree would preserve.
+        let one = BinaryTree::singleton("1", &mut token);
+        let two = BinaryTree::singleton("2", &mut token);
+        let three = BinaryTree::singleton("3", &mut token);
+
+        let sum = BinaryTree::from_parts(one, "+", two, &mut token);
+        let product = BinaryTree::from_parts(sum, "*", BinaryTree::new(), &mut token);
+        let (product, times, empty) = product.into_parts(&mut token).expect("Non-empty");
+
+        assert!(empty.is_empty());
+
+        let mut tree = BinaryTree::from_parts(product, times, three, &mut token);
+
+        assert_eq!(Ok(()), tree.check_invariants(&token));
+        assert_eq!(5, tree.len(&token));
+        assert_eq!(Some(&"*"), tree.root(&token));
+
+        let elements: Vec<_> = tree.iter(&token).copied().collect();
+        assert_eq!(&["1", "+", "2", "*", "3"][..], &elements[..]);
+
+        let mut cursor = tree.cursor(&token);
+        cursor.move_down(Side::Left);
+
+        assert_eq!(Some(&"+"), cursor.current());
+        assert_eq!(0..3, cursor.range());
+
+        cursor.move_down(Side::Right);
+
+        assert_eq!(Some(&"2"), cursor.current());
+        assert_eq!(Some(Side::Right), cursor.side());
+
+        tree.clear(&mut token);
+
+        assert_eq!(None, BinaryTree::<'_, String>::new().into_parts(&mut token).map(|_| ()));
+    });
+}
+
+} // mod tests
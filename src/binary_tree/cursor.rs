@@ -0,0 +1,307 @@
+use core::{
+    mem,
+    ops::Range,
+};
+
+use ghost_cell::GhostToken;
+
+use crate::tripod_tree::{self, Unbalanced};
+
+use super::{BinaryTree, Cursor, Side};
+
+/// A mutable cursor over the BinaryTree.
+///
+/// Unlike the `CursorMut` of the `TripodTree`, no operation of this cursor ever rotates the tree: the shape of the tree
+/// only ever changes through `set_child`, `take_child`, and `remove_leaf`.
+///
+/// The cursor contains a "twilight" non-element between the leaves and the root, see `Cursor`.
+pub struct CursorMut<'a, 'brand, T> {
+    inner: tripod_tree::CursorMut<'a, 'brand, T, Unbalanced>,
+}
+
+//  Constant time cursor navigation.
+impl<'a, 'brand, T> CursorMut<'a, 'brand, T> {
+    /// Creates a new instance pointing to the root element of the tree, if any.
+    pub fn new(token: &'a mut GhostToken<'brand>, tree: &'a mut BinaryTree<'brand, T>) -> Self {
+        Self { inner: tripod_tree::CursorMut::new(token, &mut tree.tree), }
+    }
+
+    /// Returns a read-only cursor pointing to the current element.
+    pub fn as_cursor(&self) -> Cursor<'_, 'brand, T> { self.inner.as_cursor() }
+
+    /// Returns the index of the element pointed to by the cursor in the tree.
+    ///
+    /// If the cursor currently points to the "twilight" non-element, returns None.
+    pub fn index(&self) -> Option<usize> { self.inner.index() }
+
+    /// Returns the range of indices covered by the sub-tree rooted at the element the cursor is pointing at.
+    pub fn range(&self) -> Range<usize> { self.inner.range() }
+
+    /// Returns the side of the current element, relative to its parent, if any.
+    pub fn side(&self) -> Option<Side> { self.as_cursor().side() }
+
+    /// Moves the cursor to the parent element, if any.
+    ///
+    /// If the cursor points to the "twilight" non-element, nothing happens.
+    pub fn move_up(&mut self) { self.inner.move_up() }
+
+    /// Moves the cursor to the child element on the given side, if any.
+    ///
+    /// If the cursor points to the "twilight" non-element, moves to the root instead.
+    pub fn move_down(&mut self, side: Side) { self.inner.move_down(side) }
+
+    /// Moves the cursor to the root, if any.
+    pub fn move_to_root(&mut self) { self.inner.move_to_root() }
+
+    /// Attempts to move the cursor to the parent element, if any.
+    ///
+    /// Returns a reference to the pointed to element, in case of success.
+    ///
+    /// If the cursor points to the "twilight" non-element, or the root, nothing happens and None is returned.
+    pub fn try_move_up(&mut self) -> Option<&mut T> { self.inner.try_move_up() }
+
+    /// Attempts to move the cursor to the child element on the given side, if any.
+    ///
+    /// Returns a reference to the pointed to element, in case of success.
+    ///
+    /// If the cursor points to the "twilight" non-element, or there is no such child, nothing happens and None is
+    /// returned.
+    pub fn try_move_down(&mut self, side: Side) -> Option<&mut T> { self.inner.try_move_down(side) }
+
+    /// Returns a reference to the current element, if any.
+    pub fn current(&mut self) -> Option<&mut T> { self.inner.current() }
+
+    /// Returns a reference to the parent element, if any.
+    pub fn peek_up(&self) -> Option<&T> { self.inner.peek_up() }
+
+    /// Returns a reference to the child element on the given side, if any.
+    pub fn peek_down(&self, side: Side) -> Option<&T> { self.inner.peek_down(side) }
+}
+
+//  In-order cursor navigation, linear in the depth of the tree.
+impl<'a, 'brand, T> CursorMut<'a, 'brand, T> {
+    /// Moves the cursor to the next element, in-order, if any.
+    ///
+    /// If there is no next element, then the cursor moves to the "twilight" non-element, which exists between the root
+    /// and the leaves.
+    ///
+    /// If the cursor is pointing to the "twilight" non-element, then moves to the front element, if any.
+    pub fn move_next(&mut self) { self.inner.move_next() }
+
+    /// Moves the cursor to the previous element, in-order, if any.
+    ///
+    /// If there is no previous element, then the cursor moves to the "twilight" non-element, which exists between the
+    /// root and the leaves.
+    ///
+    /// If the cursor is pointing to the "twilight" non-element, then moves to the back element, if any.
+    pub fn move_prev(&mut self) { self.inner.move_prev() }
+
+    /// Moves the cursor to the element at the given index, in-order.
+    ///
+    /// If there is no such element, then the cursor moves to the "twilight" non-element, which exists between the root
+    /// and the leaves.
+    pub fn move_to(&mut self, at: usize) { self.inner.move_to(at) }
+
+    /// Moves the cursor to the front element, if any.
+    pub fn move_to_front(&mut self) { self.inner.move_to_front() }
+
+    /// Moves the cursor to the back element, if any.
+    pub fn move_to_back(&mut self) { self.inner.move_to_back() }
+}
+
+//  Shape editing cursor operations.
+//
+//  None of the operations below rotate the tree, the shape of the tree is exactly the shape requested.
+impl<'a, 'brand, T> CursorMut<'a, 'brand, T> {
+    /// Replaces the value of the current element, returning the former value.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(1).
+    /// -   Space: O(1).
+    ///
+    /// #   Panics
+    ///
+    /// If the cursor points to the "twilight" non-element.
+    pub fn replace_value(&mut self, value: T) -> T {
+        let current = self.inner.current().expect("Non-twilight - to replace the value of");
+
+        mem::replace(current, value)
+    }
+
+    /// Sets `subtree` as the child on the given `side` of the current element, returning the former child sub-tree,
+    /// possibly empty.
+    ///
+    /// The cursor still points to the current element afterwards.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(D) in the depth of the current element.
+    /// -   Space: O(1).
+    ///
+    /// #   Panics
+    ///
+    /// If `subtree` is not empty, and the cursor points to the "twilight" non-element.
+    pub fn set_child(&mut self, side: Side, subtree: BinaryTree<'brand, T>) -> BinaryTree<'brand, T> {
+        let former = self.take_child(side);
+
+        self.inner.attach_child(side, subtree.tree);
+
+        former
+    }
+
+    /// Takes the child sub-tree on the given `side` of the current element, returning it, possibly empty.
+    ///
+    /// The cursor still points to the current element afterwards.
+    ///
+    /// If the cursor points to the "twilight" non-element, nothing happens and an empty tree is returned.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(D) in the depth of the current element.
+    /// -   Space: O(1).
+    pub fn take_child(&mut self, side: Side) -> BinaryTree<'brand, T> {
+        BinaryTree { tree: self.inner.detach_child(side), }
+    }
+
+    /// Removes the current element, if a leaf, and returns it.
+    ///
+    /// The cursor is moved to the parent of the removed element, if any, and to the "twilight" non-element otherwise.
+    ///
+    /// If the cursor points to the "twilight" non-element, or the current element is not a leaf, nothing happens and
+    /// None is returned.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(D) in the depth of the current element.
+    /// -   Space: O(1).
+    ///
+    /// A single memory deallocation occurs.
+    pub fn remove_leaf(&mut self) -> Option<T> {
+        let index = self.index()?;
+
+        if self.peek_down(Side::Left).is_some() || self.peek_down(Side::Right).is_some() {
+            return None;
+        }
+
+        //  The index of the parent, once the leaf is removed.
+        let parent = match self.side() {
+            Some(Side::Left) => Some(index),
+            Some(Side::Right) => Some(index - 1),
+            None => None,
+        };
+
+        //  Removing a leaf never rotates the tree, as it is `Unbalanced`.
+        let result = self.inner.remove_current();
+
+        match parent {
+            Some(parent) => self.move_to(parent),
+            None => debug_assert!(self.index().is_none()),
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+use std::vec::Vec;
+
+use super::*;
+
+#[test]
+fn cursor_mut_replace_value() {
+    GhostToken::new(|mut token| {
+        let mut tree = BinaryTree::singleton(1, &mut token);
+
+        {
+            let mut cursor = tree.cursor_mut(&mut token);
+
+            assert_eq!(1, cursor.replace_value(2));
+            assert_eq!(Some(&mut 2), cursor.current());
+        }
+
+        tree.clear(&mut token);
+    });
+}
+
+#[test]
+fn cursor_mut_set_take_child() {
+    GhostToken::new(|mut token| {
+        let mut tree = BinaryTree::singleton(0, &mut token);
+        let leaves: Vec<_> = (1..5).map(|i| BinaryTree::singleton(i, &mut token)).collect();
+
+        //  Grow a right vine, which a balanced tree would never tolerate.
+        {
+            let mut cursor = tree.cursor_mut(&mut token);
+
+            for (i, leaf) in (1..5).zip(leaves) {
+                let former = cursor.set_child(Side::Right, leaf);
+                assert!(former.is_empty());
+
+                cursor.move_down(Side::Right);
+                assert_eq!(Some(i), cursor.index());
+            }
+
+            assert_eq!(4..5, cursor.range());
+
+            cursor.move_up();
+            cursor.move_up();
+
+            assert_eq!(Some(&mut 2), cursor.current());
+            assert_eq!(2..5, cursor.range());
+
+            let taken = cursor.take_child(Side::Right);
+            assert_eq!(2..3, cursor.range());
+
+            let former = cursor.set_child(Side::Left, taken);
+            assert!(former.is_empty());
+
+            assert_eq!(Some(4), cursor.index());
+            assert_eq!(2..5, cursor.range());
+            assert_eq!(Some(&3), cursor.peek_down(Side::Left));
+        }
+
+        assert_eq!(Ok(()), tree.check_invariants(&token));
+
+        let elements: Vec<_> = tree.iter(&token).copied().collect();
+        assert_eq!(&[0, 1, 3, 4, 2][..], &elements[..]);
+
+        tree.clear(&mut token);
+    });
+}
+
+#[test]
+fn cursor_mut_remove_leaf() {
+    GhostToken::new(|mut token| {
+        let left = BinaryTree::singleton(0, &mut token);
+        let right = BinaryTree::singleton(2, &mut token);
+        let mut tree = BinaryTree::from_parts(left, 1, right, &mut token);
+
+        {
+            let mut cursor = tree.cursor_mut(&mut token);
+
+            assert_eq!(None, cursor.remove_leaf());
+
+            cursor.move_down(Side::Right);
+
+            assert_eq!(Some(2), cursor.remove_leaf());
+            assert_eq!(Some(&mut 1), cursor.current());
+
+            cursor.move_down(Side::Left);
+
+            assert_eq!(Some(0), cursor.remove_leaf());
+            assert_eq!(Some(&mut 1), cursor.current());
+            assert_eq!(Some(0), cursor.index());
+
+            assert_eq!(Some(1), cursor.remove_leaf());
+            assert_eq!(None, cursor.current());
+            assert_eq!(None, cursor.remove_leaf());
+        }
+
+        assert!(tree.is_empty());
+    });
+}
+
+} // mod tests
@@ -0,0 +1,83 @@
+//! Structural invariants of the collections.
+//!
+//! Each collection exposes a `check_invariants` method, which walks the entire collection and verifies that its
+//! internal structure is consistent, reporting the first violation found.
+//!
+//! When the `paranoid` feature is enabled, the invariants are asserted after every public mutating method, as well as
+//! when dropping a mutable cursor.
+
+use core::fmt;
+
+/// A violation of the structural invariants of a collection.
+///
+/// Where relevant, `index` is the index of the element at which the violation was detected.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum InvariantError {
+    /// The cached number of elements does not match the actual number of elements.
+    Length {
+        /// The cached number of elements.
+        expected: usize,
+        /// The actual number of elements.
+        actual: usize,
+    },
+    /// The head, or tail, of the collection is not the first, or last, element.
+    HeadTail,
+    /// A link is not mirrored by its counterpart, such as `next.prev` or `child.up` not pointing back to the element.
+    Link {
+        /// The index of the element.
+        index: usize,
+    },
+    /// The tripod of the element is deployed, or does not point to the element itself.
+    Tripod {
+        /// The index of the element.
+        index: usize,
+    },
+    /// The cached size of the sub-tree rooted at the element is not 1 + the sizes of its children.
+    Size {
+        /// The index of the element.
+        index: usize,
+        /// The cached size of the sub-tree.
+        expected: usize,
+        /// The actual size of the sub-tree, computed from its children.
+        actual: usize,
+    },
+    /// The cached rank of the element does not match the rank computed by the balancing policy.
+    Rank {
+        /// The index of the element.
+        index: usize,
+        /// The cached rank of the element.
+        expected: u64,
+        /// The actual rank of the element, computed from its children.
+        actual: u64,
+    },
+    /// The children of the element are unbalanced, according to the balancing policy.
+    Balance {
+        /// The index of the element.
+        index: usize,
+        /// The size of the left sub-tree.
+        left: usize,
+        /// The size of the right sub-tree.
+        right: usize,
+    },
+}
+
+impl fmt::Display for InvariantError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            InvariantError::Length { expected, actual } =>
+                write!(f, "length is {}, but {} elements were found", expected, actual),
+            InvariantError::HeadTail =>
+                write!(f, "head or tail is not the first or last element"),
+            InvariantError::Link { index } =>
+                write!(f, "asymmetric link at index {}", index),
+            InvariantError::Tripod { index } =>
+                write!(f, "tripod not retracted at index {}", index),
+            InvariantError::Size { index, expected, actual } =>
+                write!(f, "size is {}, but sub-tree contains {} elements at index {}", expected, actual, index),
+            InvariantError::Rank { index, expected, actual } =>
+                write!(f, "rank is {}, but should be {} at index {}", expected, actual, index),
+            InvariantError::Balance { index, left, right } =>
+                write!(f, "unbalanced children of sizes {} and {} at index {}", left, right, index),
+        }
+    }
+}
@@ -18,6 +18,24 @@
 //  Lints.
 #![deny(missing_docs)]
 
+extern crate alloc;
+
+pub mod binary_tree;
+pub mod invariant;
 pub mod linked_list;
+pub mod splay_tree;
+pub mod tripod_forest;
 pub mod tripod_list;
 pub mod tripod_tree;
+
+#[cfg(feature = "serde")]
+pub mod serialization;
+
+mod rendering;
+
+#[cfg(feature = "testing")]
+pub mod testing;
+
+#[cfg(any(feature = "std", feature = "rayon"))]
+#[macro_use]
+extern crate std;
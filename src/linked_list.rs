@@ -4,19 +4,25 @@
 //! the requirement of supplying the GhostToken externally.
 
 mod iter;
+mod conversion;
 mod cursor;
 
 pub use iter::Iter;
 pub use cursor::Cursor;
 
-#[cfg(feature = "experimental-ghost-cursor")]
 pub use cursor::CursorMut;
 
+use core::{
+    mem,
+    ptr,
+};
+
+use alloc::vec::Vec;
+
 use ghost_cell::{GhostCell, GhostToken};
 use static_rc::StaticRc;
 
-#[cfg(feature = "experimental-ghost-cursor")]
-use core::mem;
+use crate::invariant::InvariantError;
 
 #[cfg(feature = "experimental-ghost-cursor")]
 use ghost_cell::GhostCursor;
@@ -25,12 +31,13 @@ use ghost_cell::GhostCursor;
 ///
 /// The future is now!
 pub struct LinkedList<'brand, T> {
+    length: usize,
     head_tail: Option<(HalfNodePtr<'brand, T>, HalfNodePtr<'brand, T>)>,
 }
 
 impl<'brand, T> LinkedList<'brand, T> {
     /// Creates an empty list.
-    pub const fn new() -> Self { Self { head_tail: None } }
+    pub const fn new() -> Self { Self { length: 0, head_tail: None } }
 
     /// Creates an iterator over self.
     pub fn iter<'a>(&'a self, token: &'a GhostToken<'brand>) -> Iter<'a, 'brand, T> {
@@ -54,10 +61,8 @@ impl<'brand, T> LinkedList<'brand, T> {
     ///
     /// #   Complexity
     ///
-    /// This operation is O(N).
-    ///
-    /// Maintaining a separate length field is incompatible with arbitrary splits at a given cursor position.
-    pub fn len(&self, token: &GhostToken<'brand>) -> usize { self.iter(token).count() }
+    /// This operation is O(1).
+    pub fn len(&self) -> usize { self.length }
 
     /// Clears the list, making it empty.
     ///
@@ -99,7 +104,88 @@ impl<'brand, T> LinkedList<'brand, T> {
     /// Prepends the given element at the front of the list.
     pub fn push_front(&mut self, value: T, token: &mut GhostToken<'brand>) {
         let (one, two) = Self::new_halves(value);
+        self.push_front_halves(one, two, token);
+    }
+
+    /// Removes and returns the first element of the list, if any.
+    pub fn pop_front(&mut self, token: &mut GhostToken<'brand>) -> Option<T> {
+        self.pop_front_halves(token).map(|(one, two)| Self::into_inner(one, two))
+    }
+
+    /// Appends the given element at the back of the list.
+    pub fn push_back(&mut self, value: T, token: &mut GhostToken<'brand>) {
+        let (one, two) = Self::new_halves(value);
+        self.push_back_halves(one, two, token);
+    }
+
+    /// Removes and returns the last element of the list, if any.
+    pub fn pop_back(&mut self, token: &mut GhostToken<'brand>) -> Option<T> {
+        self.pop_back_halves(token).map(|(one, two)| Self::into_inner(one, two))
+    }
 
+    /// Checks the structural invariants of the list, returning the first violation found, if any.
+    ///
+    /// The following invariants are checked:
+    ///
+    /// -   The length of the list is the number of elements reachable from its head.
+    /// -   The head has no previous element, and the tail is the last element reachable from the head.
+    /// -   The previous pointer of the next element of each element points back to the element.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(N) in the number of elements.
+    /// -   Space: O(1).
+    pub fn check_invariants(&self, token: &GhostToken<'brand>) -> Result<(), InvariantError> {
+        let (head, tail) = if let Some((head, tail)) = self.head_tail.as_ref() {
+            (&**head, &**tail)
+        } else if self.length == 0 {
+            return Ok(());
+        } else {
+            return Err(InvariantError::Length { expected: self.length, actual: 0 });
+        };
+
+        if head.borrow(token).prev.is_some() {
+            return Err(InvariantError::HeadTail);
+        }
+
+        let mut node = head;
+        let mut index = 0;
+
+        //  O(N) iterations, each checking a single link.
+        while let Some(next) = node.borrow(token).next.as_deref() {
+            if !matches!(next.borrow(token).prev.as_deref(), Some(prev) if ptr::eq(prev, node)) {
+                return Err(InvariantError::Link { index });
+            }
+
+            node = next;
+            index += 1;
+        }
+
+        if !ptr::eq(node, tail) {
+            return Err(InvariantError::HeadTail);
+        }
+
+        if self.length != index + 1 {
+            return Err(InvariantError::Length { expected: self.length, actual: index + 1 });
+        }
+
+        Ok(())
+    }
+
+    //  Internal; asserts that the invariants hold, when the `paranoid` feature is enabled.
+    #[cfg(feature = "paranoid")]
+    fn assert_invariants(&self, token: &GhostToken<'brand>) {
+        if let Err(error) = self.check_invariants(token) {
+            panic!("LinkedList invariant violated: {}", error);
+        }
+    }
+
+    //  Internal (dummy)
+    #[cfg(not(feature = "paranoid"))]
+    fn assert_invariants(&self, _: &GhostToken<'brand>) {}
+
+    //  Internal; pushes the node, of which both halves are passed, to the front of the list.
+    fn push_front_halves(&mut self, one: HalfNodePtr<'brand, T>, two: HalfNodePtr<'brand, T>, token: &mut GhostToken<'brand>) {
         let head_tail = if let Some((head, tail)) = self.head_tail.take() {
             head.borrow_mut(token).prev = Some(one);
 
@@ -110,31 +196,38 @@ impl<'brand, T> LinkedList<'brand, T> {
             (one, two)
         };
 
+        self.length += 1;
         self.head_tail = Some(head_tail);
+
+        self.assert_invariants(token);
     }
 
-    /// Removes and returns the first element of the list, if any.
-    pub fn pop_front(&mut self, token: &mut GhostToken<'brand>) -> Option<T> {
+    //  Internal; removes the front node of the list, if any, and returns both its halves.
+    fn pop_front_halves(&mut self, token: &mut GhostToken<'brand>) -> Option<(HalfNodePtr<'brand, T>, HalfNodePtr<'brand, T>)> {
         let (head, tail) = self.head_tail.take()?;
 
-        if StaticRc::as_ptr(&head) == StaticRc::as_ptr(&tail) {
-            return Some(Self::into_inner(head, tail));
-        }
+        let halves = if StaticRc::as_ptr(&head) == StaticRc::as_ptr(&tail) {
+            (head, tail)
+        } else {
+            let next = head.borrow_mut(token).next.take()
+                .expect("Non-tail should have a next node");
+            let other_head = next.borrow_mut(token).prev.take()
+                .expect("Non-head should have a previous node");
 
-        let next = head.borrow_mut(token).next.take()
-            .expect("Non-tail should have a next node");
-        let other_head = next.borrow_mut(token).prev.take()
-            .expect("Non-head should have a previous node");
+            self.head_tail = Some((next, tail));
 
-        self.head_tail = Some((next, tail));
+            (head, other_head)
+        };
 
-        Some(Self::into_inner(head, other_head))
-    }
+        self.length -= 1;
 
-    /// Appends the given element at the back of the list.
-    pub fn push_back(&mut self, value: T, token: &mut GhostToken<'brand>) {
-        let (one, two) = Self::new_halves(value);
+        self.assert_invariants(token);
 
+        Some(halves)
+    }
+
+    //  Internal; pushes the node, of which both halves are passed, to the back of the list.
+    fn push_back_halves(&mut self, one: HalfNodePtr<'brand, T>, two: HalfNodePtr<'brand, T>, token: &mut GhostToken<'brand>) {
         let head_tail = if let Some((head, tail)) = self.head_tail.take() {
             tail.borrow_mut(token).next = Some(one);
 
@@ -145,25 +238,41 @@ impl<'brand, T> LinkedList<'brand, T> {
             (one, two)
         };
 
+        self.length += 1;
         self.head_tail = Some(head_tail);
+
+        self.assert_invariants(token);
     }
 
-    /// Removes and returns the last element of the list, if any.
-    pub fn pop_back(&mut self, token: &mut GhostToken<'brand>) -> Option<T> {
+    //  Internal; removes the back node of the list, if any, and returns both its halves.
+    fn pop_back_halves(&mut self, token: &mut GhostToken<'brand>) -> Option<(HalfNodePtr<'brand, T>, HalfNodePtr<'brand, T>)> {
         let (head, tail) = self.head_tail.take()?;
 
-        if StaticRc::as_ptr(&head) == StaticRc::as_ptr(&tail) {
-            return Some(Self::into_inner(head, tail));
-        }
+        let halves = if StaticRc::as_ptr(&head) == StaticRc::as_ptr(&tail) {
+            (head, tail)
+        } else {
+            let prev = tail.borrow_mut(token).prev.take()
+                .expect("Non-head should have a previous node");
+            let other_tail = prev.borrow_mut(token).next.take()
+                .expect("Non-tail should have a next node");
+
+            self.head_tail = Some((head, prev));
+
+            (tail, other_tail)
+        };
+
+        self.length -= 1;
+
+        self.assert_invariants(token);
 
-        let prev = tail.borrow_mut(token).prev.take()
-            .expect("Non-head should have a previous node");
-        let other_tail = prev.borrow_mut(token).next.take()
-            .expect("Non-tail should have a next node");
+        Some(halves)
+    }
 
-        self.head_tail = Some((head, prev));
+    //  Internal; returns a reference to the second element, if any.
+    fn peek_second<'a>(&'a self, token: &'a GhostToken<'brand>) -> Option<&'a T> {
+        let (head, _) = self.head_tail.as_ref()?;
 
-        Some(Self::into_inner(tail, other_tail))
+        head.borrow(token).next.as_ref().map(|next| &next.borrow(token).value)
     }
 
     fn new_halves(value: T) -> (HalfNodePtr<'brand, T>, HalfNodePtr<'brand, T>) {
@@ -186,47 +295,89 @@ impl<'brand, T> LinkedList<'brand, T> {
     }
 }
 
-#[cfg(feature = "experimental-ghost-cursor")]
-impl<'brand, T> LinkedList<'brand, T> {
-    /// Appends all elements of `other`, in order, to the back of this list.
+impl<'brand, T: Clone> LinkedList<'brand, T> {
+    /// Creates a copy of the list.
     ///
-    /// After this call, `other` is empty
+    /// #   Complexity
+    ///
+    /// -   Time: O(N) in the number of elements.
+    /// -   Space: O(N) in the number of elements.
+    ///
+    /// The list cannot be traversed while new nodes are linked, hence the copies of the elements are first gathered in
+    /// a temporary buffer.
+    pub fn clone_with(&self, token: &mut GhostToken<'brand>) -> Self {
+        let values: Vec<T> = self.iter(token).cloned().collect();
+
+        let mut result = Self::new();
+
+        for value in values {
+            result.push_back(value, token);
+        }
+
+        result
+    }
+
+    /// Turns self into a copy of `source`, reusing the nodes of self where possible.
     ///
     /// #   Complexity
     ///
-    /// This operation is O(1) in the number of elements.
+    /// -   Time: O(N + M) in the number of elements of `source` and self, respectively.
+    /// -   Space: O(N) in the number of elements.
     ///
-    /// No memory allocation or deallocation occurs.
-    pub fn append(&mut self, other: &mut Self, token: &mut GhostToken<'brand>) {
-        let other_ht = if let Some(other_ht) = other.head_tail.take() {
-            other_ht
-        } else {
-            return;
-        };
+    /// The copies of the elements are first gathered in a temporary buffer, as for `clone_with`.
+    pub fn clone_from_with(&mut self, source: &Self, token: &mut GhostToken<'brand>) {
+        let mut values = source.iter(token).cloned().collect::<Vec<_>>().into_iter();
 
-        if let Some(self_ht) = self.head_tail.take() {
-            let (new_head, mid_tail) = self_ht;
-            let (mid_head, new_tail) = other_ht;
+        let mut reused = 0;
 
-            mid_tail.borrow_mut(token).next = Some(mid_head);
+        {
+            let mut cursor = self.cursor_front_mut(token);
 
-            let previous = static_rc::lift_with_mut(Some(mid_tail), token, |mid_tail: &Option<HalfNodePtr<'brand, T>>, token| {
-                let mut cursor = GhostCursor::new(token, Some(mid_tail.as_ref().unwrap()));
+            while let Some(current) = cursor.current() {
+                if let Some(value) = values.next() {
+                    *current = value;
+                    reused += 1;
+                } else {
+                    break;
+                }
 
-                cursor.move_mut(|mid_tail| mid_tail.next.as_ref().map(core::borrow::Borrow::borrow))
-                    .expect("mid_tail.next was just set!");
+                cursor.move_next();
+            }
+        }
 
-                let mid_head = cursor.into_inner().expect("mid_head was just computed!");
+        for _ in reused..self.len() {
+            self.pop_back(token);
+        }
 
-                &mut mid_head.prev
-            });
+        for value in values {
+            self.push_back(value, token);
+        }
+    }
+}
 
-            debug_assert!(previous.is_none(), "mid_head should not have had any previous!");
+impl<'brand, T> LinkedList<'brand, T> {
+    /// Appends all elements of `other`, in order, to the back of this list.
+    ///
+    /// After this call, `other` is empty
+    ///
+    /// #   Complexity
+    ///
+    /// With the `experimental-ghost-cursor` feature, this operation is O(1) in the number of elements. Otherwise, it is
+    /// O(min(N, M)), where N and M are the number of elements of self and `other`, respectively.
+    ///
+    /// No memory allocation or deallocation occurs.
+    pub fn append(&mut self, other: &mut Self, token: &mut GhostToken<'brand>) {
+        if other.is_empty() {
+            return;
+        }
 
-            self.head_tail = Some((new_head, new_tail));
+        if self.is_empty() {
+            mem::swap(self, other);
         } else {
-            self.head_tail = Some(other_ht);
+            self.link_back(other, token);
         }
+
+        self.assert_invariants(token);
     }
 
     /// Prepends all elements of `other`, in order, to the front of this list.
@@ -235,7 +386,8 @@ impl<'brand, T> LinkedList<'brand, T> {
     ///
     /// #   Complexity
     ///
-    /// This operation is O(1) in the number of elements.
+    /// With the `experimental-ghost-cursor` feature, this operation is O(1) in the number of elements. Otherwise, it is
+    /// O(min(N, M)), where N and M are the number of elements of self and `other`, respectively.
     ///
     /// No memory allocation or deallocation occurs.
     pub fn prepend(&mut self, other: &mut Self, token: &mut GhostToken<'brand>) {
@@ -259,24 +411,20 @@ impl<'brand, T> LinkedList<'brand, T> {
     ///
     /// #   Complexity
     ///
-    /// This operations is O(min(`at`, N)), where N is the number of elements.
+    /// This operations is O(min(`at`, N - `at`)), where N is the number of elements.
     ///
     /// No memory allocation or deallocation occurs.
     pub fn split_off(&mut self, at: usize, token: &mut GhostToken<'brand>) -> Option<Self> {
-        //  This is not the most optimal implementation, but it works, and respects the promised algorithmic complexity.
-        let mut head = LinkedList::new();
-
-        for _ in 0..at {
-            let element = if let Some(element) = self.pop_front(token) {
-                element
-            } else {
-                //  Restore popped elements, and pretend that nothing happened.
-                self.prepend(&mut head, token);
-                return None;
-            };
-            head.push_back(element, token);
+        if at > self.length {
+            return None;
+        }
+
+        if at == self.length {
+            return Some(Self::new());
         }
 
+        let mut head = self.cursor_at_mut(at, token).split_before();
+
         mem::swap(self, &mut head);
 
         Some(head)
@@ -286,32 +434,95 @@ impl<'brand, T> LinkedList<'brand, T> {
     ///
     /// #   Complexity
     ///
-    /// This operations is O(min(`at`, N)), where N is the number of elements.
+    /// This operations is O(min(`at`, N - `at`)), where N is the number of elements.
     ///
     /// No memory allocation occurs, and a single memory deallocation occurs.
     pub fn remove(&mut self, at: usize, token: &mut GhostToken<'brand>) -> Option<T> {
-        //  This is not the most optimal implementation, but it works, and respects the promised algorithmic complexity.
-        let mut head = LinkedList::new();
-
-        for _ in 0..at {
-            let element = if let Some(element) = self.pop_front(token) {
-                element
-            } else {
-                //  Restore popped elements, and pretend that nothing happened.
-                self.prepend(&mut head, token);
-                return None;
-            };
-            head.push_back(element, token);
+        if at >= self.length {
+            return None;
         }
 
-        let result = self.pop_front(token);
+        self.cursor_at_mut(at, token).remove_current()
+    }
 
-        //  Restore popped elements!
-        self.prepend(&mut head, token);
+    //  Internal; returns a cursor pointing to the element at index `at`, walking from the nearer end.
+    //
+    //  Complexity: Time O(min(at, N - at)), Space O(1).
+    fn cursor_at_mut<'a>(&'a mut self, at: usize, token: &'a mut GhostToken<'brand>) -> CursorMut<'a, 'brand, T> {
+        debug_assert!(at < self.length);
 
-        result
+        let distance_from_back = self.length - 1 - at;
+
+        if at <= distance_from_back {
+            let mut cursor = self.cursor_front_mut(token);
+
+            for _ in 0..at {
+                cursor.move_next();
+            }
+
+            cursor
+        } else {
+            let mut cursor = self.cursor_back_mut(token);
+
+            for _ in 0..distance_from_back {
+                cursor.move_prev();
+            }
+
+            cursor
+        }
     }
+}
 
+//  Junction of two lists.
+impl<'brand, T> LinkedList<'brand, T> {
+    //  Internal; links the elements of `other`, in order, after those of self. Both lists must be non-empty.
+    //
+    //  Complexity: Time O(1), Space O(1).
+    #[cfg(feature = "experimental-ghost-cursor")]
+    fn link_back(&mut self, other: &mut Self, token: &mut GhostToken<'brand>) {
+        let (new_head, mid_tail) = self.head_tail.take().expect("Non-empty self");
+        let (mid_head, new_tail) = other.head_tail.take().expect("Non-empty other");
+
+        self.length += mem::replace(&mut other.length, 0);
+
+        mid_tail.borrow_mut(token).next = Some(mid_head);
+
+        let previous = static_rc::lift_with_mut(Some(mid_tail), token, |mid_tail: &Option<HalfNodePtr<'brand, T>>, token| {
+            let mut cursor = GhostCursor::new(token, Some(mid_tail.as_ref().unwrap()));
+
+            cursor.move_mut(|mid_tail| mid_tail.next.as_ref().map(core::borrow::Borrow::borrow))
+                .expect("mid_tail.next was just set!");
+
+            let mid_head = cursor.into_inner().expect("mid_head was just computed!");
+
+            &mut mid_head.prev
+        });
+
+        debug_assert!(previous.is_none(), "mid_head should not have had any previous!");
+
+        self.head_tail = Some((new_head, new_tail));
+    }
+
+    //  Internal; links the elements of `other`, in order, after those of self. Both lists must be non-empty.
+    //
+    //  Once either node at the junction holds the only remaining pointer to the other, the other can no longer be
+    //  borrowed mutably without `GhostCursor`, hence the nodes of the shorter list are moved over one at a time instead.
+    //
+    //  Complexity: Time O(min(N, M)), Space O(1).
+    #[cfg(not(feature = "experimental-ghost-cursor"))]
+    fn link_back(&mut self, other: &mut Self, token: &mut GhostToken<'brand>) {
+        if other.length <= self.length {
+            while let Some((one, two)) = other.pop_front_halves(token) {
+                self.push_back_halves(one, two, token);
+            }
+        } else {
+            while let Some((one, two)) = self.pop_back_halves(token) {
+                other.push_front_halves(one, two, token);
+            }
+
+            mem::swap(self, other);
+        }
+    }
 }
 
 impl<'brand, T> Default for LinkedList<'brand, T> {
@@ -335,10 +546,180 @@ type FullNodePtr<'brand, T> = StaticRc<GhostNode<'brand, T>, 2, 2>;
 #[cfg(test)]
 mod tests {
 
-use std::panic::{self, AssertUnwindSafe};
+use std::{
+    mem,
+    panic::{self, AssertUnwindSafe},
+};
 
 use super::*;
 
+#[test]
+fn list_clone_with() {
+    for length in 0..4 {
+        with_list((0..length).collect(), |token, list| {
+            let mut clone = list.clone_with(token);
+
+            let invariants = clone.check_invariants(token);
+            let cloned: Vec<i32> = clone.iter(token).copied().collect();
+
+            clone.clear(token);
+
+            assert_eq!(Ok(()), invariants);
+            assert_eq!((0..length).collect::<Vec<_>>(), cloned);
+        });
+    }
+}
+
+#[test]
+fn list_clone_from_with() {
+    for target in 0..4 {
+        for source in 0..4 {
+            with_list((10..10 + target).collect(), |token, list| {
+                let mut source_list = LinkedList::new();
+
+                for value in 0..source {
+                    source_list.push_back(value, token);
+                }
+
+                list.clone_from_with(&source_list, token);
+
+                source_list.clear(token);
+
+                assert_eq!(Ok(()), list.check_invariants(token));
+                assert_eq!((0..source).collect::<Vec<_>>(), list.iter(token).copied().collect::<Vec<_>>());
+            });
+        }
+    }
+}
+
+#[test]
+fn list_check_invariants() {
+    with_list(Vec::<String>::new(), |token, list| {
+        assert_eq!(Ok(()), list.check_invariants(token));
+    });
+
+    with_list(vec!["0".to_string(), "1".to_string(), "2".to_string()], |token, list| {
+        assert_eq!(Ok(()), list.check_invariants(token));
+    });
+}
+
+#[test]
+fn list_check_invariants_head_tail() {
+    with_list(vec!["0".to_string(), "1".to_string(), "2".to_string()], |token, list| {
+        let (head, tail) = list.head_tail.as_mut().expect("Non-empty");
+        mem::swap(head, tail);
+
+        assert_eq!(Err(InvariantError::HeadTail), list.check_invariants(token));
+
+        let (head, tail) = list.head_tail.as_mut().expect("Non-empty");
+        mem::swap(head, tail);
+    });
+}
+
+#[test]
+fn list_check_invariants_length() {
+    with_list(vec!["0".to_string(), "1".to_string(), "2".to_string()], |token, list| {
+        list.length = 4;
+
+        assert_eq!(Err(InvariantError::Length { expected: 4, actual: 3 }), list.check_invariants(token));
+
+        list.length = 3;
+    });
+}
+
+#[test]
+fn list_len() {
+    with_list(Vec::<i32>::new(), |token, list| {
+        assert_eq!(0, list.len());
+
+        list.push_back(1, token);
+        list.push_front(0, token);
+
+        assert_eq!(2, list.len());
+
+        list.pop_back(token);
+
+        assert_eq!(1, list.len());
+    });
+}
+
+#[test]
+fn list_append_prepend() {
+    with_list(vec![0, 1], |token, list| {
+        //  Longer other.
+        let mut other = LinkedList::from_vec(vec![2, 3, 4], token);
+
+        list.append(&mut other, token);
+
+        assert_eq!(5, list.len());
+        assert_eq!(0, other.len());
+        assert_eq!(Ok(()), list.check_invariants(token));
+        assert_eq!(Ok(()), other.check_invariants(token));
+
+        //  Shorter other.
+        let mut other = LinkedList::from_vec(vec![5], token);
+
+        list.append(&mut other, token);
+
+        assert!(other.is_empty());
+
+        let mut other = LinkedList::from_vec(vec![-2, -1], token);
+
+        list.prepend(&mut other, token);
+
+        assert!(other.is_empty());
+        assert_eq!(Ok(()), list.check_invariants(token));
+        assert_eq!(vec![-2, -1, 0, 1, 2, 3, 4, 5], list.to_vec(token));
+
+        //  Empty other.
+        list.append(&mut other, token);
+        list.prepend(&mut other, token);
+
+        assert_eq!(8, list.len());
+    });
+}
+
+#[test]
+fn list_split_off() {
+    for at in 0..=4 {
+        with_list((0..4).collect(), |token, list| {
+            let mut tail = list.split_off(at, token).expect("Within bounds");
+
+            let (head_invariants, tail_invariants) = (list.check_invariants(token), tail.check_invariants(token));
+            let (head_len, tail_len) = (list.len(), tail.len());
+            let elements = tail.to_vec(token);
+
+            tail.clear(token);
+
+            assert_eq!((Ok(()), Ok(())), (head_invariants, tail_invariants));
+            assert_eq!((at, 4 - at), (head_len, tail_len));
+            assert_eq!((0..4).collect::<Vec<_>>()[at..], elements[..]);
+        });
+    }
+
+    with_list((0..4).collect::<Vec<i32>>(), |token, list| {
+        assert!(list.split_off(5, token).is_none());
+        assert_eq!(4, list.len());
+    });
+}
+
+#[test]
+fn list_remove() {
+    for at in 0..4 {
+        with_list((0..4).collect(), |token, list| {
+            assert_eq!(Some(at as i32), list.remove(at, token));
+
+            assert_eq!(Ok(()), list.check_invariants(token));
+            assert_eq!(3, list.len());
+        });
+    }
+
+    with_list((0..4).collect::<Vec<i32>>(), |token, list| {
+        assert_eq!(None, list.remove(4, token));
+        assert_eq!(4, list.len());
+    });
+}
+
 pub(crate) fn with_list<T, R, F>(initial: Vec<T>, fun: F) -> R
 where
     F: for<'brand> FnOnce(&mut GhostToken<'brand>, &mut LinkedList<'brand, T>) -> R,
@@ -0,0 +1,185 @@
+use alloc::{collections::VecDeque, vec::Vec};
+
+use ghost_cell::GhostToken;
+
+use crate::{tripod_list::TripodList, tripod_tree::TripodTree};
+
+use super::LinkedList;
+
+//  Conversions.
+//
+//  The nodes of the LinkedList, TripodList and TripodTree all have different layouts, hence no conversion can reuse
+//  the nodes of the source collection: each node is released as soon as its element has been moved into the target.
+impl<'brand, T> LinkedList<'brand, T> {
+    /// Creates a list from the elements of `vec`, in order.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(N) in the number of elements.
+    /// -   Space: O(1).
+    pub fn from_vec(vec: Vec<T>, token: &mut GhostToken<'brand>) -> Self {
+        let mut result = Self::new();
+
+        for value in vec {
+            result.push_back(value, token);
+        }
+
+        result
+    }
+
+    /// Creates a list from the elements of `deque`, in order.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(N) in the number of elements.
+    /// -   Space: O(1).
+    pub fn from_vec_deque(deque: VecDeque<T>, token: &mut GhostToken<'brand>) -> Self {
+        let mut result = Self::new();
+
+        for value in deque {
+            result.push_back(value, token);
+        }
+
+        result
+    }
+
+    /// Creates a list from the elements of `list`, in order.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(N) in the number of elements.
+    /// -   Space: O(1).
+    pub fn from_tripod_list(mut list: TripodList<'brand, T>, token: &mut GhostToken<'brand>) -> Self {
+        let mut result = Self::new();
+
+        while let Some(value) = list.pop_front(token) {
+            result.push_back(value, token);
+        }
+
+        result
+    }
+
+    /// Creates a list from the elements of `tree`, in order.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(N) in the number of elements.
+    /// -   Space: O(1).
+    pub fn from_tree(tree: TripodTree<'brand, T>, token: &mut GhostToken<'brand>) -> Self {
+        let mut result = Self::new();
+
+        tree.drain_with(token, |value, token| result.push_back(value, token));
+
+        result
+    }
+
+    /// Moves the elements of the list, in order, into a `Vec`.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(N) in the number of elements.
+    /// -   Space: O(N) in the number of elements.
+    pub fn into_vec(mut self, token: &mut GhostToken<'brand>) -> Vec<T> {
+        let mut result = Vec::with_capacity(self.len());
+
+        while let Some(value) = self.pop_front(token) {
+            result.push(value);
+        }
+
+        result
+    }
+
+    /// Moves the elements of the list, in order, into a `VecDeque`.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(N) in the number of elements.
+    /// -   Space: O(N) in the number of elements.
+    pub fn into_vec_deque(mut self, token: &mut GhostToken<'brand>) -> VecDeque<T> {
+        let mut result = VecDeque::with_capacity(self.len());
+
+        while let Some(value) = self.pop_front(token) {
+            result.push_back(value);
+        }
+
+        result
+    }
+
+    /// Copies the elements of the list, in order, into a `Vec`.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(N) in the number of elements.
+    /// -   Space: O(N) in the number of elements.
+    pub fn to_vec(&self, token: &GhostToken<'brand>) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.iter(token).cloned().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+use super::super::tests::with_list;
+use super::*;
+
+#[test]
+fn conversion_vec() {
+    for length in 0..6 {
+        with_list(Vec::<i32>::new(), |token, _| {
+            let expected: Vec<i32> = (0..length).collect();
+
+            let list = LinkedList::from_vec(expected.clone(), token);
+
+            assert_eq!(Ok(()), list.check_invariants(token));
+            assert_eq!(expected, list.to_vec(token));
+            assert_eq!(expected, list.into_vec(token));
+        });
+    }
+}
+
+#[test]
+fn conversion_vec_deque() {
+    for length in 0..6 {
+        with_list(Vec::<i32>::new(), |token, _| {
+            let expected: VecDeque<i32> = (0..length).collect();
+
+            let list = LinkedList::from_vec_deque(expected.clone(), token);
+
+            assert_eq!(Ok(()), list.check_invariants(token));
+            assert_eq!(expected, list.into_vec_deque(token));
+        });
+    }
+}
+
+#[test]
+fn conversion_tripod_list() {
+    for length in 0..6 {
+        with_list(Vec::new(), |token, list| {
+            let expected: Vec<i32> = (0..length).collect();
+
+            *list = LinkedList::from_tripod_list(TripodList::from_vec(expected.clone(), token), token);
+
+            assert_eq!(Ok(()), list.check_invariants(token));
+            assert_eq!(expected, list.to_vec(token));
+        });
+    }
+}
+
+#[test]
+fn conversion_tree() {
+    for length in 0..20 {
+        with_list(Vec::new(), |token, list| {
+            let expected: Vec<i32> = (0..length).collect();
+
+            *list = LinkedList::from_tree(TripodTree::from_vec(expected.clone(), token), token);
+
+            assert_eq!(Ok(()), list.check_invariants(token));
+            assert_eq!(expected, list.to_vec(token));
+        });
+    }
+}
+
+} // mod tests
@@ -1,17 +1,18 @@
-use ghost_cell::GhostToken;
-
-use super::{GhostNode, LinkedList};
+use core::mem;
 
-#[cfg(feature = "experimental-ghost-cursor")]
-use ghost_cell::GhostCursor;
+use ghost_cell::GhostToken;
 
-#[cfg(feature = "experimental-ghost-cursor")]
-use super::Node;
+use super::{GhostNode, Iter, LinkedList};
 
 /// A Cursor over the LinkedList.
+///
+/// Cursors index the list in a logically circular way. To accomodate this, there is a "twilight" non-element
+/// represented by `None` between the head and tail of the list.
 pub struct Cursor<'a, 'brand, T> {
     token: &'a GhostToken<'brand>,
+    list: &'a LinkedList<'brand, T>,
     node: Option<&'a GhostNode<'brand, T>>,
+    index: usize,
 }
 
 impl<'a, 'brand, T> Cursor<'a, 'brand, T> {
@@ -19,148 +20,389 @@ impl<'a, 'brand, T> Cursor<'a, 'brand, T> {
     pub fn new_front(token: &'a GhostToken<'brand>, list: &'a LinkedList<'brand, T>) -> Self {
         let node = list.head_tail.as_ref().map(|head_tail| &*head_tail.0);
 
-        Self { token, node, }
+        Self { token, list, node, index: 0, }
     }
 
     /// Creates a new instance pointing to the back element of the list, if any.
     pub fn new_back(token: &'a GhostToken<'brand>, list: &'a LinkedList<'brand, T>) -> Self {
         let node = list.head_tail.as_ref().map(|head_tail| &*head_tail.1);
+        let index = list.len().saturating_sub(1);
 
-        Self { token, node, }
+        Self { token, list, node, index, }
     }
 
-    /// Moves the cursor to the next element, if any.
+    /// Returns a pair (iterator, iterator) in which:
+    ///
+    /// -   The first iterator is an iterator over all the elements before the cursor.
+    /// -   The second iterator is an iterator over all the elements after the cursor.
     ///
-    /// If there is no next element, either because the list is empty, or because the current element is the back
-    /// element, then an error is returned.
-    pub fn move_next(&mut self) -> Result<(), ()> {
-        if let Some(node) = self.peek_next_node() {
-            self.node = Some(node);
-            Ok(())
+    /// If the cursor currently points to the "twilight" non-element, all elements are considered to be before it.
+    pub fn before_after(&self) -> (Iter<'a, 'brand, T>, Iter<'a, 'brand, T>) {
+        if self.node.is_some() {
+            let (head, tail) = self.list.head_tail.as_ref().expect("non-empty, node being non-null");
+
+            let before = self.peek_prev_node().map(|prev| (&**head, prev));
+            let after = self.peek_next_node().map(|next| (next, &**tail));
+
+            (Iter::slice(self.token, before), Iter::slice(self.token, after))
         } else {
-            Err(())
+            (self.list.iter(self.token), Iter::empty(self.token))
         }
     }
 
+    /// Returns the index of the element pointed to by the cursor in the list.
+    ///
+    /// If the cursor currently points to the "twilight" non-element, returns None.
+    pub fn index(&self) -> Option<usize> { self.node.map(|_| self.index) }
+
+    /// Moves the cursor to the next element, if any.
+    ///
+    /// If there is no next element, then the cursor moves to the "twilight" non-element, which exists between the front
+    /// and back element.
+    pub fn move_next(&mut self) {
+        self.index = if self.node.is_some() { self.index + 1 } else { 0 };
+        self.node = self.peek_next_node();
+    }
+
     /// Moves the cursor to the previous element, if any.
     ///
-    /// If there is no previous element, either because the list is empty, or because the current element is the front
-    /// element, then an error is returned.
-    pub fn move_prev(&mut self) -> Result<(), ()> {
-        if let Some(node) = self.peek_prev_node() {
-            self.node = Some(node);
-            Ok(())
-        } else {
-            Err(())
-        }
+    /// If there is no previous element, then the cursor moves to the "twilight" non-element, which exists between the front
+    /// and back element.
+    pub fn move_prev(&mut self) {
+        self.index = if self.node.is_some() { self.index.saturating_sub(1) } else { self.list.len().saturating_sub(1) };
+        self.node = self.peek_prev_node();
     }
 
     /// Returns a reference to the current element, if any.
-    ///
-    /// Unless the list is empty, there should always be a current element.
     pub fn current(&self) -> Option<&'a T> { self.node.map(|node| &node.borrow(self.token).value) }
 
     /// Returns a reference to the next element, if any.
     pub fn peek_next(&self) -> Option<&'a T> { self.peek_next_node().map(|node| &node.borrow(self.token).value) }
 
-    /// Returns a reference to the next element, if any.
+    /// Returns a reference to the previous element, if any.
     pub fn peek_prev(&self) -> Option<&'a T> { self.peek_prev_node().map(|node| &node.borrow(self.token).value) }
 
     //  Internal: returns a reference to the next GhostNode.
     fn peek_next_node(&self) -> Option<&'a GhostNode<'brand, T>> {
-        self.node?.borrow(self.token).next.as_ref().map(|n| &**n)
+        if let Some(node) = self.node {
+            node.borrow(self.token).next.as_ref().map(|n| &**n)
+        } else {
+            self.list.head_tail.as_ref().map(|head_tail| &*head_tail.0)
+        }
     }
 
     //  Internal: returns a reference to the previous GhostNode.
     fn peek_prev_node(&self) -> Option<&'a GhostNode<'brand, T>> {
-        self.node?.borrow(self.token).prev.as_ref().map(|n| &**n)
+        if let Some(node) = self.node {
+            node.borrow(self.token).prev.as_ref().map(|n| &**n)
+        } else {
+            self.list.head_tail.as_ref().map(|head_tail| &*head_tail.1)
+        }
     }
 }
 
+impl<'a, 'brand, T> Clone for Cursor<'a, 'brand, T> {
+    fn clone(&self) -> Self { *self }
+}
+
+impl<'a, 'brand, T> Copy for Cursor<'a, 'brand, T> {}
+
 /// A mutable Cursor over a LinkedList.
-#[cfg(feature = "experimental-ghost-cursor")]
+///
+/// Cursors index the list in a logically circular way. To accomodate this, there is a "twilight" non-element
+/// represented by `None` between the head and tail of the list.
+///
+/// The cursor only owns a single pointer to each of its neighbours, hence it cannot anchor the current node: instead,
+/// the elements before the current one are detached from the list, and re-attached when the cursor is dropped.
+///
+/// Re-attaching is O(1) with the `experimental-ghost-cursor` feature, and O(min(I, N - I)) otherwise, where I is the
+/// index of the cursor, see `LinkedList::append`.
+///
+/// If the cursor points to the "twilight" non-element, all elements are considered to be before it.
 pub struct CursorMut<'a, 'brand, T> {
-    inner: GhostCursor<'a, 'brand, Node<'brand, T>>,
+    //  Only ever None once converted into a Cursor.
+    token_list: Option<(&'a mut GhostToken<'brand>, &'a mut LinkedList<'brand, T>)>,
+    //  The elements before the current one; the current one, if any, is the front element of the list.
+    before: LinkedList<'brand, T>,
 }
 
-#[cfg(feature = "experimental-ghost-cursor")]
 impl<'a, 'brand, T> CursorMut<'a, 'brand, T> {
     /// Creates a new instance pointing to the front element of the list, if any.
-    pub fn new_front(token: &'a mut GhostToken<'brand>, list: &'a LinkedList<'brand, T>) -> Self {
-        let node = list.head_tail.as_ref().map(|head_tail| &*head_tail.0);
-        let inner = GhostCursor::new(token, node);
-
-        Self { inner, }
+    pub fn new_front(token: &'a mut GhostToken<'brand>, list: &'a mut LinkedList<'brand, T>) -> Self {
+        Self { token_list: Some((token, list)), before: LinkedList::new(), }
     }
 
     /// Creates a new instance pointing to the back element of the list, if any.
-    pub fn new_back(token: &'a mut GhostToken<'brand>, list: &'a LinkedList<'brand, T>) -> Self {
-        let node = list.head_tail.as_ref().map(|head_tail| &*head_tail.1);
-        let inner = GhostCursor::new(token, node);
+    pub fn new_back(token: &'a mut GhostToken<'brand>, list: &'a mut LinkedList<'brand, T>) -> Self {
+        let mut before = mem::take(list);
+
+        if let Some((one, two)) = before.pop_back_halves(token) {
+            list.push_front_halves(one, two, token);
+        }
 
-        Self { inner, }
+        Self { token_list: Some((token, list)), before, }
     }
 
     /// Returns a read-only cursor pointing to the current element.
-    pub fn into_cursor(self) -> Cursor<'a, 'brand, T> {
-        let (token, node) = self.inner.into_parts();
+    ///
+    /// #   Complexity
+    ///
+    /// This operation is O(I), where I is the index of the current element.
+    ///
+    /// #   Deviation
+    ///
+    /// The read-only cursor requires the elements before the current one to be re-attached first, after which the
+    /// current element can only be found again by walking from the front of the list.
+    pub fn into_cursor(mut self) -> Cursor<'a, 'brand, T> {
+        let index = self.index();
+
+        let (token, list) = self.token_list.take().expect("Not yet converted");
+
+        list.prepend(&mut self.before, token);
+
+        let token: &'a GhostToken<'brand> = token;
+        let list: &'a LinkedList<'brand, T> = list;
+
+        let mut cursor = Cursor::new_front(token, list);
+
+        match index {
+            Some(index) => (0..index).for_each(|_| cursor.move_next()),
+            None => cursor.move_prev(),
+        }
+
+        cursor
+    }
+
+    /// Returns a pair (iterator, iterator) in which:
+    ///
+    /// -   The first iterator is an iterator over all the elements before the cursor.
+    /// -   The second iterator is an iterator over all the elements after the cursor.
+    ///
+    /// If the cursor currently points to the "twilight" non-element, all elements are considered to be before it.
+    pub fn before_after(&self) -> (Iter<'_, 'brand, T>, Iter<'_, 'brand, T>) {
+        let (token, list) = self.token_list.as_ref().expect("Not yet converted");
+
+        let mut after = list.iter(token);
+        after.next();
+
+        (self.before.iter(token), after)
+    }
+
+    /// Returns the index of the element pointed to by the cursor in the list.
+    ///
+    /// If the cursor currently points to the "twilight" non-element, returns None.
+    pub fn index(&self) -> Option<usize> {
+        let (_, list) = self.token_list.as_ref().expect("Not yet converted");
 
-        Cursor { token, node, }
+        if list.is_empty() { None } else { Some(self.before.len()) }
     }
 
     /// Moves the cursor to the next element, if any.
     ///
-    /// If there is no next element, either because the list is empty, or because the current element is the back
-    /// element, then an error is returned and the cursor is left unmodified.
-    pub fn move_next(&mut self) -> Result<(), ()> {
-        self.inner.move_mut(|node| node.next.as_ref().map(|n| &**n))
+    /// If there is no next element, then the cursor moves to the "twilight" non-element, which exists between the front
+    /// and back element.
+    pub fn move_next(&mut self) {
+        let (token, list, before) = self.parts();
+
+        if let Some((one, two)) = list.pop_front_halves(token) {
+            before.push_back_halves(one, two, token);
+        } else {
+            //  From the "twilight" non-element to the front element, if any.
+            mem::swap(list, before);
+        }
     }
 
     /// Moves the cursor to the previous element, if any.
     ///
-    /// If there is no previous element, either because the list is empty, or because the current element is the front
-    /// element, then an error is returned and the cursor is left unmodified.
-    pub fn move_prev(&mut self) -> Result<(), ()> {
-        self.inner.move_mut(|node| node.prev.as_ref().map(|n| &**n))
+    /// If there is no previous element, then the cursor moves to the "twilight" non-element, which exists between the front
+    /// and back element.
+    pub fn move_prev(&mut self) {
+        let (token, list, before) = self.parts();
+
+        if let Some((one, two)) = before.pop_back_halves(token) {
+            list.push_front_halves(one, two, token);
+        } else {
+            //  From the front element, if any, to the "twilight" non-element.
+            mem::swap(list, before);
+        }
     }
 
     /// Returns a mutable reference to the current element, if any.
-    ///
-    /// Unless the list is empty, there should always be a current element.
-    pub fn current(&mut self) -> Option<&mut T> { self.inner.borrow_mut().map(|node| &mut node.value) }
+    pub fn current(&mut self) -> Option<&mut T> {
+        let (token, list, _) = self.parts();
+
+        list.front_mut(token)
+    }
 
     /// Returns a reference to the next element, if any.
-    ///
-    /// #   Deviation
-    ///
-    /// In safe code, it is not possible to allow mutable access to another node than the one currently visited as this
-    /// other node could be the anchor of the current node to the stack.
     pub fn peek_next(&self) -> Option<&T> {
-        let token = self.inner.token();
+        let (token, list) = self.token_list.as_ref().expect("Not yet converted");
 
-        self.peek_next_node().map(|node| &node.borrow(token).value)
+        if list.is_empty() { self.before.front(token) } else { list.peek_second(token) }
     }
 
     /// Returns a reference to the previous element, if any.
+    pub fn peek_prev(&self) -> Option<&T> {
+        let (token, _) = self.token_list.as_ref().expect("Not yet converted");
+
+        self.before.back(token)
+    }
+
+    /// Inserts a new element in the list after the current one.
     ///
-    /// #   Deviation
+    /// If the cursor does not point to any element, then the new element is inserted at the front of the list.
     ///
-    /// In safe code, it is not possible to allow mutable access to another node than the one currently visited as this
-    /// other node could be the anchor of the current node to the stack.
-    pub fn peek_prev(&self) -> Option<&T> {
-        let token = self.inner.token();
+    /// #   Complexity
+    ///
+    /// This operation is O(1) in the number of elements.
+    ///
+    /// One memory allocation and no deallocation occur.
+    pub fn insert_after(&mut self, item: T) {
+        let (token, list, before) = self.parts();
 
-        self.peek_prev_node().map(|node| &node.borrow(token).value)
+        if let Some((one, two)) = list.pop_front_halves(token) {
+            list.push_front(item, token);
+            list.push_front_halves(one, two, token);
+        } else {
+            before.push_front(item, token);
+        }
     }
 
-    //  Internal: returns a reference to the next GhostNode.
-    fn peek_next_node(&self) -> Option<&GhostNode<'brand, T>> {
-        self.inner.borrow().and_then(|node| node.next.as_ref().map(|n| &**n))
+    /// Inserts a new element in the list before the current one.
+    ///
+    /// If the cursor does not point to any element, then the new element is inserted at the back of the list.
+    ///
+    /// #   Complexity
+    ///
+    /// This operation is O(1) in the number of elements.
+    ///
+    /// One memory allocation and no deallocation occur.
+    pub fn insert_before(&mut self, item: T) {
+        let (token, _, before) = self.parts();
+
+        before.push_back(item, token);
     }
 
-    //  Internal: returns a reference to the previous GhostNode.
-    fn peek_prev_node(&self) -> Option<&GhostNode<'brand, T>> {
-        self.inner.borrow().and_then(|node| node.prev.as_ref().map(|n| &**n))
+    /// Removes the current element from the list, and return it.
+    ///
+    /// If the cursor does not point to any element, then None is returned and the cursor is left unmodified.
+    /// Otherwise, the element is returned and the cursor is moved to the next element, if any.
+    ///
+    /// #   Complexity
+    ///
+    /// This operation is O(1) in the number of elements.
+    ///
+    /// No memory allocation occurs, and a single memory deallocation occurs.
+    pub fn remove_current(&mut self) -> Option<T> {
+        let (token, list, _) = self.parts();
+
+        list.pop_front(token)
+    }
+
+    /// Removes the current element from the list, and return it as a list of its own.
+    ///
+    /// If the cursor does not point to any element, then None is returned and the cursor is left unmodified.
+    /// Otherwise, the element is returned and the cursor is moved to the next element, if any.
+    ///
+    /// #   Complexity
+    ///
+    /// This operation is O(1) in the number of elements.
+    ///
+    /// No memory allocation or deallocation occurs.
+    pub fn remove_current_as_list(&mut self) -> Option<LinkedList<'brand, T>> {
+        let (token, list, _) = self.parts();
+
+        let (one, two) = list.pop_front_halves(token)?;
+
+        let mut result = LinkedList::new();
+        result.push_front_halves(one, two, token);
+
+        Some(result)
+    }
+
+    /// Inserts the elements from the given list after the current one.
+    ///
+    /// If the cursor does not point to any element, then the new elements are inserted at the front of the list.
+    ///
+    /// #   Complexity
+    ///
+    /// This operation has the complexity of `LinkedList::prepend`.
+    ///
+    /// No memory allocation or deallocation occurs.
+    pub fn splice_after(&mut self, other: &mut LinkedList<'brand, T>) {
+        let (token, list, before) = self.parts();
+
+        if let Some((one, two)) = list.pop_front_halves(token) {
+            list.prepend(other, token);
+            list.push_front_halves(one, two, token);
+        } else {
+            before.prepend(other, token);
+        }
+    }
+
+    /// Inserts the elements from the given list before the current one.
+    ///
+    /// If the cursor does not point to any element, then the new elements are inserted at the back of the list.
+    ///
+    /// #   Complexity
+    ///
+    /// This operation has the complexity of `LinkedList::append`.
+    ///
+    /// No memory allocation or deallocation occurs.
+    pub fn splice_before(&mut self, other: &mut LinkedList<'brand, T>) {
+        let (token, _, before) = self.parts();
+
+        before.append(other, token);
+    }
+
+    /// Splits the list into two after the current element.
+    ///
+    /// If the cursor does not point to any element, then the entire contents of the list are moved.
+    ///
+    /// #   Complexity
+    ///
+    /// This operation is O(1) in the number of elements.
+    ///
+    /// No memory allocation or deallocation occurs.
+    pub fn split_after(&mut self) -> LinkedList<'brand, T> {
+        let (token, list, before) = self.parts();
+
+        if let Some((one, two)) = list.pop_front_halves(token) {
+            let result = mem::take(list);
+            list.push_front_halves(one, two, token);
+
+            result
+        } else {
+            mem::take(before)
+        }
+    }
+
+    /// Splits the list into two before the current element.
+    ///
+    /// If the cursor does not point to any element, then the entire contents of the list are moved.
+    ///
+    /// #   Complexity
+    ///
+    /// This operation is O(1) in the number of elements.
+    ///
+    /// No memory allocation or deallocation occurs.
+    pub fn split_before(&mut self) -> LinkedList<'brand, T> {
+        mem::take(&mut self.before)
+    }
+
+    //  Internal; returns the token, the list -- starting at the current element -- and the elements before.
+    fn parts(&mut self) -> (&mut GhostToken<'brand>, &mut LinkedList<'brand, T>, &mut LinkedList<'brand, T>) {
+        let (token, list) = self.token_list.as_mut().expect("Not yet converted");
+
+        (&mut **token, &mut **list, &mut self.before)
+    }
+}
+
+impl<'a, 'brand, T> Drop for CursorMut<'a, 'brand, T> {
+    fn drop(&mut self) {
+        if let Some((token, list)) = self.token_list.take() {
+            list.prepend(&mut self.before, token);
+        }
     }
 }
 
@@ -177,11 +419,30 @@ fn cursor_assert_none<'a, 'brand, T>(cursor: &Cursor<'a, 'brand, T>)
 where
     T: Debug + Eq,
 {
+    assert_eq!(None, cursor.index());
     assert_eq!(None, cursor.current());
     assert_eq!(None, cursor.peek_next());
     assert_eq!(None, cursor.peek_prev());
 }
 
+#[track_caller]
+fn cursor_assert(before: &[&str], current: Option<&str>, after: &[&str], cursor: &Cursor<'_, '_, String>) {
+    assert_eq!(current.map(|_| before.len()), cursor.index());
+    assert_eq!(current, cursor.current().map(String::as_str));
+
+    if current.is_some() {
+        assert_eq!(before.last().copied(), cursor.peek_prev().map(String::as_str));
+        assert_eq!(after.first().copied(), cursor.peek_next().map(String::as_str));
+    }
+
+    let (b, a) = cursor.before_after();
+    let b: Vec<_> = b.map(String::as_str).collect();
+    let a: Vec<_> = a.map(String::as_str).collect();
+
+    assert_eq!(before, &b[..]);
+    assert_eq!(after, &a[..]);
+}
+
 #[test]
 fn cursor_brush_front_empty() {
     //  Test all functions on empty list.
@@ -189,11 +450,15 @@ fn cursor_brush_front_empty() {
         let mut cursor = Cursor::new_front(token, list);
         cursor_assert_none(&cursor);
 
-        assert_eq!(Err(()), cursor.move_next());
+        cursor.move_next();
         cursor_assert_none(&cursor);
 
-        assert_eq!(Err(()), cursor.move_prev());
+        cursor.move_prev();
         cursor_assert_none(&cursor);
+
+        let (before, after) = cursor.before_after();
+        assert_eq!(0, before.count());
+        assert_eq!(0, after.count());
     });
 }
 
@@ -204,10 +469,10 @@ fn cursor_brush_back_empty() {
         let mut cursor = Cursor::new_back(token, list);
         cursor_assert_none(&cursor);
 
-        assert_eq!(Err(()), cursor.move_next());
+        cursor.move_next();
         cursor_assert_none(&cursor);
 
-        assert_eq!(Err(()), cursor.move_prev());
+        cursor.move_prev();
         cursor_assert_none(&cursor);
     });
 }
@@ -218,40 +483,24 @@ fn cursor_brush_move_next() {
 
     with_list(vec, |token, list| {
         let mut cursor = Cursor::new_front(token, list);
+        cursor_assert(&[], Some("0"), &["1", "2", "3"], &cursor);
 
-        assert_eq!(None, cursor.peek_prev().map(String::as_str));
-        assert_eq!(Some("0"), cursor.current().map(String::as_str));
-        assert_eq!(Some("1"), cursor.peek_next().map(String::as_str));
-
-        assert_eq!(Ok(()), cursor.move_next());
-
-        assert_eq!(Some("0"), cursor.peek_prev().map(String::as_str));
-        assert_eq!(Some("1"), cursor.current().map(String::as_str));
-        assert_eq!(Some("2"), cursor.peek_next().map(String::as_str));
+        cursor.move_next();
+        cursor_assert(&["0"], Some("1"), &["2", "3"], &cursor);
 
-        assert_eq!(Ok(()), cursor.move_next());
+        cursor.move_next();
+        cursor_assert(&["0", "1"], Some("2"), &["3"], &cursor);
 
-        assert_eq!(Some("1"), cursor.peek_prev().map(String::as_str));
-        assert_eq!(Some("2"), cursor.current().map(String::as_str));
-        assert_eq!(Some("3"), cursor.peek_next().map(String::as_str));
-
-        assert_eq!(Ok(()), cursor.move_next());
-
-        assert_eq!(Some("2"), cursor.peek_prev().map(String::as_str));
-        assert_eq!(Some("3"), cursor.current().map(String::as_str));
-        assert_eq!(None, cursor.peek_next());
-
-        assert_eq!(Err(()), cursor.move_next());
-
-        assert_eq!(Some("2"), cursor.peek_prev().map(String::as_str));
-        assert_eq!(Some("3"), cursor.current().map(String::as_str));
-        assert_eq!(None, cursor.peek_next());
+        cursor.move_next();
+        cursor_assert(&["0", "1", "2"], Some("3"), &[], &cursor);
 
-        assert_eq!(Err(()), cursor.move_next());
+        cursor.move_next();
+        cursor_assert(&["0", "1", "2", "3"], None, &[], &cursor);
+        assert_eq!(Some("3"), cursor.peek_prev().map(String::as_str));
+        assert_eq!(Some("0"), cursor.peek_next().map(String::as_str));
 
-        assert_eq!(Some("2"), cursor.peek_prev().map(String::as_str));
-        assert_eq!(Some("3"), cursor.current().map(String::as_str));
-        assert_eq!(None, cursor.peek_next());
+        cursor.move_next();
+        cursor_assert(&[], Some("0"), &["1", "2", "3"], &cursor);
     });
 }
 
@@ -261,49 +510,37 @@ fn cursor_brush_move_prev() {
 
     with_list(vec, |token, list| {
         let mut cursor = Cursor::new_back(token, list);
+        cursor_assert(&["0", "1", "2"], Some("3"), &[], &cursor);
 
-        assert_eq!(None, cursor.peek_next().map(String::as_str));
-        assert_eq!(Some("3"), cursor.current().map(String::as_str));
-        assert_eq!(Some("2"), cursor.peek_prev().map(String::as_str));
-
-        assert_eq!(Ok(()), cursor.move_prev());
-
-        assert_eq!(Some("3"), cursor.peek_next().map(String::as_str));
-        assert_eq!(Some("2"), cursor.current().map(String::as_str));
-        assert_eq!(Some("1"), cursor.peek_prev().map(String::as_str));
+        cursor.move_prev();
+        cursor_assert(&["0", "1"], Some("2"), &["3"], &cursor);
 
-        assert_eq!(Ok(()), cursor.move_prev());
+        cursor.move_prev();
+        cursor_assert(&["0"], Some("1"), &["2", "3"], &cursor);
 
-        assert_eq!(Some("2"), cursor.peek_next().map(String::as_str));
-        assert_eq!(Some("1"), cursor.current().map(String::as_str));
-        assert_eq!(Some("0"), cursor.peek_prev().map(String::as_str));
-
-        assert_eq!(Ok(()), cursor.move_prev());
-
-        assert_eq!(Some("1"), cursor.peek_next().map(String::as_str));
-        assert_eq!(Some("0"), cursor.current().map(String::as_str));
-        assert_eq!(None, cursor.peek_prev());
+        cursor.move_prev();
+        cursor_assert(&[], Some("0"), &["1", "2", "3"], &cursor);
 
-        assert_eq!(Err(()), cursor.move_prev());
+        cursor.move_prev();
+        cursor_assert(&["0", "1", "2", "3"], None, &[], &cursor);
+        assert_eq!(Some("3"), cursor.peek_prev().map(String::as_str));
+        assert_eq!(Some("0"), cursor.peek_next().map(String::as_str));
 
-        assert_eq!(Some("1"), cursor.peek_next().map(String::as_str));
-        assert_eq!(Some("0"), cursor.current().map(String::as_str));
-        assert_eq!(None, cursor.peek_prev());
-
-        assert_eq!(Err(()), cursor.move_prev());
-
-        assert_eq!(Some("1"), cursor.peek_next().map(String::as_str));
-        assert_eq!(Some("0"), cursor.current().map(String::as_str));
-        assert_eq!(None, cursor.peek_prev());
+        cursor.move_prev();
+        cursor_assert(&["0", "1", "2"], Some("3"), &[], &cursor);
     });
 }
 
 } // mod cursor_tests
 
-#[cfg(all(test, feature = "experimental-ghost-cursor"))]
+#[cfg(test)]
 mod cursor_mut_tests {
 
-use std::fmt::Debug;
+use std::{
+    fmt::Debug,
+    mem,
+    ops::Range,
+};
 
 use super::super::tests::with_list;
 use super::*;
@@ -313,11 +550,30 @@ fn cursor_mut_assert_none<'a, 'brand, T>(cursor: &mut CursorMut<'a, 'brand, T>)
 where
     T: Debug + Eq,
 {
+    assert_eq!(None, cursor.index());
     assert_eq!(None, cursor.current());
     assert_eq!(None, cursor.peek_next());
     assert_eq!(None, cursor.peek_prev());
 }
 
+#[track_caller]
+fn cursor_mut_assert(before: &[&str], current: Option<&str>, after: &[&str], cursor: &mut CursorMut<'_, '_, String>) {
+    assert_eq!(current.map(|_| before.len()), cursor.index());
+    assert_eq!(current, cursor.current().map(|s| &**s));
+
+    if current.is_some() {
+        assert_eq!(before.last().copied(), cursor.peek_prev().map(String::as_str));
+        assert_eq!(after.first().copied(), cursor.peek_next().map(String::as_str));
+    }
+
+    let (b, a) = cursor.before_after();
+    let b: Vec<_> = b.map(String::as_str).collect();
+    let a: Vec<_> = a.map(String::as_str).collect();
+
+    assert_eq!(before, &b[..]);
+    assert_eq!(after, &a[..]);
+}
+
 #[test]
 fn cursor_mut_brush_front_empty() {
     //  Test all functions on empty list.
@@ -325,10 +581,10 @@ fn cursor_mut_brush_front_empty() {
         let mut cursor = CursorMut::new_front(token, list);
         cursor_mut_assert_none(&mut cursor);
 
-        assert_eq!(Err(()), cursor.move_next());
+        cursor.move_next();
         cursor_mut_assert_none(&mut cursor);
 
-        assert_eq!(Err(()), cursor.move_prev());
+        cursor.move_prev();
         cursor_mut_assert_none(&mut cursor);
     });
 }
@@ -340,10 +596,10 @@ fn cursor_mut_brush_back_empty() {
         let mut cursor = CursorMut::new_back(token, list);
         cursor_mut_assert_none(&mut cursor);
 
-        assert_eq!(Err(()), cursor.move_next());
+        cursor.move_next();
         cursor_mut_assert_none(&mut cursor);
 
-        assert_eq!(Err(()), cursor.move_prev());
+        cursor.move_prev();
         cursor_mut_assert_none(&mut cursor);
     });
 }
@@ -354,83 +610,244 @@ fn cursor_mut_brush_move_next() {
 
     with_list(vec, |token, list| {
         let mut cursor = CursorMut::new_front(token, list);
+        cursor_mut_assert(&[], Some("0"), &["1", "2", "3"], &mut cursor);
 
-        assert_eq!(None, cursor.peek_prev().map(String::as_str));
-        assert_eq!(Some("0"), cursor.current().map(|s| &**s));
-        assert_eq!(Some("1"), cursor.peek_next().map(String::as_str));
+        cursor.move_next();
+        cursor_mut_assert(&["0"], Some("1"), &["2", "3"], &mut cursor);
 
-        assert_eq!(Ok(()), cursor.move_next());
+        cursor.move_next();
+        cursor_mut_assert(&["0", "1"], Some("2"), &["3"], &mut cursor);
 
-        assert_eq!(Some("0"), cursor.peek_prev().map(String::as_str));
+        cursor.move_next();
+        cursor_mut_assert(&["0", "1", "2"], Some("3"), &[], &mut cursor);
+
+        cursor.move_next();
+        cursor_mut_assert(&["0", "1", "2", "3"], None, &[], &mut cursor);
+        assert_eq!(Some("3"), cursor.peek_prev().map(String::as_str));
+        assert_eq!(Some("0"), cursor.peek_next().map(String::as_str));
+
+        cursor.move_next();
+        cursor_mut_assert(&[], Some("0"), &["1", "2", "3"], &mut cursor);
+    });
+}
+
+#[test]
+fn cursor_mut_brush_move_prev() {
+    let vec: Vec<_> = (0..4).map(|n| n.to_string()).collect();
+
+    with_list(vec, |token, list| {
+        let mut cursor = CursorMut::new_back(token, list);
+        cursor_mut_assert(&["0", "1", "2"], Some("3"), &[], &mut cursor);
+
+        cursor.move_prev();
+        cursor_mut_assert(&["0", "1"], Some("2"), &["3"], &mut cursor);
+
+        cursor.move_prev();
+        cursor_mut_assert(&["0"], Some("1"), &["2", "3"], &mut cursor);
+
+        cursor.move_prev();
+        cursor_mut_assert(&[], Some("0"), &["1", "2", "3"], &mut cursor);
+
+        cursor.move_prev();
+        cursor_mut_assert(&["0", "1", "2", "3"], None, &[], &mut cursor);
+        assert_eq!(Some("3"), cursor.peek_prev().map(String::as_str));
+        assert_eq!(Some("0"), cursor.peek_next().map(String::as_str));
+
+        cursor.move_prev();
+        cursor_mut_assert(&["0", "1", "2"], Some("3"), &[], &mut cursor);
+    });
+}
+
+
+#[track_caller]
+fn assert_list<'brand>(expected: &[&str], token: &GhostToken<'brand>, list: &LinkedList<'brand, String>) {
+    assert_eq!(Ok(()), list.check_invariants(token));
+
+    let actual: Vec<&str> = list.iter(token).map(String::as_str).collect();
+    assert_eq!(expected, actual);
+}
+
+fn create(range: Range<i32>) -> Vec<String> { range.map(|n| n.to_string()).collect() }
+
+fn place_cursor<'a, 'brand>(token: &'a mut GhostToken<'brand>, list: &'a mut LinkedList<'brand, String>, at: usize)
+    -> CursorMut<'a, 'brand, String>
+{
+    let mut cursor = CursorMut::new_front(token, list);
+
+    for _ in 0..at {
+        cursor.move_next();
+    }
+
+    cursor
+}
+
+#[test]
+fn cursor_mut_insert() {
+    with_list(create(0..4), |token, list| {
+        let mut cursor = place_cursor(token, list, 1);
+
+        cursor.insert_after("4".to_string());
+        cursor.insert_before("5".to_string());
+
+        assert_eq!(Some("5"), cursor.peek_prev().map(String::as_str));
         assert_eq!(Some("1"), cursor.current().map(|s| &**s));
-        assert_eq!(Some("2"), cursor.peek_next().map(String::as_str));
+        assert_eq!(Some("4"), cursor.peek_next().map(String::as_str));
 
-        assert_eq!(Ok(()), cursor.move_next());
+        mem::drop(cursor);
 
-        assert_eq!(Some("1"), cursor.peek_prev().map(String::as_str));
-        assert_eq!(Some("2"), cursor.current().map(|s| &**s));
-        assert_eq!(Some("3"), cursor.peek_next().map(String::as_str));
+        assert_list(&["0", "5", "1", "4", "2", "3"], token, list);
+    });
 
-        assert_eq!(Ok(()), cursor.move_next());
+    //  Special case: "empty"
+    with_list(create(0..0), |token, list| {
+        let mut cursor = CursorMut::new_front(token, list);
 
-        assert_eq!(Some("2"), cursor.peek_prev().map(String::as_str));
-        assert_eq!(Some("3"), cursor.current().map(|s| &**s));
-        assert_eq!(None, cursor.peek_next());
+        cursor.insert_after("4".to_string());
+        cursor.insert_before("5".to_string());
 
-        assert_eq!(Err(()), cursor.move_next());
+        assert_eq!(None, cursor.current());
+        assert_eq!(Some("5"), cursor.peek_prev().map(String::as_str));
 
-        assert_eq!(Some("2"), cursor.peek_prev().map(String::as_str));
-        assert_eq!(Some("3"), cursor.current().map(|s| &**s));
-        assert_eq!(None, cursor.peek_next());
+        mem::drop(cursor);
+
+        assert_list(&["4", "5"], token, list);
+    });
+}
+
+#[test]
+fn cursor_mut_remove_current() {
+    with_list(create(0..4), |token, list| {
+        let mut cursor = place_cursor(token, list, 1);
+
+        assert_eq!(Some("1".to_string()), cursor.remove_current());
+        assert_eq!(Some("2"), cursor.current().map(|s| &**s));
 
-        assert_eq!(Err(()), cursor.move_next());
+        cursor.move_next();
+        assert_eq!(Some("3".to_string()), cursor.remove_current());
 
+        //  The back element was removed, hence the cursor points to the "twilight" non-element.
+        assert_eq!(None, cursor.index());
+        assert_eq!(None, cursor.current());
+        assert_eq!(None, cursor.remove_current());
         assert_eq!(Some("2"), cursor.peek_prev().map(String::as_str));
-        assert_eq!(Some("3"), cursor.current().map(|s| &**s));
-        assert_eq!(None, cursor.peek_next());
+        assert_eq!(Some("0"), cursor.peek_next().map(String::as_str));
+
+        cursor.move_prev();
+        assert_eq!(Some(1), cursor.index());
+        assert_eq!(Some("2"), cursor.current().map(|s| &**s));
+
+        mem::drop(cursor);
+
+        assert_list(&["0", "2"], token, list);
     });
 }
 
 #[test]
-fn cursor_mut_brush_move_prev() {
-    let vec: Vec<_> = (0..4).map(|n| n.to_string()).collect();
+fn cursor_mut_remove_current_as_list() {
+    with_list(create(0..4), |token, list| {
+        let mut cursor = place_cursor(token, list, 2);
 
-    with_list(vec, |token, list| {
-        let mut cursor = CursorMut::new_back(token, list);
+        let mut removed = cursor.remove_current_as_list().expect("Current element");
 
-        assert_eq!(None, cursor.peek_next().map(String::as_str));
         assert_eq!(Some("3"), cursor.current().map(|s| &**s));
-        assert_eq!(Some("2"), cursor.peek_prev().map(String::as_str));
 
-        assert_eq!(Ok(()), cursor.move_prev());
+        mem::drop(cursor);
 
-        assert_eq!(Some("3"), cursor.peek_next().map(String::as_str));
-        assert_eq!(Some("2"), cursor.current().map(|s| &**s));
-        assert_eq!(Some("1"), cursor.peek_prev().map(String::as_str));
+        assert_list(&["2"], token, &removed);
+        assert_list(&["0", "1", "3"], token, list);
 
-        assert_eq!(Ok(()), cursor.move_prev());
+        removed.clear(token);
+    });
+}
+
+#[test]
+fn cursor_mut_splice() {
+    with_list(create(0..4), |token, list| {
+        let mut after = LinkedList::from_vec(create(4..6), token);
+        let mut before = LinkedList::from_vec(create(6..8), token);
+
+        let mut cursor = place_cursor(token, list, 1);
 
-        assert_eq!(Some("2"), cursor.peek_next().map(String::as_str));
+        cursor.splice_after(&mut after);
+        cursor.splice_before(&mut before);
+
+        assert_eq!(Some("7"), cursor.peek_prev().map(String::as_str));
         assert_eq!(Some("1"), cursor.current().map(|s| &**s));
-        assert_eq!(Some("0"), cursor.peek_prev().map(String::as_str));
+        assert_eq!(Some("4"), cursor.peek_next().map(String::as_str));
 
-        assert_eq!(Ok(()), cursor.move_prev());
+        mem::drop(cursor);
 
-        assert_eq!(Some("1"), cursor.peek_next().map(String::as_str));
-        assert_eq!(Some("0"), cursor.current().map(|s| &**s));
-        assert_eq!(None, cursor.peek_prev());
+        assert!(after.is_empty());
+        assert!(before.is_empty());
+        assert_list(&["0", "6", "7", "1", "4", "5", "2", "3"], token, list);
+    });
 
-        assert_eq!(Err(()), cursor.move_prev());
+    //  Special case: "empty"
+    with_list(create(0..0), |token, list| {
+        let mut after = LinkedList::from_vec(create(4..6), token);
+        let mut before = LinkedList::from_vec(create(6..8), token);
 
-        assert_eq!(Some("1"), cursor.peek_next().map(String::as_str));
-        assert_eq!(Some("0"), cursor.current().map(|s| &**s));
-        assert_eq!(None, cursor.peek_prev());
+        let mut cursor = CursorMut::new_front(token, list);
+
+        cursor.splice_after(&mut after);
+        cursor.splice_before(&mut before);
+
+        assert_eq!(None, cursor.current());
 
-        assert_eq!(Err(()), cursor.move_prev());
+        mem::drop(cursor);
+
+        assert_list(&["4", "5", "6", "7"], token, list);
+    });
+}
+
+#[test]
+fn cursor_mut_split() {
+    with_list(create(0..4), |token, list| {
+        let mut cursor = place_cursor(token, list, 2);
+
+        let mut after = cursor.split_after();
+        let mut before = cursor.split_before();
 
-        assert_eq!(Some("1"), cursor.peek_next().map(String::as_str));
-        assert_eq!(Some("0"), cursor.current().map(|s| &**s));
         assert_eq!(None, cursor.peek_prev());
+        assert_eq!(Some("2"), cursor.current().map(|s| &**s));
+        assert_eq!(None, cursor.peek_next());
+
+        mem::drop(cursor);
+
+        assert_list(&["0", "1"], token, &before);
+        assert_list(&["3"], token, &after);
+        assert_list(&["2"], token, list);
+
+        before.clear(token);
+        after.clear(token);
+    });
+}
+
+#[test]
+fn cursor_mut_into_cursor() {
+    for at in 0..4 {
+        with_list(create(0..4), |token, list| {
+            let mut cursor = place_cursor(token, list, at);
+            cursor.insert_before("4".to_string());
+
+            let cursor = cursor.into_cursor();
+
+            assert_eq!(Some(at + 1), cursor.index());
+            assert_eq!(Some("4"), cursor.peek_prev().map(String::as_str));
+            assert_eq!(Some(at.to_string()), cursor.current().cloned());
+        });
+    }
+
+    //  Special case: no current element.
+    with_list(create(0..2), |token, list| {
+        let mut cursor = place_cursor(token, list, 1);
+        cursor.remove_current();
+
+        let cursor = cursor.into_cursor();
+
+        assert_eq!(None, cursor.index());
+        assert_eq!(None, cursor.current());
+        assert_eq!(Some("0"), cursor.peek_prev().map(String::as_str));
     });
 }
 
@@ -17,6 +17,21 @@ impl<'a, 'brand, T> Iter<'a, 'brand, T> {
 
         Self { token, head_tail, }
     }
+
+    //  Internal: creates an empty instance.
+    pub(super) fn empty(token: &'a GhostToken<'brand>) -> Self {
+        Self { token, head_tail: None, }
+    }
+
+    //  Internal: creates an instance over a slice of items.
+    pub(super) fn slice(
+        token: &'a GhostToken<'brand>,
+        head_tail: Option<(&'a GhostNode<'brand, T>, &'a GhostNode<'brand, T>)>,
+    )
+        -> Self
+    {
+        Self { token, head_tail, }
+    }
 }
 
 impl<'a, 'brand, T> Iterator for Iter<'a, 'brand, T> {
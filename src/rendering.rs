@@ -0,0 +1,45 @@
+//  Internal; rendering helpers shared by the collections.
+
+use alloc::string::String;
+use core::fmt::{self, Write};
+
+//  Internal; writes `value` as the content of a double-quoted Graphviz DOT string, escaping it as necessary.
+pub(crate) fn write_dot_escaped<D: fmt::Display>(out: &mut String, value: D) {
+    write!(Escaper(out), "{}", value).expect("Infallible - writing to a String");
+}
+
+//  Internal; escapes double-quotes, backslashes and newlines as it writes to the underlying string.
+struct Escaper<'a>(&'a mut String);
+
+impl<'a> Write for Escaper<'a> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            match c {
+                '"' | '\\' => {
+                    self.0.push('\\');
+                    self.0.push(c);
+                },
+                '\n' => self.0.push_str("\\n"),
+                _ => self.0.push(c),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+use super::*;
+
+#[test]
+fn escape() {
+    let mut out = String::new();
+
+    write_dot_escaped(&mut out, "a \"quoted\" back\\slash\nnewline");
+
+    assert_eq!(r#"a \"quoted\" back\\slash\nnewline"#, out);
+}
+
+} // mod tests
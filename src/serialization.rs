@@ -0,0 +1,547 @@
+//! Serialization and deserialization of the collections, with `serde`.
+//!
+//! As the elements of the collections cannot be accessed without a token, the collections cannot implement `Serialize`
+//! and `Deserialize` directly:
+//!
+//! -   Serialization goes through `with_token`, which pairs a collection with a token, and implements `Serialize`.
+//! -   Deserialization goes through `TokenSeed`, which holds a token, and implements `DeserializeSeed`.
+//!
+//! All collections are serialized as a sequence of their elements, from front to back, and are therefore
+//! interchangeable with one another, and with `Vec`.
+
+use core::{fmt, marker::PhantomData};
+
+use ghost_cell::GhostToken;
+use serde::{
+    de::{DeserializeSeed, SeqAccess, Visitor},
+    ser::SerializeSeq,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{
+    linked_list::LinkedList,
+    tripod_list::TripodList,
+    tripod_tree::{BalancePolicy, TripodTree},
+};
+
+/// A collection paired with a token, for serialization.
+pub struct WithToken<'a, 'brand, C> {
+    collection: &'a C,
+    token: &'a GhostToken<'brand>,
+}
+
+impl<'a, 'brand, C> WithToken<'a, 'brand, C> {
+    /// Pairs the collection with the token.
+    pub fn new(collection: &'a C, token: &'a GhostToken<'brand>) -> Self { Self { collection, token, } }
+}
+
+/// A token, for deserialization of a collection of type `C`.
+pub struct TokenSeed<'a, 'brand, C> {
+    token: &'a mut GhostToken<'brand>,
+    _marker: PhantomData<fn() -> C>,
+}
+
+impl<'a, 'brand, C> TokenSeed<'a, 'brand, C> {
+    /// Creates a seed, borrowing the token for the duration of the deserialization.
+    pub fn new(token: &'a mut GhostToken<'brand>) -> Self { Self { token, _marker: PhantomData, } }
+}
+
+impl<'brand, T> LinkedList<'brand, T> {
+    /// Pairs the list with the token, for serialization.
+    pub fn with_token<'a>(&'a self, token: &'a GhostToken<'brand>) -> WithToken<'a, 'brand, Self> {
+        WithToken::new(self, token)
+    }
+}
+
+impl<'brand, T> TripodList<'brand, T> {
+    /// Pairs the list with the token, for serialization.
+    pub fn with_token<'a>(&'a self, token: &'a GhostToken<'brand>) -> WithToken<'a, 'brand, Self> {
+        WithToken::new(self, token)
+    }
+}
+
+impl<'brand, T, P: BalancePolicy> TripodTree<'brand, T, P> {
+    /// Pairs the tree with the token, for serialization.
+    pub fn with_token<'a>(&'a self, token: &'a GhostToken<'brand>) -> WithToken<'a, 'brand, Self> {
+        WithToken::new(self, token)
+    }
+}
+
+impl<'a, 'brand, T> Serialize for WithToken<'a, 'brand, LinkedList<'brand, T>>
+where
+    T: Serialize,
+{
+    /// Serializes the elements as a sequence.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(N) in the number of elements, plus the cost of serialization.
+    /// -   Space: O(1).
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_seq(serializer, self.collection.len(), self.collection.iter(self.token))
+    }
+}
+
+impl<'a, 'brand, T> Serialize for WithToken<'a, 'brand, TripodList<'brand, T>>
+where
+    T: Serialize,
+{
+    /// Serializes the elements as a sequence.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(N) in the number of elements, plus the cost of serialization.
+    /// -   Space: O(1).
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_seq(serializer, self.collection.len(), self.collection.iter(self.token))
+    }
+}
+
+impl<'a, 'brand, T, P> Serialize for WithToken<'a, 'brand, TripodTree<'brand, T, P>>
+where
+    T: Serialize,
+    P: BalancePolicy,
+{
+    /// Serializes the elements as a sequence.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(N) in the number of elements, plus the cost of serialization.
+    /// -   Space: O(1).
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_seq(serializer, self.collection.len(self.token), self.collection.iter(self.token))
+    }
+}
+
+impl<'de, 'a, 'brand, T> DeserializeSeed<'de> for TokenSeed<'a, 'brand, LinkedList<'brand, T>>
+where
+    T: Deserialize<'de>,
+{
+    type Value = LinkedList<'brand, T>;
+
+    /// Deserializes a sequence of elements, from front to back.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(N) in the number of elements, plus the cost of deserialization.
+    /// -   Space: O(1).
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(SeqVisitor::new(self.token))
+    }
+}
+
+impl<'de, 'a, 'brand, T> DeserializeSeed<'de> for TokenSeed<'a, 'brand, TripodList<'brand, T>>
+where
+    T: Deserialize<'de>,
+{
+    type Value = TripodList<'brand, T>;
+
+    /// Deserializes a sequence of elements, from front to back.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(N) in the number of elements, plus the cost of deserialization.
+    /// -   Space: O(1).
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(SeqVisitor::new(self.token))
+    }
+}
+
+impl<'de, 'a, 'brand, T, P> DeserializeSeed<'de> for TokenSeed<'a, 'brand, TripodTree<'brand, T, P>>
+where
+    T: Deserialize<'de>,
+    P: BalancePolicy,
+{
+    type Value = TripodTree<'brand, T, P>;
+
+    /// Deserializes a sequence of elements, from front to back, into a complete tree, balanced according to `P`.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(N) in the number of elements, plus the cost of deserialization. For a `Treap`, whose priorities are
+    ///     heap-ordered once the tree is complete, O(N) is the expected time.
+    /// -   Space: O(1).
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(SeqVisitor::new(self.token))
+    }
+}
+
+//
+//  Implementation
+//
+
+//  Internal; a collection built from front to back.
+trait Build<'brand>: Sized {
+    type Element;
+
+    fn new() -> Self;
+
+    fn push(&mut self, element: Self::Element, token: &mut GhostToken<'brand>);
+
+    fn finish(&mut self, _token: &mut GhostToken<'brand>) {}
+
+    fn clear(&mut self, token: &mut GhostToken<'brand>);
+}
+
+impl<'brand, T> Build<'brand> for LinkedList<'brand, T> {
+    type Element = T;
+
+    fn new() -> Self { LinkedList::new() }
+
+    fn push(&mut self, element: T, token: &mut GhostToken<'brand>) { self.push_back(element, token); }
+
+    fn clear(&mut self, token: &mut GhostToken<'brand>) { LinkedList::clear(self, token); }
+}
+
+impl<'brand, T> Build<'brand> for TripodList<'brand, T> {
+    type Element = T;
+
+    fn new() -> Self { TripodList::new() }
+
+    fn push(&mut self, element: T, token: &mut GhostToken<'brand>) { self.push_back(element, token); }
+
+    fn clear(&mut self, token: &mut GhostToken<'brand>) { TripodList::clear(self, token); }
+}
+
+impl<'brand, T, P: BalancePolicy> Build<'brand> for TripodTree<'brand, T, P> {
+    type Element = T;
+
+    fn new() -> Self { TripodTree::new() }
+
+    //  Balancing on each push would be O(N log N) overall, instead the tree is balanced once, at the end.
+    fn push(&mut self, element: T, token: &mut GhostToken<'brand>) { self.push_vine(element, token); }
+
+    fn finish(&mut self, token: &mut GhostToken<'brand>) { self.balance_vine(token); }
+
+    fn clear(&mut self, token: &mut GhostToken<'brand>) { TripodTree::clear(self, token); }
+}
+
+struct SeqVisitor<'a, 'brand, C> {
+    token: &'a mut GhostToken<'brand>,
+    _marker: PhantomData<fn() -> C>,
+}
+
+impl<'a, 'brand, C> SeqVisitor<'a, 'brand, C> {
+    fn new(token: &'a mut GhostToken<'brand>) -> Self { Self { token, _marker: PhantomData, } }
+}
+
+impl<'de, 'a, 'brand, C> Visitor<'de> for SeqVisitor<'a, 'brand, C>
+where
+    C: Build<'brand>,
+    C::Element: Deserialize<'de>,
+{
+    type Value = C;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a sequence")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<C, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut collection = C::new();
+
+        loop {
+            match seq.next_element() {
+                Ok(Some(element)) => collection.push(element, self.token),
+                Ok(None) => break,
+                Err(error) => {
+                    //  The collection cannot be dropped with elements, as those require the token to be freed.
+                    collection.clear(self.token);
+                    return Err(error);
+                },
+            }
+        }
+
+        collection.finish(self.token);
+
+        Ok(collection)
+    }
+}
+
+fn serialize_seq<'a, S, T, I>(serializer: S, length: usize, elements: I) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: 'a + Serialize,
+    I: Iterator<Item = &'a T>,
+{
+    let mut seq = serializer.serialize_seq(Some(length))?;
+
+    for element in elements {
+        seq.serialize_element(element)?;
+    }
+
+    seq.end()
+}
+
+#[cfg(test)]
+mod tests {
+
+use serde::{
+    de::value::{Error, SeqDeserializer},
+    ser::{self, Impossible},
+};
+
+use crate::tripod_tree::{Avl, RedBlack, Treap, Unbalanced};
+
+use super::*;
+
+#[test]
+fn linked_list_round_trip() {
+    GhostToken::new(|mut token| {
+        let mut list: LinkedList<'_, u32> = deserialize(&[1, 2, 3], &mut token).expect("Success");
+
+        assert_eq!(vec![1, 2, 3], serialize(&list.with_token(&token)));
+
+        list.clear(&mut token);
+    });
+}
+
+#[test]
+fn linked_list_error() {
+    GhostToken::new(|mut token| {
+        let result: Result<LinkedList<'_, u8>, _> = deserialize(&[1, 2, 300, 4], &mut token);
+
+        assert!(result.is_err());
+    });
+}
+
+#[test]
+fn tripod_list_round_trip() {
+    GhostToken::new(|mut token| {
+        let mut list: TripodList<'_, u32> = deserialize(&[1, 2, 3], &mut token).expect("Success");
+
+        assert_eq!(3, list.len());
+        assert_eq!(Ok(()), list.check_invariants(&token));
+        assert_eq!(vec![1, 2, 3], serialize(&list.with_token(&token)));
+
+        list.clear(&mut token);
+    });
+}
+
+#[test]
+fn tripod_list_error() {
+    GhostToken::new(|mut token| {
+        let result: Result<TripodList<'_, u8>, _> = deserialize(&[1, 2, 300, 4], &mut token);
+
+        assert!(result.is_err());
+    });
+}
+
+#[test]
+fn tripod_tree_round_trip() {
+    GhostToken::new(|mut token| {
+        for length in 0..64 {
+            let elements: Vec<u64> = (0..length).collect();
+
+            let mut tree: TripodTree<'_, u64> = deserialize(&elements, &mut token).expect("Success");
+
+            assert_eq!(length as usize, tree.len(&token));
+            assert_eq!(Ok(()), tree.check_invariants(&token));
+            assert_eq!(elements, serialize(&tree.with_token(&token)));
+
+            tree.clear(&mut token);
+        }
+    });
+}
+
+#[test]
+fn tripod_tree_round_trip_policies() {
+    fn check<P: BalancePolicy>() {
+        GhostToken::new(|mut token| {
+            for length in 0..64 {
+                let elements: Vec<u64> = (0..length).collect();
+
+                let mut tree: TripodTree<'_, u64, P> = deserialize(&elements, &mut token).expect("Success");
+
+                assert_eq!(length as usize, tree.len(&token));
+                assert_eq!(Ok(()), tree.check_invariants(&token));
+                assert_eq!(elements, serialize(&tree.with_token(&token)));
+
+                tree.clear(&mut token);
+            }
+        });
+    }
+
+    check::<Avl>();
+    check::<RedBlack>();
+    check::<Treap>();
+    check::<Unbalanced>();
+}
+
+#[test]
+fn tripod_tree_complete() {
+    GhostToken::new(|mut token| {
+        let elements: Vec<u64> = (0..12).collect();
+
+        let mut tree: TripodTree<'_, u64> = deserialize(&elements, &mut token).expect("Success");
+
+        //  A complete tree of 12 elements has 3 full levels, and 5 elements in the last, packed to the right as the
+        //  vine is compressed from its back.
+        //
+        //                4
+        //          /           \
+        //         1             8
+        //       /   \         /    \
+        //      0     2       6      10
+        //             \     / \    /  \
+        //              3   5   7  9    11
+        {
+            let mut cursor = tree.cursor(&token);
+            assert_eq!(Some(&4), cursor.current());
+            assert_eq!(0..12, cursor.range());
+
+            cursor.move_left();
+            assert_eq!(Some(&1), cursor.current());
+            assert_eq!(0..4, cursor.range());
+
+            cursor.move_up();
+            cursor.move_right();
+            assert_eq!(Some(&8), cursor.current());
+            assert_eq!(5..12, cursor.range());
+        }
+
+        tree.clear(&mut token);
+    });
+}
+
+#[test]
+fn tripod_tree_error() {
+    GhostToken::new(|mut token| {
+        let result: Result<TripodTree<'_, u8>, _> = deserialize(&[1, 2, 3, 300, 4], &mut token);
+
+        assert!(result.is_err());
+    });
+}
+
+//
+//  Helpers
+//
+
+fn deserialize<'brand, C>(elements: &[u64], token: &mut GhostToken<'brand>) -> Result<C, Error>
+where
+    for<'a> TokenSeed<'a, 'brand, C>: DeserializeSeed<'static, Value = C>,
+{
+    let deserializer = SeqDeserializer::<_, Error>::new(elements.iter().copied());
+
+    TokenSeed::new(token).deserialize(deserializer)
+}
+
+fn serialize<T: Serialize>(value: &T) -> Vec<u64> {
+    let mut result = Vec::new();
+
+    value.serialize(Collector(&mut result)).expect("Success");
+
+    result
+}
+
+//  A serializer collecting sequences of integers, and nothing else.
+struct Collector<'a>(&'a mut Vec<u64>);
+
+macro_rules! unsupported {
+    ($($name:ident: $type:ty),*) => {
+        $(
+            fn $name(self, _: $type) -> Result<(), Error> { Err(ser::Error::custom(stringify!($name))) }
+        )*
+    };
+}
+
+impl<'a> Serializer for Collector<'a> {
+    type Ok = ();
+    type Error = Error;
+    type SerializeSeq = Self;
+    type SerializeTuple = Impossible<(), Error>;
+    type SerializeTupleStruct = Impossible<(), Error>;
+    type SerializeTupleVariant = Impossible<(), Error>;
+    type SerializeMap = Impossible<(), Error>;
+    type SerializeStruct = Impossible<(), Error>;
+    type SerializeStructVariant = Impossible<(), Error>;
+
+    fn serialize_u8(self, v: u8) -> Result<(), Error> { self.serialize_u64(v.into()) }
+    fn serialize_u16(self, v: u16) -> Result<(), Error> { self.serialize_u64(v.into()) }
+    fn serialize_u32(self, v: u32) -> Result<(), Error> { self.serialize_u64(v.into()) }
+    fn serialize_u64(self, v: u64) -> Result<(), Error> { self.0.push(v); Ok(()) }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<Self, Error> { Ok(self) }
+
+    unsupported!(serialize_bool: bool, serialize_i8: i8, serialize_i16: i16, serialize_i32: i32, serialize_i64: i64,
+        serialize_f32: f32, serialize_f64: f64, serialize_char: char, serialize_str: &str, serialize_bytes: &[u8],
+        serialize_unit_struct: &'static str);
+
+    fn serialize_none(self) -> Result<(), Error> { Err(ser::Error::custom("none")) }
+    fn serialize_unit(self) -> Result<(), Error> { Err(ser::Error::custom("unit")) }
+
+    fn collect_str<T: ?Sized + fmt::Display>(self, _: &T) -> Result<(), Error> { Err(ser::Error::custom("str")) }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, _: &T) -> Result<(), Error> { Err(ser::Error::custom("some")) }
+
+    fn serialize_unit_variant(self, _: &'static str, _: u32, _: &'static str) -> Result<(), Error> {
+        Err(ser::Error::custom("unit_variant"))
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(self, _: &'static str, _: &T) -> Result<(), Error> {
+        Err(ser::Error::custom("newtype_struct"))
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(self, _: &'static str, _: u32, _: &'static str, _: &T)
+        -> Result<(), Error>
+    {
+        Err(ser::Error::custom("newtype_variant"))
+    }
+
+    fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple, Error> { Err(ser::Error::custom("tuple")) }
+
+    fn serialize_tuple_struct(self, _: &'static str, _: usize) -> Result<Self::SerializeTupleStruct, Error> {
+        Err(ser::Error::custom("tuple_struct"))
+    }
+
+    fn serialize_tuple_variant(self, _: &'static str, _: u32, _: &'static str, _: usize)
+        -> Result<Self::SerializeTupleVariant, Error>
+    {
+        Err(ser::Error::custom("tuple_variant"))
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap, Error> { Err(ser::Error::custom("map")) }
+
+    fn serialize_struct(self, _: &'static str, _: usize) -> Result<Self::SerializeStruct, Error> {
+        Err(ser::Error::custom("struct"))
+    }
+
+    fn serialize_struct_variant(self, _: &'static str, _: u32, _: &'static str, _: usize)
+        -> Result<Self::SerializeStructVariant, Error>
+    {
+        Err(ser::Error::custom("struct_variant"))
+    }
+}
+
+impl<'a> SerializeSeq for Collector<'a> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        value.serialize(Collector(&mut *self.0))
+    }
+
+    fn end(self) -> Result<(), Error> { Ok(()) }
+}
+
+} // mod tests
@@ -0,0 +1,352 @@
+//! A Splay Tree, with externally supplied token.
+//!
+//! The `SplayTree` is built upon the nodes of the `TripodTree`, yet rather than maintaining a balance invariant it
+//! splays each accessed element to the root, as befits workloads repeatedly accessing a small hot set of elements.
+//!
+//! Like the `TripodTree`, each element in the tree is indexed by a number in [0, N), where N is the number of elements,
+//! according to the in-order traversal of the tree. The tree may be used both as a sequence, accessed by index, and as
+//! an ordered tree, accessed by search.
+//!
+//! #   Deviation
+//!
+//! Splaying modifies the structure of the tree, hence accessing an element requires a mutable reference to the tree
+//! and a mutable reference to the token: see `get_splay`. The read-only accessors, such as `iter` or `cursor`, do not
+//! splay.
+
+mod cursor;
+
+pub use cursor::CursorMut;
+pub use crate::tripod_tree::{Cursor, Iter};
+
+use core::cmp::Ordering;
+
+use ghost_cell::GhostToken;
+
+use crate::{
+    invariant::InvariantError,
+    tripod_tree::{self, Side, TripodTree, Unbalanced},
+};
+
+/// A safe implementation of a splay tree.
+///
+/// Each node contains 1 element as well as 4 pointers: up, left, right, and the tripod pointer.
+///
+/// #   Complexity
+///
+/// All the splaying operations have an amortized complexity of O(log N) in the number of elements, and repeatedly
+/// accessing the same element has an amortized complexity of O(1).
+pub struct SplayTree<'brand, T> {
+    tree: TripodTree<'brand, T, Unbalanced>,
+}
+
+impl<'brand, T> SplayTree<'brand, T> {
+    /// Creates a new, empty, instance.
+    pub const fn new() -> Self { Self { tree: TripodTree::new(), } }
+
+    /// Creates a new instance, with a single value.
+    pub fn singleton(value: T, token: &mut GhostToken<'brand>) -> Self {
+        Self { tree: TripodTree::singleton(value, token), }
+    }
+
+    /// Creates an iterator over the entire tree, from front to back, without splaying.
+    ///
+    /// #   Complexity
+    ///
+    /// The complexity of this method itself is O(1).
+    ///
+    /// The complexity of calling `next` on the resulting iterator is O(D) in the depth of the tree.
+    pub fn iter<'a>(&'a self, token: &'a GhostToken<'brand>) -> Iter<'a, 'brand, T> { self.tree.iter(token) }
+
+    /// Creates a cursor pointing to the root element, which never splays.
+    pub fn cursor<'a>(&'a self, token: &'a GhostToken<'brand>) -> Cursor<'a, 'brand, T> { self.tree.cursor(token) }
+
+    /// Creates a mutable cursor pointing to the root element.
+    pub fn cursor_mut<'a>(&'a mut self, token: &'a mut GhostToken<'brand>) -> CursorMut<'a, 'brand, T> {
+        CursorMut::new(token, self)
+    }
+
+    /// Returns whether the tree is empty, or not.
+    pub fn is_empty(&self) -> bool { self.tree.is_empty() }
+
+    /// Returns the number of elements in the tree.
+    pub fn len(&self, token: &GhostToken<'brand>) -> usize { self.tree.len(token) }
+
+    /// Clears the tree of all elements.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(N) in the number of elements.
+    /// -   Space: O(1).
+    pub fn clear(&mut self, token: &mut GhostToken<'brand>) { self.tree.clear(token) }
+
+    /// Returns a reference to the root element, if any, that is the element accessed last.
+    pub fn root<'a>(&'a self, token: &'a GhostToken<'brand>) -> Option<&'a T> { self.cursor(token).current() }
+
+    /// Returns a reference to the element at index `at`, if any, after splaying it to the root.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: amortized O(log N) in the number of elements, O(1) if `at` was accessed last.
+    /// -   Space: O(1).
+    pub fn get_splay<'a>(&'a mut self, at: usize, token: &'a mut GhostToken<'brand>) -> Option<&'a T> {
+        if at >= self.len(token) {
+            return None;
+        }
+
+        {
+            let mut cursor = self.tree.cursor_mut(token);
+            cursor.move_to(at);
+            splay(&mut cursor);
+        }
+
+        self.root(token)
+    }
+
+    /// Inserts `value` at index `at`, splaying it to the root.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: amortized O(log N) in the number of elements.
+    /// -   Space: O(1).
+    ///
+    /// A single memory allocation occurs.
+    ///
+    /// #   Panics
+    ///
+    /// If `at` is strictly greater than the number of elements.
+    pub fn insert(&mut self, at: usize, value: T, token: &mut GhostToken<'brand>) {
+        let mut cursor = self.cursor_mut(token);
+        cursor.move_to(at);
+        cursor.insert_before(value);
+    }
+
+    /// Removes the element at index `at`, if any, and returns it.
+    ///
+    /// The neighbour of the removed element which takes its place, if any, is splayed to the root.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: amortized O(log N) in the number of elements.
+    /// -   Space: O(1).
+    ///
+    /// A single memory deallocation occurs.
+    pub fn remove(&mut self, at: usize, token: &mut GhostToken<'brand>) -> Option<T> {
+        if at >= self.len(token) {
+            return None;
+        }
+
+        let mut cursor = self.cursor_mut(token);
+        cursor.move_to(at);
+        cursor.remove_current()
+    }
+
+    /// Pushes an element at the front of the tree, splaying it to the root.
+    pub fn push_front(&mut self, value: T, token: &mut GhostToken<'brand>) { self.insert(0, value, token) }
+
+    /// Pushes an element at the back of the tree, splaying it to the root.
+    pub fn push_back(&mut self, value: T, token: &mut GhostToken<'brand>) {
+        let at = self.len(token);
+        self.insert(at, value, token)
+    }
+
+    /// Pops the front element of the tree, if any.
+    pub fn pop_front(&mut self, token: &mut GhostToken<'brand>) -> Option<T> { self.remove(0, token) }
+
+    /// Pops the back element of the tree, if any.
+    pub fn pop_back(&mut self, token: &mut GhostToken<'brand>) -> Option<T> {
+        let at = self.len(token).checked_sub(1)?;
+        self.remove(at, token)
+    }
+
+    /// Searches the tree, assumed sorted, for the element for which `f` returns `Ordering::Equal`, as per
+    /// `slice::binary_search_by`, splaying the last element visited to the root.
+    ///
+    /// If an element is found, returns `Ok` with its index, otherwise returns `Err` with the index at which a matching
+    /// element could be inserted while maintaining the order.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: amortized O(log N) in the number of elements.
+    /// -   Space: O(1).
+    pub fn search_splay_by<F>(&mut self, f: F, token: &mut GhostToken<'brand>) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        let mut cursor = self.cursor_mut(token);
+        cursor.search_by(f)
+    }
+
+    /// Searches the tree, assumed sorted, for `value`, splaying the last element visited to the root.
+    ///
+    /// See `search_splay_by`.
+    pub fn search_splay(&mut self, value: &T, token: &mut GhostToken<'brand>) -> Result<usize, usize>
+    where
+        T: Ord,
+    {
+        self.search_splay_by(|element| element.cmp(value), token)
+    }
+
+    /// Inserts `value` in the tree, assumed sorted, after any element equal to it, splaying it to the root.
+    ///
+    /// Returns the index at which `value` was inserted.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: amortized O(log N) in the number of elements.
+    /// -   Space: O(1).
+    ///
+    /// A single memory allocation occurs.
+    pub fn insert_sorted(&mut self, value: T, token: &mut GhostToken<'brand>) -> usize
+    where
+        T: Ord,
+    {
+        let at = self.search_splay_by(|element| element.cmp(&value).then(Ordering::Less), token).unwrap_or_else(|at| at);
+
+        self.insert(at, value, token);
+
+        at
+    }
+
+    /// Checks the structural invariants of the tree, returning the first violation found, if any.
+    ///
+    /// The following invariants are checked, for each node:
+    ///
+    /// -   The size of the node is 1 + the sizes of its left and right sub-trees.
+    /// -   The up pointer of each child points back to the node, and the root has no up pointer.
+    /// -   The tripod of the node is retracted.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(N) in the number of elements.
+    /// -   Space: O(1).
+    pub fn check_invariants(&self, token: &GhostToken<'brand>) -> Result<(), InvariantError> {
+        self.tree.check_invariants(token)
+    }
+}
+
+impl<'brand, T> Default for SplayTree<'brand, T> {
+    fn default() -> Self { Self::new() }
+}
+
+//  Internal; splays the current element of the cursor to the root, the cursor following it.
+//
+//  Each step rotates the current element, and possibly its parent, one or two levels up, and thus the whole splay is
+//  O(D) in the depth of the current element.
+fn splay<'brand, T>(cursor: &mut tripod_tree::CursorMut<'_, 'brand, T, Unbalanced>) {
+    while let Some(side) = cursor.as_cursor().side() {
+        cursor.move_up();
+
+        match cursor.as_cursor().side() {
+            //  Zig-zig: rotate the parent first, then the element.
+            Some(parent_side) if parent_side == side => {
+                cursor.move_up();
+                cursor.rotate(side);
+                cursor.rotate(side);
+            },
+            //  Zig-zag: rotate the element twice.
+            Some(parent_side) => {
+                cursor.rotate(side);
+                cursor.move_up();
+                cursor.rotate(parent_side);
+            },
+            //  Zig: the parent is the root.
+            None => cursor.rotate(side),
+        }
+    }
+}
+
+//  Internal; returns the side to descend to for the given ordering of the current element, or None if found.
+fn descend_side(ordering: Ordering) -> Option<Side> {
+    match ordering {
+        Ordering::Less => Some(Side::Right),
+        Ordering::Equal => None,
+        Ordering::Greater => Some(Side::Left),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+use std::{string::String, vec::Vec};
+
+use super::*;
+
+#[test]
+fn splay_tree_new() {
+    GhostToken::new(|mut token| {
+        let mut tree = SplayTree::<'_, String>::new();
+
+        assert!(tree.is_empty());
+        assert_eq!(0, tree.len(&token));
+        assert_eq!(None, tree.root(&token));
+        assert_eq!(None, tree.get_splay(0, &mut token));
+        assert_eq!(Err(0), tree.search_splay(&String::new(), &mut token));
+        assert_eq!(Ok(()), tree.check_invariants(&token));
+    });
+}
+
+#[test]
+fn splay_tree_sequence() {
+    GhostToken::new(|mut token| {
+        let mut tree = SplayTree::new();
+
+        for i in 0..16 {
+            tree.push_back(i, &mut token);
+            assert_eq!(Some(&i), tree.root(&token));
+        }
+
+        tree.push_front(-1, &mut token);
+        tree.insert(8, 100, &mut token);
+
+        assert_eq!(Ok(()), tree.check_invariants(&token));
+        assert_eq!(18, tree.len(&token));
+        assert_eq!(Some(&100), tree.root(&token));
+
+        for at in [3, 17, 0, 3, 9].iter() {
+            let expected = tree.iter(&token).nth(*at).copied();
+
+            assert_eq!(expected.as_ref(), tree.get_splay(*at, &mut token));
+            assert_eq!(expected.as_ref(), tree.root(&token));
+            assert_eq!(Ok(()), tree.check_invariants(&token));
+        }
+
+        assert_eq!(Some(100), tree.remove(8, &mut token));
+        assert_eq!(Some(-1), tree.pop_front(&mut token));
+        assert_eq!(Some(15), tree.pop_back(&mut token));
+
+        assert_eq!(Ok(()), tree.check_invariants(&token));
+
+        let elements: Vec<_> = tree.iter(&token).copied().collect();
+        assert_eq!(&(0..15).collect::<Vec<_>>()[..], &elements[..]);
+
+        tree.clear(&mut token);
+    });
+}
+
+#[test]
+fn splay_tree_search() {
+    GhostToken::new(|mut token| {
+        let mut tree = SplayTree::new();
+
+        for value in [5, 1, 9, 3, 7, 3].iter() {
+            tree.insert_sorted(*value, &mut token);
+            assert_eq!(Some(value), tree.root(&token));
+        }
+
+        let elements: Vec<_> = tree.iter(&token).copied().collect();
+        assert_eq!(&[1, 3, 3, 5, 7, 9][..], &elements[..]);
+
+        assert_eq!(Ok(3), tree.search_splay(&5, &mut token));
+        assert_eq!(Some(&5), tree.root(&token));
+
+        assert_eq!(Err(5), tree.search_splay(&8, &mut token));
+        assert_eq!(Err(0), tree.search_splay(&0, &mut token));
+        assert_eq!(Some(&1), tree.root(&token));
+
+        assert_eq!(Ok(()), tree.check_invariants(&token));
+
+        tree.clear(&mut token);
+    });
+}
+
+} // mod tests
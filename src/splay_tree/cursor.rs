@@ -0,0 +1,359 @@
+use core::{
+    cmp::Ordering,
+    ops::Range,
+};
+
+use ghost_cell::GhostToken;
+
+use crate::tripod_tree::{self, Side, Unbalanced};
+
+use super::{Cursor, SplayTree};
+
+/// A mutable cursor over the SplayTree.
+///
+/// Moving the cursor to a given element, through `move_to`, `move_to_front`, `move_to_back`, or `search_by`, splays
+/// this element to the root, whereas stepping to the neighbouring elements, through `move_next` and `move_prev`, does
+/// not.
+///
+/// The cursor contains a "twilight" non-element between the leaves and the root, see `Cursor`.
+pub struct CursorMut<'a, 'brand, T> {
+    inner: tripod_tree::CursorMut<'a, 'brand, T, Unbalanced>,
+}
+
+//  Cursor navigation, without splaying.
+impl<'a, 'brand, T> CursorMut<'a, 'brand, T> {
+    /// Creates a new instance pointing to the root element of the tree, if any.
+    pub fn new(token: &'a mut GhostToken<'brand>, tree: &'a mut SplayTree<'brand, T>) -> Self {
+        Self { inner: tripod_tree::CursorMut::new(token, &mut tree.tree), }
+    }
+
+    /// Returns a read-only cursor pointing to the current element.
+    pub fn as_cursor(&self) -> Cursor<'_, 'brand, T> { self.inner.as_cursor() }
+
+    /// Returns the index of the element pointed to by the cursor in the tree.
+    ///
+    /// If the cursor currently points to the "twilight" non-element, returns None.
+    pub fn index(&self) -> Option<usize> { self.inner.index() }
+
+    /// Returns the range of indices covered by the sub-tree rooted at the element the cursor is pointing at.
+    pub fn range(&self) -> Range<usize> { self.inner.range() }
+
+    /// Moves the cursor to the next element, in-order, if any.
+    ///
+    /// If there is no next element, then the cursor moves to the "twilight" non-element, which exists between the root
+    /// and the leaves.
+    ///
+    /// If the cursor is pointing to the "twilight" non-element, then moves to the front element, if any.
+    pub fn move_next(&mut self) { self.inner.move_next() }
+
+    /// Moves the cursor to the previous element, in-order, if any.
+    ///
+    /// If there is no previous element, then the cursor moves to the "twilight" non-element, which exists between the
+    /// root and the leaves.
+    ///
+    /// If the cursor is pointing to the "twilight" non-element, then moves to the back element, if any.
+    pub fn move_prev(&mut self) { self.inner.move_prev() }
+
+    /// Returns a reference to the current element, if any.
+    pub fn current(&mut self) -> Option<&mut T> { self.inner.current() }
+
+    /// Returns a reference to the next element, if any.
+    pub fn peek_next(&self) -> Option<&T> { self.inner.peek_next() }
+
+    /// Returns a reference to the previous element, if any.
+    pub fn peek_prev(&self) -> Option<&T> { self.inner.peek_prev() }
+}
+
+//  Cursor navigation, splaying the element moved to.
+impl<'a, 'brand, T> CursorMut<'a, 'brand, T> {
+    /// Splays the current element to the root, the cursor still pointing to it afterwards.
+    ///
+    /// If the cursor points to the "twilight" non-element, nothing happens.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(D) in the depth of the current element.
+    /// -   Space: O(1).
+    pub fn splay(&mut self) { super::splay(&mut self.inner) }
+
+    /// Moves the cursor to the element at the given index, in-order, and splays it to the root.
+    ///
+    /// If `at` is the number of elements, then the cursor moves to the "twilight" non-element, which exists between the
+    /// root and the leaves.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: amortized O(log N) in the number of elements, O(1) if `at` was accessed last.
+    /// -   Space: O(1).
+    ///
+    /// #   Panics
+    ///
+    /// If `at` is strictly greater than the number of elements.
+    pub fn move_to(&mut self, at: usize) {
+        self.inner.move_to(at);
+        self.splay();
+    }
+
+    /// Moves the cursor to the front element, if any, and splays it to the root.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: amortized O(log N) in the number of elements.
+    /// -   Space: O(1).
+    pub fn move_to_front(&mut self) {
+        self.inner.move_to_front();
+        self.splay();
+    }
+
+    /// Moves the cursor to the back element, if any, and splays it to the root.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: amortized O(log N) in the number of elements.
+    /// -   Space: O(1).
+    pub fn move_to_back(&mut self) {
+        self.inner.move_to_back();
+        self.splay();
+    }
+
+    /// Searches the tree, assumed sorted, for the element for which `f` returns `Ordering::Equal`, as per
+    /// `slice::binary_search_by`, moving the cursor to the last element visited and splaying it to the root.
+    ///
+    /// If an element is found, returns `Ok` with its index, otherwise returns `Err` with the index at which a matching
+    /// element could be inserted while maintaining the order.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: amortized O(log N) in the number of elements.
+    /// -   Space: O(1).
+    pub fn search_by<F>(&mut self, mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        self.inner.move_to_root();
+
+        let result = loop {
+            let index = if let Some(index) = self.inner.index() { index } else { return Err(0) };
+            let current = self.inner.current().expect("Non-twilight - as index is Some");
+
+            let side = if let Some(side) = super::descend_side(f(current)) { side } else { break Ok(index) };
+
+            if self.inner.peek_down(side).is_none() {
+                break Err(if side == Side::Right { index + 1 } else { index });
+            }
+
+            self.inner.move_down(side);
+        };
+
+        self.splay();
+
+        result
+    }
+}
+
+//  Edit cursor operations, splaying the element inserted, or the one taking the place of the element removed.
+impl<'a, 'brand, T> CursorMut<'a, 'brand, T> {
+    /// Inserts a new element after the current one, and splays it to the root, the cursor pointing to it afterwards.
+    ///
+    /// If the cursor is pointing at the "twilight" non-element, then the new element is inserted at the front.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(D) in the depth of the current element, plus the amortized O(log N) of the splay.
+    /// -   Space: O(1).
+    ///
+    /// A single memory allocation occurs.
+    pub fn insert_after(&mut self, value: T) {
+        let index = self.index();
+
+        self.inner.insert_after(value);
+
+        match index {
+            Some(index) => self.move_to(index + 1),
+            None => self.move_to_front(),
+        }
+    }
+
+    /// Inserts a new element before the current one, and splays it to the root, the cursor pointing to it afterwards.
+    ///
+    /// If the cursor is pointing at the "twilight" non-element, then the new element is inserted at the back.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(D) in the depth of the current element, plus the amortized O(log N) of the splay.
+    /// -   Space: O(1).
+    ///
+    /// A single memory allocation occurs.
+    pub fn insert_before(&mut self, value: T) {
+        let index = self.index();
+
+        self.inner.insert_before(value);
+
+        match index {
+            Some(index) => self.move_to(index),
+            None => self.move_to_back(),
+        }
+    }
+
+    /// Removes the current element from the tree, and returns it.
+    ///
+    /// The cursor moves to the next element, if any, and splays it to the root.
+    ///
+    /// If the cursor is pointing at the "twilight" non-element, then no element is removed and `None` is returned.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(D) in the depth of the current element, plus the amortized O(log N) of the splay.
+    /// -   Space: O(1).
+    ///
+    /// A single memory deallocation occurs.
+    pub fn remove_current(&mut self) -> Option<T> {
+        let result = self.inner.remove_current()?;
+
+        self.splay();
+
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+use std::{
+    panic::{self, AssertUnwindSafe},
+    vec::Vec,
+};
+
+use super::*;
+
+#[test]
+fn cursor_mut_move_to_splays() {
+    GhostToken::new(|mut token| {
+        let mut tree = SplayTree::new();
+
+        for i in 0..10 {
+            tree.push_back(i, &mut token);
+        }
+
+        {
+            let mut cursor = tree.cursor_mut(&mut token);
+
+            for at in [0, 9, 4, 4, 7].iter() {
+                cursor.move_to(*at);
+
+                assert_eq!(Some(*at), cursor.index());
+                assert_eq!(0..10, cursor.range());
+                assert_eq!(None, cursor.as_cursor().side());
+            }
+
+            cursor.move_next();
+
+            assert_eq!(Some(&mut 8), cursor.current());
+            assert_ne!(0..10, cursor.range());
+
+            cursor.splay();
+
+            assert_eq!(0..10, cursor.range());
+
+            cursor.move_to_front();
+
+            assert_eq!(Some(&mut 0), cursor.current());
+            assert_eq!(0..10, cursor.range());
+
+            cursor.move_to_back();
+
+            assert_eq!(Some(&mut 9), cursor.current());
+            assert_eq!(0..10, cursor.range());
+        }
+
+        assert_eq!(Ok(()), tree.check_invariants(&token));
+
+        tree.clear(&mut token);
+    });
+}
+
+#[test]
+fn cursor_mut_move_to_twilight() {
+    GhostToken::new(|mut token| {
+        let mut tree = SplayTree::new();
+
+        for i in 0..4 {
+            tree.push_back(i, &mut token);
+        }
+
+        {
+            let mut cursor = tree.cursor_mut(&mut token);
+            cursor.move_to(4);
+
+            assert_eq!(None, cursor.current());
+            assert_eq!(None, cursor.index());
+        }
+
+        tree.clear(&mut token);
+    });
+}
+
+#[test]
+#[should_panic]
+fn cursor_mut_move_to_out_of_bounds() {
+    GhostToken::new(|mut token| {
+        let mut tree = SplayTree::new();
+
+        for i in 0..4 {
+            tree.push_back(i, &mut token);
+        }
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            let mut cursor = tree.cursor_mut(&mut token);
+            cursor.move_to(5);
+        }));
+
+        tree.clear(&mut token);
+
+        result.expect("No Panic");
+    });
+}
+
+#[test]
+fn cursor_mut_edit() {
+    GhostToken::new(|mut token| {
+        let mut tree = SplayTree::new();
+
+        {
+            let mut cursor = tree.cursor_mut(&mut token);
+
+            cursor.insert_before(1);
+            cursor.insert_after(3);
+            cursor.insert_before(2);
+
+            assert_eq!(Some(&mut 2), cursor.current());
+            assert_eq!(Some(1), cursor.index());
+            assert_eq!(0..3, cursor.range());
+
+            cursor.move_to(2);
+            cursor.insert_after(4);
+
+            assert_eq!(Some(&mut 4), cursor.current());
+            assert_eq!(0..4, cursor.range());
+
+            cursor.move_to(1);
+
+            assert_eq!(Some(2), cursor.remove_current());
+            assert_eq!(Some(&mut 3), cursor.current());
+            assert_eq!(0..3, cursor.range());
+
+            assert_eq!(Ok(2), cursor.search_by(|element| element.cmp(&4)));
+            assert_eq!(Some(&mut 4), cursor.current());
+            assert_eq!(0..3, cursor.range());
+        }
+
+        assert_eq!(Ok(()), tree.check_invariants(&token));
+
+        let elements: Vec<_> = tree.iter(&token).copied().collect();
+        assert_eq!(&[1, 3, 4][..], &elements[..]);
+
+        tree.clear(&mut token);
+    });
+}
+
+} // mod tests
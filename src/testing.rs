@@ -0,0 +1,79 @@
+//! Model-based differential testing of the collections.
+//!
+//! For each collection, a sub-module defines an enum of operations on the collection itself, and enums of operations
+//! on its cursors, as well as a `replay` function which applies a sequence of operations to both the collection and a
+//! `VecDeque` model, asserting that they agree after every single step.
+//!
+//! All operation enums implement both `arbitrary::Arbitrary`, for fuzzing, and `proptest::arbitrary::Arbitrary`, for
+//! property testing, so that downstream crates can generate sequences of operations for their own wrappers.
+//!
+//! Indices carried by operations are arbitrary: they are reduced modulo the number of valid positions at the time the
+//! operation is applied, so that any sequence of operations is valid.
+
+pub mod linked_list;
+pub mod tripod_list;
+pub mod tripod_tree;
+
+use core::fmt::Debug;
+
+use std::{collections::VecDeque, vec::Vec};
+
+use crate::invariant::InvariantError;
+
+//  Internal; reduces an arbitrary index to `0..bound`, or 0 if `bound` is 0.
+fn reduce(index: usize, bound: usize) -> usize {
+    if bound == 0 { 0 } else { index % bound }
+}
+
+//  Internal; returns the index of the model cursor after moving to the next element, "twilight" being `None`.
+fn next_index(index: Option<usize>, length: usize) -> Option<usize> {
+    let next = index.map_or(0, |index| index + 1);
+
+    if next < length { Some(next) } else { None }
+}
+
+//  Internal; returns the index of the model cursor after moving to the previous element, "twilight" being `None`.
+fn prev_index(index: Option<usize>, length: usize) -> Option<usize> {
+    match index {
+        Some(index) => index.checked_sub(1),
+        None => length.checked_sub(1),
+    }
+}
+
+//  Internal; returns the element at the given index of the model, if any.
+fn model_at<T>(model: &VecDeque<T>, index: Option<usize>) -> Option<&T> { index.and_then(|index| model.get(index)) }
+
+//  Internal; asserts that the elements match those of the model.
+#[track_caller]
+fn assert_elements<'a, T, I>(step: usize, elements: I, model: &VecDeque<T>)
+where
+    T: 'a + Debug + PartialEq,
+    I: Iterator<Item = &'a T>,
+{
+    let actual: Vec<&T> = elements.collect();
+    let expected: Vec<&T> = model.iter().collect();
+
+    assert_eq!(expected, actual, "step {}: elements", step);
+}
+
+//  Internal; asserts that the invariants hold.
+#[track_caller]
+fn assert_invariants(step: usize, result: Result<(), InvariantError>) {
+    if let Err(error) = result {
+        panic!("step {}: {}", step, error);
+    }
+}
+
+//  Internal; asserts that the cursor agrees with the model cursor.
+#[track_caller]
+fn assert_cursor<T>(step: usize, index: Option<usize>, current: Option<&T>, peek: (Option<&T>, Option<&T>), model: &VecDeque<T>, model_index: Option<usize>)
+where
+    T: Debug + PartialEq,
+{
+    let length = model.len();
+
+    assert_eq!(model_index, index, "step {}: cursor index", step);
+    assert_eq!(model_at(model, model_index), current, "step {}: cursor current", step);
+    assert_eq!(model_at(model, next_index(model_index, length)), peek.1, "step {}: cursor peek_next", step);
+    assert_eq!(model_at(model, prev_index(model_index, length)), peek.0, "step {}: cursor peek_prev", step);
+}
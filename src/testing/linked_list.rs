@@ -0,0 +1,346 @@
+//! Model-based testing of `LinkedList` and its cursors.
+
+use core::fmt::Debug;
+
+use std::{
+    collections::VecDeque,
+    panic::{self, AssertUnwindSafe},
+    vec::Vec,
+};
+
+use ghost_cell::GhostToken;
+use proptest::{collection, prelude::*, strategy::BoxedStrategy};
+
+use crate::linked_list::LinkedList;
+
+use super::{assert_cursor, assert_elements, assert_invariants, next_index, prev_index, reduce};
+
+/// An operation on a `LinkedList`.
+#[derive(Clone, Debug, Eq, Hash, PartialEq, arbitrary::Arbitrary)]
+pub enum Op<T> {
+    /// Pushes the element at the front of the list.
+    PushFront(T),
+    /// Pushes the element at the back of the list.
+    PushBack(T),
+    /// Pops the front element of the list.
+    PopFront,
+    /// Pops the back element of the list.
+    PopBack,
+    /// Clears the list.
+    Clear,
+    /// Applies the operations to a cursor, starting from the front or back of the list.
+    Cursor {
+        /// Whether the cursor starts from the back.
+        back: bool,
+        /// The operations to apply.
+        ops: Vec<CursorOp>,
+    },
+    /// Appends the elements at the back of the list.
+    Append(Vec<T>),
+    /// Prepends the elements at the front of the list.
+    Prepend(Vec<T>),
+    /// Splits the list at the given index, possibly out of bounds.
+    SplitOff(usize),
+    /// Removes the element at the given index, possibly out of bounds.
+    Remove(usize),
+    /// Applies the operations to a mutable cursor, starting from the front or back of the list.
+    CursorMut {
+        /// Whether the cursor starts from the back.
+        back: bool,
+        /// The operations to apply.
+        ops: Vec<CursorMutOp<T>>,
+    },
+}
+
+/// A movement of a cursor over a `LinkedList`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, arbitrary::Arbitrary)]
+pub enum CursorOp {
+    /// Moves to the next element.
+    MoveNext,
+    /// Moves to the previous element.
+    MovePrev,
+}
+
+/// An operation on a mutable cursor over a `LinkedList`.
+#[derive(Clone, Debug, Eq, Hash, PartialEq, arbitrary::Arbitrary)]
+pub enum CursorMutOp<T> {
+    /// Moves the cursor.
+    Move(CursorOp),
+    /// Replaces the current element, if any.
+    Replace(T),
+}
+
+/// Replays the operations against both a `LinkedList` and a `VecDeque` model.
+///
+/// After each operation, the elements, length, front and back of the list are compared to those of the model, and the
+/// invariants of the list are checked. Within a cursor operation, the current element of the cursor and its neighbours
+/// are compared to those of the model cursor after each movement or mutation.
+///
+/// #   Panics
+///
+/// If the list and the model disagree, or an invariant is violated.
+pub fn replay<T>(ops: &[Op<T>])
+where
+    T: Clone + Debug + PartialEq,
+{
+    GhostToken::new(|mut token| {
+        let mut list = LinkedList::new();
+
+        //  Lists split off, or created for appending, are kept here so they are cleared even if an assertion fails.
+        let mut spares = Vec::new();
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            let mut model = VecDeque::new();
+
+            for (step, op) in ops.iter().enumerate() {
+                apply(step, op, &mut list, &mut spares, &mut model, &mut token);
+
+                assert_elements(step, list.iter(&token), &model);
+                assert_eq!(model.len(), list.len(), "step {}: len", step);
+                assert_eq!(model.is_empty(), list.is_empty(), "step {}: is_empty", step);
+                assert_eq!(model.front(), list.front(&token), "step {}: front", step);
+                assert_eq!(model.back(), list.back(&token), "step {}: back", step);
+                assert_invariants(step, list.check_invariants(&token));
+            }
+        }));
+
+        list.clear(&mut token);
+
+        for spare in &mut spares {
+            spare.clear(&mut token);
+        }
+
+        if let Err(payload) = result {
+            panic::resume_unwind(payload);
+        }
+    });
+}
+
+//
+//  Implementation
+//
+
+fn apply<'brand, T>(
+    step: usize,
+    op: &Op<T>,
+    list: &mut LinkedList<'brand, T>,
+    spares: &mut Vec<LinkedList<'brand, T>>,
+    model: &mut VecDeque<T>,
+    token: &mut GhostToken<'brand>,
+)
+where
+    T: Clone + Debug + PartialEq,
+{
+    match op {
+        Op::PushFront(value) => {
+            list.push_front(value.clone(), token);
+            model.push_front(value.clone());
+        },
+        Op::PushBack(value) => {
+            list.push_back(value.clone(), token);
+            model.push_back(value.clone());
+        },
+        Op::PopFront => assert_eq!(model.pop_front(), list.pop_front(token), "step {}: pop_front", step),
+        Op::PopBack => assert_eq!(model.pop_back(), list.pop_back(token), "step {}: pop_back", step),
+        Op::Clear => {
+            list.clear(token);
+            model.clear();
+        },
+        Op::Cursor { back, ops } => {
+            let mut cursor = if *back { list.cursor_back(token) } else { list.cursor_front(token) };
+            let mut index = start_index(*back, model.len());
+
+            assert_cursor(step, cursor.index(), cursor.current(), (cursor.peek_prev(), cursor.peek_next()), model, index);
+
+            for op in ops {
+                index = move_model(*op, index, model.len());
+
+                match op {
+                    CursorOp::MoveNext => cursor.move_next(),
+                    CursorOp::MovePrev => cursor.move_prev(),
+                }
+
+                assert_cursor(step, cursor.index(), cursor.current(), (cursor.peek_prev(), cursor.peek_next()), model, index);
+            }
+        },
+        Op::Append(values) => {
+            let spare = new_spare(values, spares, token);
+            list.append(&mut spares[spare], token);
+            model.extend(values.iter().cloned());
+
+            assert!(spares[spare].is_empty(), "step {}: append left elements behind", step);
+        },
+        Op::Prepend(values) => {
+            let spare = new_spare(values, spares, token);
+            list.prepend(&mut spares[spare], token);
+
+            for value in values.iter().rev() {
+                model.push_front(value.clone());
+            }
+
+            assert!(spares[spare].is_empty(), "step {}: prepend left elements behind", step);
+        },
+        Op::SplitOff(at) => {
+            //  Out of bounds indices are exercised too.
+            let at = reduce(*at, model.len() + 2);
+
+            let split = list.split_off(at, token);
+
+            assert_eq!(at <= model.len(), split.is_some(), "step {}: split_off", step);
+
+            if let Some(split) = split {
+                spares.push(split);
+
+                let expected = model.split_off(at);
+
+                assert_elements(step, spares[spares.len() - 1].iter(token), &expected);
+            }
+        },
+        Op::Remove(at) => {
+            //  Out of bounds indices are exercised too.
+            let at = reduce(*at, model.len() + 1);
+
+            assert_eq!(model.remove(at), list.remove(at, token), "step {}: remove", step);
+        },
+        Op::CursorMut { back, ops } => {
+            let mut cursor = if *back { list.cursor_back_mut(token) } else { list.cursor_front_mut(token) };
+            let mut index = start_index(*back, model.len());
+
+            for op in ops {
+                match op {
+                    CursorMutOp::Move(op) => {
+                        index = move_model(*op, index, model.len());
+
+                        match op {
+                            CursorOp::MoveNext => cursor.move_next(),
+                            CursorOp::MovePrev => cursor.move_prev(),
+                        }
+                    },
+                    CursorMutOp::Replace(value) => {
+                        let current = cursor.current();
+
+                        assert_eq!(index.is_some(), current.is_some(), "step {}: replace", step);
+
+                        if let (Some(index), Some(current)) = (index, current) {
+                            *current = value.clone();
+                            model[index] = value.clone();
+                        }
+                    },
+                }
+
+                let current = cursor.current().cloned();
+
+                assert_cursor(step, cursor.index(), current.as_ref(), (cursor.peek_prev(), cursor.peek_next()), model, index);
+            }
+        },
+    }
+}
+
+//  Internal; returns the index of the model cursor, starting from the front or back.
+fn start_index(back: bool, length: usize) -> Option<usize> {
+    if back { length.checked_sub(1) } else if length > 0 { Some(0) } else { None }
+}
+
+//  Internal; returns the index of the model cursor after the move.
+fn move_model(op: CursorOp, index: Option<usize>, length: usize) -> Option<usize> {
+    match op {
+        CursorOp::MoveNext => next_index(index, length),
+        CursorOp::MovePrev => prev_index(index, length),
+    }
+}
+
+fn new_spare<'brand, T>(values: &[T], spares: &mut Vec<LinkedList<'brand, T>>, token: &mut GhostToken<'brand>) -> usize
+where
+    T: Clone,
+{
+    let mut spare = LinkedList::new();
+
+    for value in values {
+        spare.push_back(value.clone(), token);
+    }
+
+    spares.push(spare);
+
+    spares.len() - 1
+}
+
+//
+//  Strategies
+//
+
+impl<T> proptest::arbitrary::Arbitrary for Op<T>
+where
+    T: proptest::arbitrary::Arbitrary + Clone + 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        prop_oneof![
+            any::<T>().prop_map(Op::PushFront),
+            any::<T>().prop_map(Op::PushBack),
+            Just(Op::PopFront),
+            Just(Op::PopBack),
+            Just(Op::Clear),
+            (any::<bool>(), collection::vec(any::<CursorOp>(), 0..8)).prop_map(|(back, ops)| Op::Cursor { back, ops }),
+            collection::vec(any::<T>(), 0..4).prop_map(Op::Append),
+            collection::vec(any::<T>(), 0..4).prop_map(Op::Prepend),
+            any::<usize>().prop_map(Op::SplitOff),
+            any::<usize>().prop_map(Op::Remove),
+            (any::<bool>(), collection::vec(any::<CursorMutOp<T>>(), 0..8))
+                .prop_map(|(back, ops)| Op::CursorMut { back, ops }),
+        ]
+        .boxed()
+    }
+}
+
+impl proptest::arbitrary::Arbitrary for CursorOp {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        prop_oneof![Just(CursorOp::MoveNext), Just(CursorOp::MovePrev)].boxed()
+    }
+}
+
+impl<T> proptest::arbitrary::Arbitrary for CursorMutOp<T>
+where
+    T: proptest::arbitrary::Arbitrary + Clone + 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        prop_oneof![
+            any::<CursorOp>().prop_map(CursorMutOp::Move),
+            any::<T>().prop_map(CursorMutOp::Replace),
+        ]
+        .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+use super::*;
+
+proptest! {
+    #[test]
+    fn list_replay(ops in collection::vec(any::<Op<u8>>(), 0..32)) {
+        replay(&ops);
+    }
+}
+
+#[test]
+fn list_replay_ends() {
+    use CursorOp::*;
+
+    replay(&[
+        Op::Cursor { back: false, ops: vec![MoveNext, MovePrev] },
+        Op::PushBack(1),
+        Op::PushBack(2),
+        Op::Cursor { back: true, ops: vec![MoveNext, MovePrev, MovePrev, MoveNext] },
+    ]);
+}
+
+} // mod tests
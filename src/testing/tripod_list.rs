@@ -0,0 +1,500 @@
+//! Model-based testing of `TripodList` and its cursors.
+
+use core::fmt::Debug;
+
+use std::{
+    collections::VecDeque,
+    panic::{self, AssertUnwindSafe},
+    vec::Vec,
+};
+
+use ghost_cell::GhostToken;
+use proptest::{collection, prelude::*, strategy::BoxedStrategy};
+
+use crate::tripod_list::{CursorMut, TripodList};
+
+use super::{assert_cursor, assert_elements, assert_invariants, next_index, prev_index, reduce};
+
+/// An operation on a `TripodList`.
+#[derive(Clone, Debug, Eq, Hash, PartialEq, arbitrary::Arbitrary)]
+pub enum Op<T> {
+    /// Pushes the element at the front of the list.
+    PushFront(T),
+    /// Pushes the element at the back of the list.
+    PushBack(T),
+    /// Pops the front element of the list.
+    PopFront,
+    /// Pops the back element of the list.
+    PopBack,
+    /// Appends the elements at the back of the list.
+    Append(Vec<T>),
+    /// Prepends the elements at the front of the list.
+    Prepend(Vec<T>),
+    /// Splits the list at the given index; skipped if the list is empty.
+    SplitOff(usize),
+    /// Removes the element at the given index; skipped if the list is empty.
+    Remove(usize),
+    /// Clears the list.
+    Clear,
+    /// Applies the operations to a cursor, starting from the front or back of the list.
+    Cursor {
+        /// Whether the cursor starts from the back.
+        back: bool,
+        /// The operations to apply.
+        ops: Vec<CursorOp>,
+    },
+    /// Applies the operations to a mutable cursor, starting from the front or back of the list.
+    CursorMut {
+        /// Whether the cursor starts from the back.
+        back: bool,
+        /// The operations to apply.
+        ops: Vec<CursorMutOp<T>>,
+    },
+}
+
+/// A movement of a cursor over a `TripodList`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, arbitrary::Arbitrary)]
+pub enum CursorOp {
+    /// Moves to the next element.
+    MoveNext,
+    /// Moves to the previous element.
+    MovePrev,
+}
+
+/// An operation on a mutable cursor over a `TripodList`.
+#[derive(Clone, Debug, Eq, Hash, PartialEq, arbitrary::Arbitrary)]
+pub enum CursorMutOp<T> {
+    /// Moves the cursor.
+    Move(CursorOp),
+    /// Replaces the current element, if any.
+    Replace(T),
+    /// Inserts the element after the current one.
+    InsertAfter(T),
+    /// Inserts the element before the current one.
+    InsertBefore(T),
+    /// Removes the current element.
+    RemoveCurrent,
+    /// Removes the current element, as a list.
+    RemoveCurrentAsList,
+    /// Splices the elements after the current one.
+    SpliceAfter(Vec<T>),
+    /// Splices the elements before the current one.
+    SpliceBefore(Vec<T>),
+    /// Splits the list after the current element.
+    SplitAfter,
+    /// Splits the list before the current element.
+    SplitBefore,
+}
+
+/// Replays the operations against both a `TripodList` and a `VecDeque` model.
+///
+/// After each operation, the elements, length, front and back of the list are compared to those of the model, and the
+/// invariants of the list are checked. Within a cursor operation, the position of the cursor and its neighbours are
+/// compared to those of the model cursor after each movement or mutation.
+///
+/// #   Panics
+///
+/// If the list and the model disagree, or an invariant is violated.
+pub fn replay<T>(ops: &[Op<T>])
+where
+    T: Clone + Debug + PartialEq,
+{
+    GhostToken::new(|mut token| {
+        let mut list = TripodList::new();
+
+        //  Lists split off, or created for splicing, are kept here so they are cleared even if an assertion fails.
+        let mut spares = Vec::new();
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            let mut model = VecDeque::new();
+
+            for (step, op) in ops.iter().enumerate() {
+                apply(step, op, &mut list, &mut spares, &mut model, &mut token);
+
+                assert_elements(step, list.iter(&token), &model);
+                assert_eq!(model.len(), list.len(), "step {}: len", step);
+                assert_eq!(model.front(), list.front(&token), "step {}: front", step);
+                assert_eq!(model.back(), list.back(&token), "step {}: back", step);
+                assert_invariants(step, list.check_invariants(&token));
+            }
+        }));
+
+        list.clear(&mut token);
+
+        for spare in &mut spares {
+            spare.clear(&mut token);
+        }
+
+        if let Err(payload) = result {
+            panic::resume_unwind(payload);
+        }
+    });
+}
+
+//
+//  Implementation
+//
+
+//  Internal; a list whose elements are compared with the expected ones once the token is available.
+struct Pending<T> {
+    step: usize,
+    spare: usize,
+    expected: VecDeque<T>,
+}
+
+fn apply<'brand, T>(
+    step: usize,
+    op: &Op<T>,
+    list: &mut TripodList<'brand, T>,
+    spares: &mut Vec<TripodList<'brand, T>>,
+    model: &mut VecDeque<T>,
+    token: &mut GhostToken<'brand>,
+)
+where
+    T: Clone + Debug + PartialEq,
+{
+    match op {
+        Op::PushFront(value) => {
+            list.push_front(value.clone(), token);
+            model.push_front(value.clone());
+        },
+        Op::PushBack(value) => {
+            list.push_back(value.clone(), token);
+            model.push_back(value.clone());
+        },
+        Op::PopFront => assert_eq!(model.pop_front(), list.pop_front(token), "step {}: pop_front", step),
+        Op::PopBack => assert_eq!(model.pop_back(), list.pop_back(token), "step {}: pop_back", step),
+        Op::Append(values) => {
+            let spare = new_spare(values, spares, token);
+            list.append(&mut spares[spare], token);
+            model.extend(values.iter().cloned());
+
+            assert!(spares[spare].is_empty(), "step {}: append left elements behind", step);
+        },
+        Op::Prepend(values) => {
+            let spare = new_spare(values, spares, token);
+            list.prepend(&mut spares[spare], token);
+
+            for value in values.iter().rev() {
+                model.push_front(value.clone());
+            }
+
+            assert!(spares[spare].is_empty(), "step {}: prepend left elements behind", step);
+        },
+        Op::SplitOff(at) => {
+            if model.is_empty() {
+                return;
+            }
+
+            let at = reduce(*at, model.len());
+
+            spares.push(list.split_off(at, token));
+
+            let expected = model.split_off(at);
+
+            assert_elements(step, spares[spares.len() - 1].iter(token), &expected);
+        },
+        Op::Remove(at) => {
+            if model.is_empty() {
+                return;
+            }
+
+            let at = reduce(*at, model.len());
+
+            assert_eq!(model.remove(at), Some(list.remove(at, token)), "step {}: remove", step);
+        },
+        Op::Clear => {
+            list.clear(token);
+            model.clear();
+        },
+        Op::Cursor { back, ops } => {
+            let mut cursor = if *back { list.cursor_back(token) } else { list.cursor_front(token) };
+            let mut index = if *back { model.len().checked_sub(1) } else { next_index(None, model.len()) };
+
+            assert_cursor(step, cursor.index(), cursor.current(), (cursor.peek_prev(), cursor.peek_next()), model, index);
+
+            for op in ops {
+                index = move_model(*op, index, model.len());
+
+                match op {
+                    CursorOp::MoveNext => cursor.move_next(),
+                    CursorOp::MovePrev => cursor.move_prev(),
+                }
+
+                assert_cursor(step, cursor.index(), cursor.current(), (cursor.peek_prev(), cursor.peek_next()), model, index);
+            }
+        },
+        Op::CursorMut { back, ops } => {
+            //  Lists to splice are created ahead, as the cursor borrows the token.
+            let mut splices = Vec::new();
+
+            for op in ops {
+                if let CursorMutOp::SpliceAfter(values) | CursorMutOp::SpliceBefore(values) = op {
+                    splices.push(new_spare(values, spares, token));
+                }
+            }
+
+            let mut pending = Vec::new();
+
+            {
+                let mut cursor = if *back { list.cursor_back_mut(token) } else { list.cursor_front_mut(token) };
+                let mut index = if *back { model.len().checked_sub(1) } else { next_index(None, model.len()) };
+                let mut splices = splices.iter();
+
+                assert_cursor_mut(step, &cursor, model, index);
+
+                for op in ops {
+                    index = apply_cursor_mut(step, op, &mut cursor, &mut splices, spares, &mut pending, model, index);
+
+                    assert_cursor_mut(step, &cursor, model, index);
+                }
+            }
+
+            for spare in splices {
+                assert!(spares[spare].is_empty(), "step {}: splice left elements behind", step);
+            }
+
+            for Pending { step, spare, expected } in pending {
+                assert_elements(step, spares[spare].iter(token), &expected);
+                assert_invariants(step, spares[spare].check_invariants(token));
+            }
+        },
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn apply_cursor_mut<'a, 'brand, T>(
+    step: usize,
+    op: &CursorMutOp<T>,
+    cursor: &mut CursorMut<'a, 'brand, T>,
+    splices: &mut std::slice::Iter<'_, usize>,
+    spares: &mut Vec<TripodList<'brand, T>>,
+    pending: &mut Vec<Pending<T>>,
+    model: &mut VecDeque<T>,
+    index: Option<usize>,
+) -> Option<usize>
+where
+    T: Clone + Debug + PartialEq,
+{
+    match op {
+        CursorMutOp::Move(op) => {
+            match op {
+                CursorOp::MoveNext => cursor.move_next(),
+                CursorOp::MovePrev => cursor.move_prev(),
+            }
+
+            move_model(*op, index, model.len())
+        },
+        CursorMutOp::Replace(value) => {
+            let current = cursor.current();
+
+            assert_eq!(index.is_some(), current.is_some(), "step {}: replace", step);
+
+            if let (Some(index), Some(current)) = (index, current) {
+                *current = value.clone();
+                model[index] = value.clone();
+            }
+
+            index
+        },
+        CursorMutOp::InsertAfter(value) => {
+            cursor.insert_after(value.clone());
+            model.insert(index.map_or(0, |index| index + 1), value.clone());
+
+            index
+        },
+        CursorMutOp::InsertBefore(value) => {
+            cursor.insert_before(value.clone());
+            model.insert(index.unwrap_or(model.len()), value.clone());
+
+            index.map(|index| index + 1)
+        },
+        CursorMutOp::RemoveCurrent => {
+            let expected = index.map(|index| model.remove(index).expect("Valid index"));
+
+            assert_eq!(expected, cursor.remove_current(), "step {}: remove_current", step);
+
+            index.filter(|index| *index < model.len())
+        },
+        CursorMutOp::RemoveCurrentAsList => {
+            let removed = cursor.remove_current_as_list();
+
+            assert_eq!(index.is_some(), removed.is_some(), "step {}: remove_current_as_list", step);
+
+            if let (Some(index), Some(removed)) = (index, removed) {
+                let expected = model.drain(index..=index).collect();
+
+                pending.push(Pending { step, spare: spares.len(), expected });
+                spares.push(removed);
+            }
+
+            index.filter(|index| *index < model.len())
+        },
+        CursorMutOp::SpliceAfter(values) => {
+            let spare = *splices.next().expect("Splice list");
+
+            cursor.splice_after(&mut spares[spare]);
+
+            let at = index.map_or(0, |index| index + 1);
+
+            for (offset, value) in values.iter().enumerate() {
+                model.insert(at + offset, value.clone());
+            }
+
+            index
+        },
+        CursorMutOp::SpliceBefore(values) => {
+            let spare = *splices.next().expect("Splice list");
+
+            cursor.splice_before(&mut spares[spare]);
+
+            let at = index.unwrap_or(model.len());
+
+            for (offset, value) in values.iter().enumerate() {
+                model.insert(at + offset, value.clone());
+            }
+
+            index.map(|index| index + values.len())
+        },
+        CursorMutOp::SplitAfter => {
+            let at = index.map_or(0, |index| index + 1);
+            let expected = model.split_off(at);
+
+            pending.push(Pending { step, spare: spares.len(), expected });
+            spares.push(cursor.split_after());
+
+            index
+        },
+        CursorMutOp::SplitBefore => {
+            let at = index.unwrap_or(model.len());
+            let expected = model.drain(..at).collect();
+
+            pending.push(Pending { step, spare: spares.len(), expected });
+            spares.push(cursor.split_before());
+
+            index.map(|_| 0)
+        },
+    }
+}
+
+fn move_model(op: CursorOp, index: Option<usize>, length: usize) -> Option<usize> {
+    match op {
+        CursorOp::MoveNext => next_index(index, length),
+        CursorOp::MovePrev => prev_index(index, length),
+    }
+}
+
+#[track_caller]
+fn assert_cursor_mut<T>(step: usize, cursor: &CursorMut<'_, '_, T>, model: &VecDeque<T>, index: Option<usize>)
+where
+    T: Debug + PartialEq,
+{
+    let cursor = cursor.as_cursor();
+
+    assert_cursor(step, cursor.index(), cursor.current(), (cursor.peek_prev(), cursor.peek_next()), model, index);
+}
+
+fn new_spare<'brand, T>(values: &[T], spares: &mut Vec<TripodList<'brand, T>>, token: &mut GhostToken<'brand>) -> usize
+where
+    T: Clone,
+{
+    let mut spare = TripodList::new();
+
+    for value in values {
+        spare.push_back(value.clone(), token);
+    }
+
+    spares.push(spare);
+
+    spares.len() - 1
+}
+
+//
+//  Strategies
+//
+
+impl<T> proptest::arbitrary::Arbitrary for Op<T>
+where
+    T: proptest::arbitrary::Arbitrary + Clone + 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        prop_oneof![
+            any::<T>().prop_map(Op::PushFront),
+            any::<T>().prop_map(Op::PushBack),
+            Just(Op::PopFront),
+            Just(Op::PopBack),
+            collection::vec(any::<T>(), 0..4).prop_map(Op::Append),
+            collection::vec(any::<T>(), 0..4).prop_map(Op::Prepend),
+            any::<usize>().prop_map(Op::SplitOff),
+            any::<usize>().prop_map(Op::Remove),
+            Just(Op::Clear),
+            (any::<bool>(), collection::vec(any::<CursorOp>(), 0..8)).prop_map(|(back, ops)| Op::Cursor { back, ops }),
+            (any::<bool>(), collection::vec(any::<CursorMutOp<T>>(), 0..8))
+                .prop_map(|(back, ops)| Op::CursorMut { back, ops }),
+        ]
+        .boxed()
+    }
+}
+
+impl proptest::arbitrary::Arbitrary for CursorOp {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        prop_oneof![Just(CursorOp::MoveNext), Just(CursorOp::MovePrev)].boxed()
+    }
+}
+
+impl<T> proptest::arbitrary::Arbitrary for CursorMutOp<T>
+where
+    T: proptest::arbitrary::Arbitrary + Clone + 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        prop_oneof![
+            any::<CursorOp>().prop_map(CursorMutOp::Move),
+            any::<T>().prop_map(CursorMutOp::Replace),
+            any::<T>().prop_map(CursorMutOp::InsertAfter),
+            any::<T>().prop_map(CursorMutOp::InsertBefore),
+            Just(CursorMutOp::RemoveCurrent),
+            Just(CursorMutOp::RemoveCurrentAsList),
+            collection::vec(any::<T>(), 0..4).prop_map(CursorMutOp::SpliceAfter),
+            collection::vec(any::<T>(), 0..4).prop_map(CursorMutOp::SpliceBefore),
+            Just(CursorMutOp::SplitAfter),
+            Just(CursorMutOp::SplitBefore),
+        ]
+        .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+use super::*;
+
+proptest! {
+    #[test]
+    fn list_replay(ops in collection::vec(any::<Op<u8>>(), 0..32)) {
+        replay(&ops);
+    }
+}
+
+#[test]
+fn list_replay_twilight() {
+    use CursorMutOp::*;
+    use CursorOp::*;
+
+    replay(&[
+        Op::CursorMut { back: false, ops: vec![InsertAfter(1), InsertBefore(2), Move(MovePrev), SplitAfter] },
+        Op::PushBack(3),
+        Op::CursorMut { back: true, ops: vec![Move(MoveNext), SpliceBefore(vec![4, 5]), SpliceAfter(vec![6]), SplitBefore] },
+        Op::Cursor { back: false, ops: vec![MovePrev, MovePrev, MoveNext, MoveNext] },
+    ]);
+}
+
+} // mod tests
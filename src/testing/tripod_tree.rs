@@ -0,0 +1,632 @@
+//! Model-based testing of `TripodTree` and its cursors.
+//!
+//! Movements which depend on the shape of the tree, such as moving up or down, cannot be predicted by the model: the
+//! model cursor adopts the index reported by the cursor instead, and checks that the elements agree at that index.
+
+use core::fmt::Debug;
+
+use std::{
+    collections::VecDeque,
+    panic::{self, AssertUnwindSafe},
+    vec::Vec,
+};
+
+use ghost_cell::GhostToken;
+use proptest::{collection, prelude::*, strategy::BoxedStrategy};
+
+use crate::tripod_tree::{Cursor, CursorMut, TripodTree};
+
+use super::{assert_cursor, assert_elements, assert_invariants, next_index, prev_index, reduce};
+
+/// An operation on a `TripodTree`.
+#[derive(Clone, Debug, Eq, Hash, PartialEq, arbitrary::Arbitrary)]
+pub enum Op<T> {
+    /// Pushes the element at the front of the tree.
+    PushFront(T),
+    /// Pushes the element at the back of the tree.
+    PushBack(T),
+    /// Pops the front element of the tree.
+    PopFront,
+    /// Pops the back element of the tree.
+    PopBack,
+    /// Appends the elements at the back of the tree.
+    Append(Vec<T>),
+    /// Prepends the elements at the front of the tree.
+    Prepend(Vec<T>),
+    /// Splits the tree at the given index.
+    SplitOff(usize),
+    /// Splits the range between the given indices out of the tree.
+    Split(usize, usize),
+    /// Looks up the element at the given index, possibly out of bounds.
+    At(usize),
+    /// Replaces the element at the given index, possibly out of bounds.
+    #[cfg(feature = "experimental-ghost-cursor")]
+    AtMut(usize, T),
+    /// Clears the tree.
+    Clear,
+    /// Applies the operations to a cursor, starting from the root of the tree.
+    Cursor(Vec<CursorOp>),
+    /// Applies the operations to a mutable cursor, starting from the root of the tree.
+    CursorMut(Vec<CursorMutOp<T>>),
+}
+
+/// A movement of a cursor over a `TripodTree`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, arbitrary::Arbitrary)]
+pub enum CursorOp {
+    /// Moves to the parent element.
+    MoveUp,
+    /// Moves to the left child.
+    MoveLeft,
+    /// Moves to the right child.
+    MoveRight,
+    /// Moves to the root.
+    MoveToRoot,
+    /// Moves to the next element.
+    MoveNext,
+    /// Moves to the previous element.
+    MovePrev,
+    /// Attempts to move to the next element.
+    TryMoveNext,
+    /// Attempts to move to the previous element.
+    TryMovePrev,
+    /// Moves to the given index, reduced to `0..=len`.
+    MoveTo(usize),
+    /// Moves to the front element.
+    MoveToFront,
+    /// Moves to the back element.
+    MoveToBack,
+}
+
+/// An operation on a mutable cursor over a `TripodTree`.
+#[derive(Clone, Debug, Eq, Hash, PartialEq, arbitrary::Arbitrary)]
+pub enum CursorMutOp<T> {
+    /// Moves the cursor.
+    Move(CursorOp),
+    /// Replaces the current element, if any.
+    Replace(T),
+    /// Inserts the element after the current one.
+    InsertAfter(T),
+    /// Inserts the element before the current one.
+    InsertBefore(T),
+    /// Removes the current element.
+    RemoveCurrent,
+    /// Removes the current element, as a tree.
+    RemoveCurrentAsTree,
+    /// Splices the elements after the current one.
+    SpliceAfter(Vec<T>),
+    /// Splices the elements before the current one.
+    SpliceBefore(Vec<T>),
+    /// Splits the tree after the current element.
+    SplitAfter,
+    /// Splits the tree before the current element.
+    SplitBefore,
+}
+
+/// Replays the operations against both a `TripodTree` and a `VecDeque` model.
+///
+/// After each operation, the elements, length, front and back of the tree are compared to those of the model, and the
+/// invariants of the tree are checked. Within a cursor operation, the position of the cursor and its neighbours are
+/// compared to those of the model cursor after each movement or mutation.
+///
+/// #   Panics
+///
+/// If the tree and the model disagree, or an invariant is violated.
+pub fn replay<T>(ops: &[Op<T>])
+where
+    T: Clone + Debug + PartialEq,
+{
+    GhostToken::new(|mut token| {
+        let mut tree = TripodTree::new();
+
+        //  Trees split off, or created for splicing, are kept here so they are cleared even if an assertion fails.
+        let mut spares = Vec::new();
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            let mut model = VecDeque::new();
+
+            for (step, op) in ops.iter().enumerate() {
+                apply(step, op, &mut tree, &mut spares, &mut model, &mut token);
+
+                assert_elements(step, tree.iter(&token), &model);
+                assert_eq!(model.len(), tree.len(&token), "step {}: len", step);
+                assert_eq!(model.front(), tree.front(&token), "step {}: front", step);
+                assert_eq!(model.back(), tree.back(&token), "step {}: back", step);
+                assert_invariants(step, tree.check_invariants(&token));
+            }
+        }));
+
+        tree.clear(&mut token);
+
+        for spare in &mut spares {
+            spare.clear(&mut token);
+        }
+
+        if let Err(payload) = result {
+            panic::resume_unwind(payload);
+        }
+    });
+}
+
+//
+//  Implementation
+//
+
+//  Internal; a tree whose elements are compared with the expected ones once the token is available.
+struct Pending<T> {
+    step: usize,
+    spare: usize,
+    expected: VecDeque<T>,
+}
+
+fn apply<'brand, T>(
+    step: usize,
+    op: &Op<T>,
+    tree: &mut TripodTree<'brand, T>,
+    spares: &mut Vec<TripodTree<'brand, T>>,
+    model: &mut VecDeque<T>,
+    token: &mut GhostToken<'brand>,
+)
+where
+    T: Clone + Debug + PartialEq,
+{
+    match op {
+        Op::PushFront(value) => {
+            tree.push_front(value.clone(), token);
+            model.push_front(value.clone());
+        },
+        Op::PushBack(value) => {
+            tree.push_back(value.clone(), token);
+            model.push_back(value.clone());
+        },
+        Op::PopFront => assert_eq!(model.pop_front(), tree.pop_front(token), "step {}: pop_front", step),
+        Op::PopBack => assert_eq!(model.pop_back(), tree.pop_back(token), "step {}: pop_back", step),
+        Op::Append(values) => {
+            let spare = new_spare(values, spares, token);
+            tree.append(&mut spares[spare], token);
+            model.extend(values.iter().cloned());
+
+            assert!(spares[spare].is_empty(), "step {}: append left elements behind", step);
+        },
+        Op::Prepend(values) => {
+            let spare = new_spare(values, spares, token);
+            tree.prepend(&mut spares[spare], token);
+
+            for value in values.iter().rev() {
+                model.push_front(value.clone());
+            }
+
+            assert!(spares[spare].is_empty(), "step {}: prepend left elements behind", step);
+        },
+        Op::SplitOff(at) => {
+            let at = reduce(*at, model.len() + 1);
+
+            spares.push(tree.split_off(at, token));
+
+            let expected = model.split_off(at);
+
+            check_spare(step, &spares[spares.len() - 1], &expected, token);
+        },
+        Op::Split(start, end) => {
+            let (start, end) = (reduce(*start, model.len() + 1), reduce(*end, model.len() + 1));
+            let (start, end) = (start.min(end), start.max(end));
+
+            spares.push(tree.split(start..end, token));
+
+            let expected = model.drain(start..end).collect();
+
+            check_spare(step, &spares[spares.len() - 1], &expected, token);
+        },
+        Op::At(at) => {
+            let at = reduce(*at, model.len() + 1);
+
+            assert_eq!(model.get(at), tree.at(at, token), "step {}: at", step);
+        },
+        #[cfg(feature = "experimental-ghost-cursor")]
+        Op::AtMut(at, value) => {
+            let at = reduce(*at, model.len() + 1);
+            let element = tree.at_mut(at, token);
+
+            assert_eq!(at < model.len(), element.is_some(), "step {}: at_mut", step);
+
+            if let Some(element) = element {
+                *element = value.clone();
+                model[at] = value.clone();
+            }
+        },
+        Op::Clear => {
+            tree.clear(token);
+            model.clear();
+        },
+        Op::Cursor(ops) => {
+            let mut cursor = tree.cursor(token);
+            let mut index = cursor.index();
+
+            assert_tree_cursor(step, &cursor, model, index);
+
+            for op in ops {
+                index = move_cursor(step, *op, &mut cursor, model, index);
+
+                assert_tree_cursor(step, &cursor, model, index);
+            }
+        },
+        Op::CursorMut(ops) => {
+            //  Trees to splice are created ahead, as the cursor borrows the token.
+            let mut splices = Vec::new();
+
+            for op in ops {
+                if let CursorMutOp::SpliceAfter(values) | CursorMutOp::SpliceBefore(values) = op {
+                    splices.push(new_spare(values, spares, token));
+                }
+            }
+
+            let mut pending = Vec::new();
+
+            {
+                let mut cursor = tree.cursor_mut(token);
+                let mut index = cursor.index();
+                let mut splices = splices.iter();
+
+                assert_tree_cursor(step, &cursor.as_cursor(), model, index);
+
+                for op in ops {
+                    index = apply_cursor_mut(step, op, &mut cursor, &mut splices, spares, &mut pending, model, index);
+
+                    assert_tree_cursor(step, &cursor.as_cursor(), model, index);
+                }
+            }
+
+            for spare in splices {
+                assert!(spares[spare].is_empty(), "step {}: splice left elements behind", step);
+            }
+
+            for Pending { step, spare, expected } in pending {
+                check_spare(step, &spares[spare], &expected, token);
+            }
+        },
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn apply_cursor_mut<'a, 'brand, T>(
+    step: usize,
+    op: &CursorMutOp<T>,
+    cursor: &mut CursorMut<'a, 'brand, T>,
+    splices: &mut std::slice::Iter<'_, usize>,
+    spares: &mut Vec<TripodTree<'brand, T>>,
+    pending: &mut Vec<Pending<T>>,
+    model: &mut VecDeque<T>,
+    index: Option<usize>,
+) -> Option<usize>
+where
+    T: Clone + Debug + PartialEq,
+{
+    match op {
+        CursorMutOp::Move(op) => move_cursor_mut(step, *op, cursor, model, index),
+        CursorMutOp::Replace(value) => {
+            let current = cursor.current();
+
+            assert_eq!(index.is_some(), current.is_some(), "step {}: replace", step);
+
+            if let (Some(index), Some(current)) = (index, current) {
+                *current = value.clone();
+                model[index] = value.clone();
+            }
+
+            index
+        },
+        CursorMutOp::InsertAfter(value) => {
+            cursor.insert_after(value.clone());
+            model.insert(index.map_or(0, |index| index + 1), value.clone());
+
+            index
+        },
+        CursorMutOp::InsertBefore(value) => {
+            cursor.insert_before(value.clone());
+            model.insert(index.unwrap_or(model.len()), value.clone());
+
+            index.map(|index| index + 1)
+        },
+        CursorMutOp::RemoveCurrent => {
+            let expected = index.map(|index| model.remove(index).expect("Valid index"));
+
+            assert_eq!(expected, cursor.remove_current(), "step {}: remove_current", step);
+
+            index.filter(|index| *index < model.len())
+        },
+        CursorMutOp::RemoveCurrentAsTree => {
+            let expected = index.map(|index| model.drain(index..=index).collect()).unwrap_or_default();
+
+            pending.push(Pending { step, spare: spares.len(), expected });
+            spares.push(cursor.remove_current_as_tree());
+
+            index.filter(|index| *index < model.len())
+        },
+        CursorMutOp::SpliceAfter(values) => {
+            let spare = *splices.next().expect("Splice tree");
+
+            cursor.splice_after(&mut spares[spare]);
+
+            let at = index.map_or(0, |index| index + 1);
+
+            for (offset, value) in values.iter().enumerate() {
+                model.insert(at + offset, value.clone());
+            }
+
+            index
+        },
+        CursorMutOp::SpliceBefore(values) => {
+            let spare = *splices.next().expect("Splice tree");
+
+            cursor.splice_before(&mut spares[spare]);
+
+            let at = index.unwrap_or(model.len());
+
+            for (offset, value) in values.iter().enumerate() {
+                model.insert(at + offset, value.clone());
+            }
+
+            index.map(|index| index + values.len())
+        },
+        CursorMutOp::SplitAfter => {
+            let at = index.map_or(0, |index| index + 1);
+            let expected = model.split_off(at);
+
+            pending.push(Pending { step, spare: spares.len(), expected });
+            spares.push(cursor.split_after());
+
+            //  The cursor moves to the back element.
+            model.len().checked_sub(1)
+        },
+        CursorMutOp::SplitBefore => {
+            let at = index.unwrap_or(model.len());
+            let expected = model.drain(..at).collect();
+
+            pending.push(Pending { step, spare: spares.len(), expected });
+            spares.push(cursor.split_before());
+
+            //  The cursor moves to the front element.
+            next_index(None, model.len())
+        },
+    }
+}
+
+//  Internal; moves the cursor, returning the new index of the model cursor.
+fn move_cursor<T>(step: usize, op: CursorOp, cursor: &mut Cursor<'_, '_, T>, model: &VecDeque<T>, index: Option<usize>)
+    -> Option<usize>
+where
+    T: Debug + PartialEq,
+{
+    let length = model.len();
+
+    match op {
+        CursorOp::MoveUp => { cursor.move_up(); cursor.index() },
+        CursorOp::MoveLeft => { cursor.move_left(); cursor.index() },
+        CursorOp::MoveRight => { cursor.move_right(); cursor.index() },
+        CursorOp::MoveToRoot => { cursor.move_to_root(); cursor.index() },
+        CursorOp::MoveNext => { cursor.move_next(); next_index(index, length) },
+        CursorOp::MovePrev => { cursor.move_prev(); prev_index(index, length) },
+        CursorOp::TryMoveNext => {
+            let next = next_index(index, length);
+
+            assert_eq!(next.and_then(|next| model.get(next)), cursor.try_move_next(), "step {}: try_move_next", step);
+
+            next.or(index)
+        },
+        CursorOp::TryMovePrev => {
+            let prev = prev_index(index, length);
+
+            assert_eq!(prev.and_then(|prev| model.get(prev)), cursor.try_move_prev(), "step {}: try_move_prev", step);
+
+            prev.or(index)
+        },
+        CursorOp::MoveTo(at) => {
+            let at = reduce(at, length + 1);
+
+            cursor.move_to(at);
+
+            Some(at).filter(|at| *at < length)
+        },
+        CursorOp::MoveToFront => { cursor.move_to_front(); next_index(None, length) },
+        CursorOp::MoveToBack => { cursor.move_to_back(); prev_index(None, length) },
+    }
+}
+
+//  Internal; moves the cursor, returning the new index of the model cursor.
+fn move_cursor_mut<T>(step: usize, op: CursorOp, cursor: &mut CursorMut<'_, '_, T>, model: &VecDeque<T>, index: Option<usize>)
+    -> Option<usize>
+where
+    T: Debug + PartialEq,
+{
+    let length = model.len();
+
+    match op {
+        CursorOp::MoveUp => { cursor.move_up(); cursor.index() },
+        CursorOp::MoveLeft => { cursor.move_left(); cursor.index() },
+        CursorOp::MoveRight => { cursor.move_right(); cursor.index() },
+        CursorOp::MoveToRoot => { cursor.move_to_root(); cursor.index() },
+        CursorOp::MoveNext => { cursor.move_next(); next_index(index, length) },
+        CursorOp::MovePrev => { cursor.move_prev(); prev_index(index, length) },
+        CursorOp::TryMoveNext => {
+            let next = next_index(index, length);
+            let actual = cursor.try_move_next().map(|current| &*current);
+
+            assert_eq!(next.and_then(|next| model.get(next)), actual, "step {}: try_move_next", step);
+
+            next.or(index)
+        },
+        CursorOp::TryMovePrev => {
+            let prev = prev_index(index, length);
+            let actual = cursor.try_move_prev().map(|current| &*current);
+
+            assert_eq!(prev.and_then(|prev| model.get(prev)), actual, "step {}: try_move_prev", step);
+
+            prev.or(index)
+        },
+        CursorOp::MoveTo(at) => {
+            let at = reduce(at, length + 1);
+
+            cursor.move_to(at);
+
+            Some(at).filter(|at| *at < length)
+        },
+        CursorOp::MoveToFront => { cursor.move_to_front(); next_index(None, length) },
+        CursorOp::MoveToBack => { cursor.move_to_back(); prev_index(None, length) },
+    }
+}
+
+#[track_caller]
+fn assert_tree_cursor<T>(step: usize, cursor: &Cursor<'_, '_, T>, model: &VecDeque<T>, index: Option<usize>)
+where
+    T: Debug + PartialEq,
+{
+    assert_cursor(step, cursor.index(), cursor.current(), (cursor.peek_prev(), cursor.peek_next()), model, index);
+
+    if let Some(index) = index {
+        assert!(cursor.range().contains(&index), "step {}: cursor range {:?} excludes {}", step, cursor.range(), index);
+    }
+}
+
+#[track_caller]
+fn check_spare<'brand, T>(step: usize, spare: &TripodTree<'brand, T>, expected: &VecDeque<T>, token: &GhostToken<'brand>)
+where
+    T: Debug + PartialEq,
+{
+    assert_elements(step, spare.iter(token), expected);
+    assert_invariants(step, spare.check_invariants(token));
+}
+
+fn new_spare<'brand, T>(values: &[T], spares: &mut Vec<TripodTree<'brand, T>>, token: &mut GhostToken<'brand>) -> usize
+where
+    T: Clone,
+{
+    let mut spare = TripodTree::new();
+
+    for value in values {
+        spare.push_back(value.clone(), token);
+    }
+
+    spares.push(spare);
+
+    spares.len() - 1
+}
+
+//
+//  Strategies
+//
+
+impl<T> proptest::arbitrary::Arbitrary for Op<T>
+where
+    T: proptest::arbitrary::Arbitrary + Clone + 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        let strategy = prop_oneof![
+            any::<T>().prop_map(Op::PushFront),
+            any::<T>().prop_map(Op::PushBack),
+            Just(Op::PopFront),
+            Just(Op::PopBack),
+            collection::vec(any::<T>(), 0..4).prop_map(Op::Append),
+            collection::vec(any::<T>(), 0..4).prop_map(Op::Prepend),
+            any::<usize>().prop_map(Op::SplitOff),
+            (any::<usize>(), any::<usize>()).prop_map(|(start, end)| Op::Split(start, end)),
+            any::<usize>().prop_map(Op::At),
+            Just(Op::Clear),
+            collection::vec(any::<CursorOp>(), 0..8).prop_map(Op::Cursor),
+            collection::vec(any::<CursorMutOp<T>>(), 0..8).prop_map(Op::CursorMut),
+        ];
+
+        #[cfg(feature = "experimental-ghost-cursor")]
+        let strategy = prop_oneof![
+            12 => strategy,
+            1 => (any::<usize>(), any::<T>()).prop_map(|(at, value)| Op::AtMut(at, value)),
+        ];
+
+        strategy.boxed()
+    }
+}
+
+impl proptest::arbitrary::Arbitrary for CursorOp {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        prop_oneof![
+            Just(CursorOp::MoveUp),
+            Just(CursorOp::MoveLeft),
+            Just(CursorOp::MoveRight),
+            Just(CursorOp::MoveToRoot),
+            Just(CursorOp::MoveNext),
+            Just(CursorOp::MovePrev),
+            Just(CursorOp::TryMoveNext),
+            Just(CursorOp::TryMovePrev),
+            any::<usize>().prop_map(CursorOp::MoveTo),
+            Just(CursorOp::MoveToFront),
+            Just(CursorOp::MoveToBack),
+        ]
+        .boxed()
+    }
+}
+
+impl<T> proptest::arbitrary::Arbitrary for CursorMutOp<T>
+where
+    T: proptest::arbitrary::Arbitrary + Clone + 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        prop_oneof![
+            any::<CursorOp>().prop_map(CursorMutOp::Move),
+            any::<T>().prop_map(CursorMutOp::Replace),
+            any::<T>().prop_map(CursorMutOp::InsertAfter),
+            any::<T>().prop_map(CursorMutOp::InsertBefore),
+            Just(CursorMutOp::RemoveCurrent),
+            Just(CursorMutOp::RemoveCurrentAsTree),
+            collection::vec(any::<T>(), 0..4).prop_map(CursorMutOp::SpliceAfter),
+            collection::vec(any::<T>(), 0..4).prop_map(CursorMutOp::SpliceBefore),
+            Just(CursorMutOp::SplitAfter),
+            Just(CursorMutOp::SplitBefore),
+        ]
+        .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+use super::*;
+
+proptest! {
+    #[test]
+    fn tree_replay(ops in collection::vec(any::<Op<u8>>(), 0..32)) {
+        replay(&ops);
+    }
+}
+
+#[test]
+fn tree_replay_twilight() {
+    use CursorMutOp::*;
+    use CursorOp::*;
+
+    replay(&[
+        Op::CursorMut(vec![InsertAfter(1), InsertBefore(2), Move(MoveTo(2)), SpliceAfter(vec![3, 4]), SpliceBefore(vec![5])]),
+        Op::Cursor(vec![MoveTo(5), MoveNext, MovePrev, MovePrev, MoveToRoot, MoveLeft, MoveUp, MoveRight, TryMoveNext]),
+        Op::CursorMut(vec![Move(MoveTo(5)), SplitAfter, Move(MoveTo(4)), SplitBefore, RemoveCurrent]),
+    ]);
+}
+
+#[test]
+fn tree_replay_remove_current() {
+    use CursorMutOp::*;
+    use CursorOp::*;
+
+    let values: Vec<u8> = (0..16).collect();
+
+    for at in 0..16 {
+        replay(&[Op::Append(values.clone()), Op::CursorMut(vec![Move(MoveTo(at)), RemoveCurrent, RemoveCurrent])]);
+    }
+}
+
+} // mod tests
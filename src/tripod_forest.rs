@@ -0,0 +1,640 @@
+//! A Forest of ordered trees, with externally supplied token.
+//!
+//! The `TripodForest` is a building block for general trees, such as DOMs or ASTs, in which each element has a parent
+//! and an ordered list of children. The roots of the forest are themselves ordered, as siblings of one another.
+//!
+//! #   Deviation
+//!
+//! Each element links to its first child and to its next sibling, and back to either its parent, if it is the first
+//! child, or to its previous sibling, otherwise. A `StaticRc` cannot be shared by an arbitrary number of children, hence
+//! only the first child links back to its parent, and reaching the parent of any other child requires walking back its
+//! previous siblings.
+
+mod cursor;
+mod iter;
+
+pub use cursor::{Cursor, CursorMut};
+pub use iter::{BreadthFirst, DepthFirst};
+
+use core::{
+    cell::Cell,
+    ptr,
+};
+
+use ghost_cell::{GhostCell, GhostToken};
+use static_rc::StaticRc;
+
+use crate::invariant::InvariantError;
+
+/// A safe implementation of a forest of ordered trees.
+///
+/// Each node contains 1 element as well as 4 pointers: up, first child, next sibling, and the tripod pointer.
+pub struct TripodForest<'brand, T> {
+    //  The first root, the other roots being its siblings.
+    root: Option<QuarterNodePtr<'brand, T>>,
+}
+
+impl<'brand, T> TripodForest<'brand, T> {
+    /// Creates a new, empty, instance.
+    pub const fn new() -> Self { Self { root: None, } }
+
+    /// Creates a new instance, with a single value.
+    pub fn singleton(value: T, token: &mut GhostToken<'brand>) -> Self {
+        Self { root: Some(Self::from_value(value, token)), }
+    }
+
+    /// Creates a depth-first iterator over the entire forest: each element is yielded before its children, themselves
+    /// yielded before the next sibling of the element.
+    ///
+    /// #   Complexity
+    ///
+    /// The complexity of this method itself is O(1).
+    ///
+    /// The complexity of iterating over the entire forest is O(N) in the number of elements, and no memory allocation
+    /// occurs.
+    pub fn iter_depth_first<'a>(&'a self, token: &'a GhostToken<'brand>) -> DepthFirst<'a, 'brand, T> {
+        DepthFirst::new(token, self)
+    }
+
+    /// Creates a breadth-first iterator over the entire forest: the elements are yielded by increasing depth, and in
+    /// order within a given depth.
+    ///
+    /// #   Complexity
+    ///
+    /// The complexity of this method itself is O(1).
+    ///
+    /// The complexity of iterating over the entire forest is O(N) in the number of elements. A queue of the elements
+    /// whose children remain to be yielded is maintained, hence memory allocations occur.
+    pub fn iter_breadth_first<'a>(&'a self, token: &'a GhostToken<'brand>) -> BreadthFirst<'a, 'brand, T> {
+        BreadthFirst::new(token, self)
+    }
+
+    /// Creates a cursor pointing to the first root.
+    pub fn cursor<'a>(&'a self, token: &'a GhostToken<'brand>) -> Cursor<'a, 'brand, T> {
+        Cursor::new(token, self)
+    }
+
+    /// Creates a mutable cursor pointing to the first root.
+    pub fn cursor_mut<'a>(&'a mut self, token: &'a mut GhostToken<'brand>) -> CursorMut<'a, 'brand, T> {
+        CursorMut::new(token, self)
+    }
+
+    /// Returns whether the forest is empty, or not.
+    pub fn is_empty(&self) -> bool { self.root.is_none() }
+
+    /// Returns the number of elements in the forest.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(N) in the number of elements.
+    /// -   Space: O(1).
+    pub fn len(&self, token: &GhostToken<'brand>) -> usize { self.iter_depth_first(token).count() }
+
+    /// Clears the forest of all elements.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(N) in the number of elements.
+    /// -   Space: O(1).
+    pub fn clear(&mut self, token: &mut GhostToken<'brand>) {
+        if let Some(root) = self.root.take() {
+            let mut tripod = root.borrow(token).deploy();
+
+            //  O(N) iterations, performing O(1) work each.
+            loop {
+                //  Clear the children first.
+                if let Some(child) = tripod.borrow(token).first_child() {
+                    let child_tripod = child.borrow(token).deploy();
+                    retract(tripod, token);
+                    tripod = child_tripod;
+                    continue;
+                }
+
+                //  And the next siblings afterwards.
+                if let Some(sibling) = tripod.borrow(token).next_sibling() {
+                    let sibling_tripod = sibling.borrow(token).deploy();
+                    retract(tripod, token);
+                    tripod = sibling_tripod;
+                    continue;
+                }
+
+                //  Neither children nor next siblings, time to clean and move up!
+                if let Some(up) = tripod.borrow_mut(token).up.take() {
+                    let up_tripod = up.borrow(token).deploy();
+
+                    let link = tripod.borrow(token).link_from(up.borrow(token));
+                    let node = up_tripod.borrow_mut(token).link_mut(link).replace(up).expect("Linked!");
+
+                    retract(tripod, token);
+                    Self::node_into_inner(node, token);
+
+                    tripod = up_tripod;
+                } else {
+                    retract(tripod, token);
+                    Self::node_into_inner(root, token);
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Returns a reference to the first root, if any.
+    pub fn front<'a>(&'a self, token: &'a GhostToken<'brand>) -> Option<&'a T> { self.cursor(token).current() }
+
+    /// Pushes an element as the first root of the forest.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(1).
+    /// -   Space: O(1).
+    pub fn push_front(&mut self, value: T, token: &mut GhostToken<'brand>) {
+        let mut cursor = CursorMut::twilight(token, self);
+        cursor.insert(Position::FirstChild, value);
+    }
+
+    /// Pushes an element as the last root of the forest.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(R) in the number of roots.
+    /// -   Space: O(1).
+    pub fn push_back(&mut self, value: T, token: &mut GhostToken<'brand>) {
+        let mut cursor = CursorMut::twilight(token, self);
+        cursor.insert(Position::LastChild, value);
+    }
+
+    /// Checks the structural invariants of the forest, returning the first violation found, if any.
+    ///
+    /// The following invariants are checked, for each node, the index being its depth-first index:
+    ///
+    /// -   The up pointers of its first child and next sibling point back to the node, and the first root has no up
+    ///     pointer.
+    /// -   The tripod of the node is retracted.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(N) in the number of elements.
+    /// -   Space: O(1).
+    pub fn check_invariants(&self, token: &GhostToken<'brand>) -> Result<(), InvariantError> {
+        let mut node: &GhostNode<'brand, T> = if let Some(root) = self.root.as_ref() {
+            root
+        } else {
+            return Ok(());
+        };
+
+        if node.borrow(token).up.is_some() {
+            return Err(InvariantError::Link { index: 0 });
+        }
+
+        let mut index = 0;
+
+        //  O(N) iterations, each checking a single node.
+        loop {
+            let current = node.borrow(token);
+
+            let tripod = current.tripod.take();
+            let retracted = matches!(tripod.as_deref(), Some(tripod) if ptr::eq(tripod, node));
+            current.tripod.set(tripod);
+
+            if !retracted {
+                return Err(InvariantError::Tripod { index });
+            }
+
+            for linked in [current.first_child(), current.next_sibling()].iter().flatten() {
+                if !matches!(linked.borrow(token).up(), Some(up) if ptr::eq(up, node)) {
+                    return Err(InvariantError::Link { index });
+                }
+            }
+
+            node = if let Some(next) = next_depth_first(node, token) { next } else { break };
+            index += 1;
+        }
+
+        Ok(())
+    }
+
+    //  Internal; asserts that the invariants hold, when the `paranoid` feature is enabled.
+    #[cfg(feature = "paranoid")]
+    fn assert_invariants(&self, token: &GhostToken<'brand>) {
+        if let Err(error) = self.check_invariants(token) {
+            panic!("TripodForest invariant violated: {}", error);
+        }
+    }
+
+    //  Internal (dummy)
+    #[cfg(not(feature = "paranoid"))]
+    fn assert_invariants(&self, _: &GhostToken<'brand>) {}
+
+    //  Internal; constructs a QuarterNodePtr from a value, linked to nothing.
+    fn from_value(value: T, token: &mut GhostToken<'brand>) -> QuarterNodePtr<'brand, T> {
+        let tripod = Cell::new(None);
+        let node = Node { value, up: None, first_child: None, next_sibling: None, tripod, };
+        let node = FullNodePtr::new(GhostCell::new(node));
+
+        let halves = FullNodePtr::split::<2, 2>(node);
+        let (up, tripod) = HalfNodePtr::split::<1, 1>(halves.0);
+        let (first_child, next_sibling) = HalfNodePtr::split::<1, 1>(halves.1);
+
+        up.borrow(token).retract(tripod);
+        up.borrow_mut(token).first_child = Some(first_child);
+        up.borrow_mut(token).next_sibling = Some(next_sibling);
+
+        up
+    }
+
+    //  Internal; returns the value contained within a node linked to nothing.
+    fn node_into_inner(node: QuarterNodePtr<'brand, T>, token: &mut GhostToken<'brand>) -> T {
+        let first_child = node.borrow_mut(token).first_child.take().expect("First child - pointing to self");
+        let next_sibling = node.borrow_mut(token).next_sibling.take().expect("Next sibling - pointing to self");
+        let tripod = node.borrow_mut(token).tripod.take().expect("Tripod - pointing to self");
+
+        let main = HalfNodePtr::join(node, tripod);
+        let links = HalfNodePtr::join(first_child, next_sibling);
+
+        let ghost_cell = FullNodePtr::into_inner(FullNodePtr::join(main, links));
+        let node = GhostNode::into_inner(ghost_cell);
+
+        debug_assert!(node.up.is_none());
+
+        node.value
+    }
+}
+
+impl<'brand, T> Default for TripodForest<'brand, T> {
+    fn default() -> Self { Self::new() }
+}
+
+/// The position at which to insert, relative to an element.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Position {
+    /// As the previous sibling of the element.
+    Before,
+    /// As the next sibling of the element.
+    After,
+    /// As the first child of the element.
+    FirstChild,
+    /// As the last child of the element.
+    LastChild,
+}
+
+//
+//  Implementation
+//
+
+//  The link through which a node is reached from its up node.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Link {
+    FirstChild,
+    NextSibling,
+}
+
+struct Node<'brand, T> {
+    value: T,
+    //  The parent if the node is a first child, the previous sibling otherwise, and None for the first root.
+    up: Option<QuarterNodePtr<'brand, T>>,
+    //  The first child, or the node itself if it has no child.
+    first_child: Option<QuarterNodePtr<'brand, T>>,
+    //  The next sibling, or the node itself if it has no next sibling.
+    next_sibling: Option<QuarterNodePtr<'brand, T>>,
+    tripod: Cell<Option<QuarterNodePtr<'brand, T>>>,
+}
+
+impl<'brand, T> Node<'brand, T> {
+    //  Internal; checks whether a reference to a node is aliased to this node.
+    fn is_aliased(&self, node: Option<&GhostNode<'brand, T>>) -> bool {
+        node.map(|node| self as *const _ as *const u8 == node as *const _ as *const u8).unwrap_or(false)
+    }
+
+    //  Internal; returns a reference to the up node, if any.
+    fn up(&self) -> Option<&GhostNode<'brand, T>> { self.up.as_deref() }
+
+    //  Internal; returns a reference to the first child, if any.
+    fn first_child(&self) -> Option<&GhostNode<'brand, T>> { self.linked(Link::FirstChild) }
+
+    //  Internal; returns a reference to the next sibling, if any.
+    fn next_sibling(&self) -> Option<&GhostNode<'brand, T>> { self.linked(Link::NextSibling) }
+
+    //  Internal; returns a reference to the node reached through the link, if any.
+    fn linked(&self, link: Link) -> Option<&GhostNode<'brand, T>> {
+        let result = self.link_ref(link).as_deref();
+        if self.is_aliased(result) { None } else { result }
+    }
+
+    //  Internal; returns the link through which this node is reached from `up`.
+    fn link_from(&self, up: &Self) -> Link {
+        if self.is_aliased(up.first_child.as_deref()) { Link::FirstChild } else { Link::NextSibling }
+    }
+
+    //  Internal; returns whether the node is the first child of its up node, if any.
+    fn is_first_child(&self, token: &GhostToken<'brand>) -> bool {
+        self.up().map(|up| self.link_from(up.borrow(token)) == Link::FirstChild).unwrap_or(false)
+    }
+
+    //  Internal; returns a reference to the previous sibling, if any.
+    fn prev_sibling(&self, token: &GhostToken<'brand>) -> Option<&GhostNode<'brand, T>> {
+        self.up().filter(|up| self.link_from(up.borrow(token)) == Link::NextSibling)
+    }
+
+    //  Internal; takes the node reached through the link, if any.
+    fn take_linked(&mut self, link: Link) -> Option<QuarterNodePtr<'brand, T>> {
+        if self.linked(link).is_some() {
+            self.link_mut(link).take()
+        } else {
+            None
+        }
+    }
+
+    //  Internal; returns a reference to the appropriate link.
+    fn link_ref(&self, link: Link) -> &Option<QuarterNodePtr<'brand, T>> {
+        match link {
+            Link::FirstChild => &self.first_child,
+            Link::NextSibling => &self.next_sibling,
+        }
+    }
+
+    //  Internal; returns a mutable reference to the appropriate link.
+    fn link_mut(&mut self, link: Link) -> &mut Option<QuarterNodePtr<'brand, T>> {
+        match link {
+            Link::FirstChild => &mut self.first_child,
+            Link::NextSibling => &mut self.next_sibling,
+        }
+    }
+
+    //  Internal; deploys the tripod.
+    fn deploy(&self) -> QuarterNodePtr<'brand, T> { self.tripod.take().expect("Tripod not to be None") }
+
+    //  Internal; retracts the tripod.
+    fn retract(&self, tripod: QuarterNodePtr<'brand, T>) {
+        let previous = self.tripod.replace(Some(tripod));
+        debug_assert!(previous.is_none());
+    }
+}
+
+//  Internal; returns the parent of the node, if any.
+//
+//  Complexity: Time O(K) in the number of previous siblings, Space O(1).
+fn parent<'a, 'brand, T>(
+    node: &'a GhostNode<'brand, T>,
+    token: &'a GhostToken<'brand>,
+)
+    -> Option<&'a GhostNode<'brand, T>>
+{
+    let mut node = node;
+
+    loop {
+        let up = node.borrow(token).up()?;
+
+        if node.borrow(token).link_from(up.borrow(token)) == Link::FirstChild {
+            return Some(up);
+        }
+
+        node = up;
+    }
+}
+
+//  Internal; returns the last of the siblings, starting from `node`.
+//
+//  Complexity: Time O(K) in the number of next siblings, Space O(1).
+fn last_sibling<'a, 'brand, T>(
+    node: &'a GhostNode<'brand, T>,
+    token: &'a GhostToken<'brand>,
+)
+    -> &'a GhostNode<'brand, T>
+{
+    let mut node = node;
+
+    while let Some(next) = node.borrow(token).next_sibling() {
+        node = next;
+    }
+
+    node
+}
+
+//  Internal; returns the next node, depth-first, if any.
+//
+//  Complexity: Time O(D + K) in the depth of the node and the number of previous siblings of its ancestors, amortized
+//  O(1) over a complete walk.
+fn next_depth_first<'a, 'brand, T>(
+    node: &'a GhostNode<'brand, T>,
+    token: &'a GhostToken<'brand>,
+)
+    -> Option<&'a GhostNode<'brand, T>>
+{
+    if let Some(child) = node.borrow(token).first_child() {
+        return Some(child);
+    }
+
+    let mut node = node;
+
+    loop {
+        if let Some(next) = node.borrow(token).next_sibling() {
+            return Some(next);
+        }
+
+        node = parent(node, token)?;
+    }
+}
+
+//  Internal; links the chain of siblings from `head` to `tail` through the link of `up`, or as the first roots if `up`
+//  is None, before the nodes formerly linked there.
+//
+//  The head must have no up node, and the tail no next sibling.
+fn link<'brand, T>(
+    root: &mut Option<QuarterNodePtr<'brand, T>>,
+    up: Option<(&GhostNode<'brand, T>, Link)>,
+    head: QuarterNodePtr<'brand, T>,
+    tail: &GhostNode<'brand, T>,
+    token: &mut GhostToken<'brand>,
+)
+{
+    debug_assert!(head.borrow(token).up.is_none());
+    debug_assert!(tail.borrow(token).next_sibling().is_none());
+
+    let existing = match up {
+        Some((up, link)) => up.borrow_mut(token).link_mut(link).take(),
+        None => root.take(),
+    };
+
+    let head_up = match existing {
+        //  The up node itself, as it had no node linked there.
+        Some(existing) if up.map(|(up, _)| ptr::eq(&*existing, up)).unwrap_or(false) => Some(existing),
+        //  The formerly linked node, which becomes the next sibling of the tail.
+        Some(next) => {
+            let next_up = next.borrow_mut(token).up.take();
+            let tail_self = tail.borrow_mut(token).next_sibling.take().expect("Next sibling - pointing to self");
+
+            next.borrow_mut(token).up = Some(tail_self);
+            tail.borrow_mut(token).next_sibling = Some(next);
+
+            next_up
+        },
+        None => None,
+    };
+
+    head.borrow_mut(token).up = head_up;
+
+    match up {
+        Some((up, link)) => *up.borrow_mut(token).link_mut(link) = Some(head),
+        None => *root = Some(head),
+    }
+}
+
+//  Internal; unlinks the node, and its descendants, from its up node and next sibling, returning the node as the single
+//  root of a chain.
+//
+//  Also returns the deployed tripod of the node which took its place, its former next sibling, if any, or otherwise of
+//  its former up node, if any.
+fn unlink<'brand, T>(
+    root: &mut Option<QuarterNodePtr<'brand, T>>,
+    node: &GhostNode<'brand, T>,
+    token: &mut GhostToken<'brand>,
+)
+    -> (QuarterNodePtr<'brand, T>, Option<QuarterNodePtr<'brand, T>>)
+{
+    let up = node.borrow_mut(token).up.take();
+    let up_tripod = up.as_ref().map(|up| up.borrow(token).deploy());
+    let link = up_tripod.as_ref().map(|up| node.borrow(token).link_from(up.borrow(token)));
+
+    let unlinked = match (up_tripod.as_ref(), link) {
+        (Some(up), Some(link)) => up.borrow_mut(token).link_mut(link).take(),
+        _ => root.take(),
+    };
+
+    let unlinked = unlinked.expect("Linked - from up, or root");
+
+    if let Some(next) = node.borrow_mut(token).take_linked(Link::NextSibling) {
+        //  The next sibling takes the place of the node.
+        let node_self = next.borrow_mut(token).up.take().expect("next.up == node");
+        node.borrow_mut(token).next_sibling = Some(node_self);
+        next.borrow_mut(token).up = up;
+
+        let next_tripod = next.borrow(token).deploy();
+
+        match (up_tripod, link) {
+            (Some(up_tripod), Some(link)) => {
+                *up_tripod.borrow_mut(token).link_mut(link) = Some(next);
+                retract(up_tripod, token);
+            },
+            _ => *root = Some(next),
+        }
+
+        (unlinked, Some(next_tripod))
+    } else {
+        //  The up node, if any, links to itself instead.
+        if let (Some(up_tripod), Some(link)) = (up_tripod.as_ref(), link) {
+            *up_tripod.borrow_mut(token).link_mut(link) = up;
+        }
+
+        (unlinked, up_tripod)
+    }
+}
+
+fn retract<'brand, T>(tripod: QuarterNodePtr<'brand, T>, token: &mut GhostToken<'brand>) {
+    let previous = static_rc::lift_with_mut(Some(tripod), token, |tripod, token| {
+        tripod.as_ref().expect("Some").borrow_mut(token).tripod.get_mut()
+    });
+    debug_assert!(previous.is_none(), "Node should not have any tripod to retract it!");
+}
+
+type GhostNode<'brand, T> = GhostCell<'brand, Node<'brand, T>>;
+
+type QuarterNodePtr<'brand, T> = StaticRc<GhostNode<'brand, T>, 1, 4>;
+type HalfNodePtr<'brand, T> = StaticRc<GhostNode<'brand, T>, 2, 4>;
+type FullNodePtr<'brand, T> = StaticRc<GhostNode<'brand, T>, 4, 4>;
+
+#[cfg(test)]
+pub(crate) mod tests {
+
+use std::{
+    panic::{self, AssertUnwindSafe},
+    string::String,
+    vec::Vec,
+};
+
+use super::*;
+
+//  Invokes `fun` with the forest: 0 { 1 { 2, 3 }, 4 { 5 } }, 6, 7.
+pub(crate) fn with_forest<R, F>(fun: F) -> R
+where
+    F: for<'brand> FnOnce(&mut GhostToken<'brand>, &mut TripodForest<'brand, i32>) -> R,
+{
+    GhostToken::new(|mut token| {
+        let mut forest = TripodForest::new();
+
+        for root in [0, 6, 7].iter() {
+            forest.push_back(*root, &mut token);
+        }
+
+        {
+            let mut cursor = forest.cursor_mut(&mut token);
+
+            cursor.append_child(1);
+            cursor.append_child(4);
+
+            cursor.move_to_first_child();
+            cursor.append_child(2);
+            cursor.append_child(3);
+
+            cursor.move_to_next_sibling();
+            cursor.append_child(5);
+        }
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| fun(&mut token, &mut forest)));
+
+        forest.clear(&mut token);
+
+        result.expect("No Panic")
+    })
+}
+
+#[test]
+fn forest_new() {
+    GhostToken::new(|token| {
+        let forest = TripodForest::<'_, String>::new();
+
+        assert!(forest.is_empty());
+        assert_eq!(0, forest.len(&token));
+        assert_eq!(None, forest.front(&token));
+        assert_eq!(Ok(()), forest.check_invariants(&token));
+    });
+}
+
+#[test]
+fn forest_push_front_back() {
+    GhostToken::new(|mut token| {
+        let mut forest = TripodForest::singleton(1, &mut token);
+
+        forest.push_back(2, &mut token);
+        forest.push_front(0, &mut token);
+
+        assert_eq!(Ok(()), forest.check_invariants(&token));
+        assert_eq!(3, forest.len(&token));
+        assert_eq!(Some(&0), forest.front(&token));
+
+        let elements: Vec<_> = forest.iter_depth_first(&token).copied().collect();
+        assert_eq!(&[0, 1, 2][..], &elements[..]);
+
+        forest.clear(&mut token);
+
+        assert!(forest.is_empty());
+    });
+}
+
+#[test]
+fn forest_check_invariants() {
+    with_forest(|token, forest| {
+        assert_eq!(Ok(()), forest.check_invariants(token));
+        assert_eq!(8, forest.len(token));
+
+        let root = forest.root.as_ref().expect("Non-empty");
+        let tripod = root.borrow(token).first_child().expect("Child").borrow(token).deploy();
+
+        assert_eq!(Err(InvariantError::Tripod { index: 1 }), forest.check_invariants(token));
+
+        retract(tripod, token);
+
+        assert_eq!(Ok(()), forest.check_invariants(token));
+    });
+}
+
+} // mod tests
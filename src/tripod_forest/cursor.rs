@@ -0,0 +1,638 @@
+use core::mem;
+
+use ghost_cell::GhostToken;
+
+use super::{GhostNode, Link, Position, QuarterNodePtr, TripodForest};
+
+/// A Cursor over the TripodForest.
+///
+/// The Cursor contains a "twilight" non-element, acting as the parent of the roots, that is:
+///
+/// -   Going to the parent of a root points the cursor to the "twilight" non-element.
+/// -   Going to the first child of the "twilight" non-element points the cursor to the first root.
+/// -   Going to a non-existing child or sibling points the cursor to the "twilight" non-element.
+pub struct Cursor<'a, 'brand, T> {
+    token: &'a GhostToken<'brand>,
+    forest: &'a TripodForest<'brand, T>,
+    node: Option<&'a GhostNode<'brand, T>>,
+}
+
+impl<'a, 'brand, T> Cursor<'a, 'brand, T> {
+    /// Creates a new instance pointing to the first root of the forest, if any.
+    pub fn new(token: &'a GhostToken<'brand>, forest: &'a TripodForest<'brand, T>) -> Self {
+        let node = forest.root.as_deref();
+
+        Self { token, forest, node, }
+    }
+
+    /// Moves the cursor to the parent element, if any.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(K) in the number of previous siblings of the current element.
+    /// -   Space: O(1).
+    pub fn move_to_parent(&mut self) { self.node = self.peek_parent_node(); }
+
+    /// Moves the cursor to the first child of the current element, if any.
+    ///
+    /// If the cursor points to the "twilight" non-element, moves to the first root instead.
+    pub fn move_to_first_child(&mut self) { self.node = self.peek_first_child_node(); }
+
+    /// Moves the cursor to the next sibling of the current element, if any.
+    pub fn move_to_next_sibling(&mut self) { self.node = self.peek_next_sibling_node(); }
+
+    /// Moves the cursor to the previous sibling of the current element, if any.
+    pub fn move_to_prev_sibling(&mut self) { self.node = self.peek_prev_sibling_node(); }
+
+    /// Returns a reference to the current element, if any.
+    pub fn current(&self) -> Option<&'a T> { self.node.map(|node| &node.borrow(self.token).value) }
+
+    /// Returns a reference to the parent element, if any.
+    pub fn peek_parent(&self) -> Option<&'a T> { self.peek_parent_node().map(|node| &node.borrow(self.token).value) }
+
+    /// Returns a reference to the first child element, if any.
+    pub fn peek_first_child(&self) -> Option<&'a T> {
+        self.peek_first_child_node().map(|node| &node.borrow(self.token).value)
+    }
+
+    /// Returns a reference to the next sibling element, if any.
+    pub fn peek_next_sibling(&self) -> Option<&'a T> {
+        self.peek_next_sibling_node().map(|node| &node.borrow(self.token).value)
+    }
+
+    /// Returns a reference to the previous sibling element, if any.
+    pub fn peek_prev_sibling(&self) -> Option<&'a T> {
+        self.peek_prev_sibling_node().map(|node| &node.borrow(self.token).value)
+    }
+
+    //  Internal; returns a reference to the parent GhostNode.
+    fn peek_parent_node(&self) -> Option<&'a GhostNode<'brand, T>> {
+        self.node.and_then(|node| super::parent(node, self.token))
+    }
+
+    //  Internal; returns a reference to the first child GhostNode.
+    fn peek_first_child_node(&self) -> Option<&'a GhostNode<'brand, T>> {
+        match self.node {
+            Some(node) => node.borrow(self.token).first_child(),
+            None => self.forest.root.as_deref(),
+        }
+    }
+
+    //  Internal; returns a reference to the next sibling GhostNode.
+    fn peek_next_sibling_node(&self) -> Option<&'a GhostNode<'brand, T>> {
+        self.node.and_then(|node| node.borrow(self.token).next_sibling())
+    }
+
+    //  Internal; returns a reference to the previous sibling GhostNode.
+    fn peek_prev_sibling_node(&self) -> Option<&'a GhostNode<'brand, T>> {
+        self.node.and_then(|node| node.borrow(self.token).prev_sibling(self.token))
+    }
+}
+
+impl<'a, 'brand, T> Clone for Cursor<'a, 'brand, T> {
+    fn clone(&self) -> Self { *self }
+}
+
+impl<'a, 'brand, T> Copy for Cursor<'a, 'brand, T> {}
+
+/// A mutable cursor over the TripodForest.
+///
+/// The cursor contains a "twilight" non-element, acting as the parent of the roots, see `Cursor`.
+pub struct CursorMut<'a, 'brand, T> {
+    token: &'a mut GhostToken<'brand>,
+    forest: &'a mut TripodForest<'brand, T>,
+    node: Option<QuarterNodePtr<'brand, T>>,
+}
+
+//  Cursor navigation.
+impl<'a, 'brand, T> CursorMut<'a, 'brand, T> {
+    /// Creates a new instance pointing to the first root of the forest, if any.
+    pub fn new(token: &'a mut GhostToken<'brand>, forest: &'a mut TripodForest<'brand, T>) -> Self {
+        let node = forest.root.as_ref().map(|root| root.borrow(token).deploy());
+
+        Self { token, forest, node, }
+    }
+
+    //  Internal; creates a new instance pointing to the "twilight" non-element.
+    pub(super) fn twilight(token: &'a mut GhostToken<'brand>, forest: &'a mut TripodForest<'brand, T>) -> Self {
+        Self { token, forest, node: None, }
+    }
+
+    /// Returns a read-only cursor pointing to the current element.
+    pub fn as_cursor(&self) -> Cursor<'_, 'brand, T> {
+        let token = &*self.token;
+        let forest = &*self.forest;
+        let node = self.node.as_deref();
+
+        Cursor { token, forest, node, }
+    }
+
+    /// Moves the cursor to the parent element, if any.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(K) in the number of previous siblings of the current element.
+    /// -   Space: O(1).
+    pub fn move_to_parent(&mut self) {
+        let new_tripod = self.as_cursor().peek_parent_node().map(|node| node.borrow(self.token).deploy());
+        self.replace_tripod(new_tripod);
+    }
+
+    /// Moves the cursor to the first child of the current element, if any.
+    ///
+    /// If the cursor points to the "twilight" non-element, moves to the first root instead.
+    pub fn move_to_first_child(&mut self) {
+        let new_tripod = self.as_cursor().peek_first_child_node().map(|node| node.borrow(self.token).deploy());
+        self.replace_tripod(new_tripod);
+    }
+
+    /// Moves the cursor to the next sibling of the current element, if any.
+    pub fn move_to_next_sibling(&mut self) {
+        let new_tripod = self.as_cursor().peek_next_sibling_node().map(|node| node.borrow(self.token).deploy());
+        self.replace_tripod(new_tripod);
+    }
+
+    /// Moves the cursor to the previous sibling of the current element, if any.
+    pub fn move_to_prev_sibling(&mut self) {
+        let new_tripod = self.as_cursor().peek_prev_sibling_node().map(|node| node.borrow(self.token).deploy());
+        self.replace_tripod(new_tripod);
+    }
+
+    /// Returns a reference to the current element, if any.
+    pub fn current(&mut self) -> Option<&mut T> {
+        let tripod = self.node.as_ref()?;
+        Some(&mut tripod.borrow_mut(self.token).value)
+    }
+
+    /// Returns a reference to the parent element, if any.
+    ///
+    /// #   Deviation
+    ///
+    /// It is not possible to return a mutable reference, safely.
+    pub fn peek_parent(&self) -> Option<&T> { self.as_cursor().peek_parent() }
+
+    /// Returns a reference to the first child element, if any.
+    ///
+    /// #   Deviation
+    ///
+    /// It is not possible to return a mutable reference, safely.
+    pub fn peek_first_child(&self) -> Option<&T> { self.as_cursor().peek_first_child() }
+
+    /// Returns a reference to the next sibling element, if any.
+    ///
+    /// #   Deviation
+    ///
+    /// It is not possible to return a mutable reference, safely.
+    pub fn peek_next_sibling(&self) -> Option<&T> { self.as_cursor().peek_next_sibling() }
+
+    /// Returns a reference to the previous sibling element, if any.
+    ///
+    /// #   Deviation
+    ///
+    /// It is not possible to return a mutable reference, safely.
+    pub fn peek_prev_sibling(&self) -> Option<&T> { self.as_cursor().peek_prev_sibling() }
+
+    //  Internal; replaces the current tripod, retracting the former one.
+    fn replace_tripod(&mut self, new_tripod: Option<QuarterNodePtr<'brand, T>>) {
+        if let Some(tripod) = mem::replace(&mut self.node, new_tripod) {
+            super::retract(tripod, self.token);
+        }
+    }
+}
+
+//  Cursor edition.
+impl<'a, 'brand, T> CursorMut<'a, 'brand, T> {
+    /// Inserts a new element at the given `position`, relative to the current element.
+    ///
+    /// If the cursor points to the "twilight" non-element, `FirstChild` and `After` insert the element as the first
+    /// root, whilst `LastChild` and `Before` insert it as the last root.
+    ///
+    /// The cursor still points to the current element afterwards.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(1) for `After`, `Before`, and `FirstChild`, O(K) in the number of children for `LastChild`.
+    /// -   Space: O(1).
+    ///
+    /// A single memory allocation occurs.
+    pub fn insert(&mut self, position: Position, value: T) {
+        let mut other = TripodForest::singleton(value, self.token);
+        self.splice(position, &mut other);
+    }
+
+    /// Appends a new element as the last child of the current element, or as the last root if the cursor points to
+    /// the "twilight" non-element.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(K) in the number of children.
+    /// -   Space: O(1).
+    pub fn append_child(&mut self, value: T) { self.insert(Position::LastChild, value) }
+
+    /// Inserts a new element as the next sibling of the current element, or as the first root if the cursor points
+    /// to the "twilight" non-element.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(1).
+    /// -   Space: O(1).
+    pub fn insert_sibling_after(&mut self, value: T) { self.insert(Position::After, value) }
+
+    /// Splices the trees of `other` at the given `position`, relative to the current element, leaving `other` empty.
+    ///
+    /// The roots of `other` keep their order, and the cursor still points to the current element afterwards. See
+    /// `insert` for the positions relative to the "twilight" non-element.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(R) in the number of roots of `other`, plus O(K) in the number of children for `LastChild`.
+    /// -   Space: O(1).
+    pub fn splice(&mut self, position: Position, other: &mut TripodForest<'brand, T>) {
+        let head = if let Some(head) = other.root.take() { head } else { return };
+
+        let tail_tripod = super::last_sibling(&head, self.token).borrow(self.token).deploy();
+
+        let holder = match (self.node.as_deref(), position) {
+            (None, Position::FirstChild) | (None, Position::After) => Holder::Root,
+            (None, Position::LastChild) | (None, Position::Before) => match self.forest.root.as_deref() {
+                Some(root) => {
+                    let last = super::last_sibling(root, self.token);
+                    Holder::Other(last.borrow(self.token).deploy(), Link::NextSibling)
+                },
+                None => Holder::Root,
+            },
+            (Some(_), Position::FirstChild) => Holder::Current(Link::FirstChild),
+            (Some(_), Position::After) => Holder::Current(Link::NextSibling),
+            (Some(node), Position::LastChild) => match node.borrow(self.token).first_child() {
+                Some(child) => {
+                    let last = super::last_sibling(child, self.token);
+                    Holder::Other(last.borrow(self.token).deploy(), Link::NextSibling)
+                },
+                None => Holder::Current(Link::FirstChild),
+            },
+            (Some(node), Position::Before) => match node.borrow(self.token).up() {
+                Some(up) => {
+                    let link = node.borrow(self.token).link_from(up.borrow(self.token));
+                    Holder::Other(up.borrow(self.token).deploy(), link)
+                },
+                None => Holder::Root,
+            },
+        };
+
+        let up = match &holder {
+            Holder::Root => None,
+            Holder::Current(link) => self.node.as_deref().map(|node| (node, *link)),
+            Holder::Other(tripod, link) => Some((&**tripod, *link)),
+        };
+
+        super::link(&mut self.forest.root, up, head, &tail_tripod, self.token);
+
+        super::retract(tail_tripod, self.token);
+
+        if let Holder::Other(tripod, _) = holder {
+            super::retract(tripod, self.token);
+        }
+    }
+
+    /// Detaches the current element, and its descendants, returning them as a forest of a single tree.
+    ///
+    /// The cursor moves to the next sibling of the detached element, if any, or otherwise to its parent, if any, or
+    /// otherwise to the "twilight" non-element.
+    ///
+    /// If the cursor points to the "twilight" non-element, nothing happens and an empty forest is returned.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(1) if the element has a next sibling, O(K) in the number of its previous siblings otherwise.
+    /// -   Space: O(1).
+    pub fn detach_subtree(&mut self) -> TripodForest<'brand, T> {
+        let tripod = if let Some(tripod) = self.node.take() { tripod } else { return TripodForest::new() };
+
+        let has_next_sibling = tripod.borrow(self.token).next_sibling().is_some();
+        let is_first = tripod.borrow(self.token).up.is_none() || tripod.borrow(self.token).is_first_child(self.token);
+
+        let (root, neighbour) = super::unlink(&mut self.forest.root, &tripod, self.token);
+
+        super::retract(tripod, self.token);
+
+        self.node = match neighbour {
+            //  The next sibling, or the parent.
+            Some(neighbour) if has_next_sibling || is_first => Some(neighbour),
+            //  The previous sibling.
+            Some(neighbour) => {
+                let parent = super::parent(&neighbour, self.token).map(|node| node.borrow(self.token).deploy());
+                super::retract(neighbour, self.token);
+                parent
+            },
+            None => None,
+        };
+
+        TripodForest { root: Some(root), }
+    }
+
+    /// Detaches the children of the current element, and their descendants, returning them as a forest.
+    ///
+    /// If the cursor points to the "twilight" non-element, the whole forest is detached instead.
+    ///
+    /// The cursor still points to the current element afterwards.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(1).
+    /// -   Space: O(1).
+    pub fn detach_children(&mut self) -> TripodForest<'brand, T> {
+        let node = if let Some(node) = self.node.as_deref() {
+            node
+        } else {
+            return mem::take(self.forest);
+        };
+
+        let root = node.borrow_mut(self.token).take_linked(Link::FirstChild);
+
+        if let Some(child) = root.as_ref() {
+            let node_self = child.borrow_mut(self.token).up.take();
+            node.borrow_mut(self.token).first_child = node_self;
+        }
+
+        TripodForest { root, }
+    }
+
+    /// Removes the current element, and returns it, splicing its children in its place.
+    ///
+    /// The cursor moves to the first child of the removed element, if any, or as per `detach_subtree` otherwise.
+    ///
+    /// If the cursor points to the "twilight" non-element, nothing happens and None is returned.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(C) in the number of children, plus O(K) in the number of previous siblings if the element has neither
+    ///     children nor next sibling.
+    /// -   Space: O(1).
+    ///
+    /// A single memory deallocation occurs.
+    pub fn remove_current(&mut self) -> Option<T> {
+        self.node.as_ref()?;
+
+        let mut children = self.detach_children();
+        self.splice(Position::After, &mut children);
+
+        let root = self.detach_subtree().root.take().expect("Non-empty - as the cursor pointed to an element");
+
+        Some(TripodForest::node_into_inner(root, self.token))
+    }
+
+    /// Moves the current element, and its descendants, to the given `position` relative to the element `navigate`
+    /// points the cursor to.
+    ///
+    /// The current element is first detached, as per `detach_subtree`, hence `navigate` cannot point the cursor within
+    /// the moved sub-tree, and it is then spliced at the given `position`, as per `splice`. The cursor still points to
+    /// the element `navigate` pointed it to afterwards.
+    ///
+    /// If the cursor points to the "twilight" non-element, only `navigate` is invoked.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: the complexity of `detach_subtree`, `navigate`, and `splice`.
+    /// -   Space: O(1).
+    pub fn move_subtree<F>(&mut self, position: Position, navigate: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+        let mut subtree = self.detach_subtree();
+
+        navigate(self);
+
+        self.splice(position, &mut subtree);
+    }
+}
+
+impl<'a, 'brand, T> Drop for CursorMut<'a, 'brand, T> {
+    fn drop(&mut self) {
+        if let Some(tripod) = self.node.take() {
+            super::retract(tripod, self.token);
+        }
+
+        self.forest.assert_invariants(self.token);
+    }
+}
+
+//
+//  Implementation
+//
+
+//  The node holding the link at which to splice.
+enum Holder<'brand, T> {
+    //  The roots of the forest.
+    Root,
+    //  The current node of the cursor.
+    Current(Link),
+    //  Another node, whose tripod is deployed.
+    Other(QuarterNodePtr<'brand, T>, Link),
+}
+
+#[cfg(test)]
+mod tests {
+
+use std::vec::Vec;
+
+use super::*;
+use super::super::tests::with_forest;
+
+fn collect<'brand>(forest: &TripodForest<'brand, i32>, token: &GhostToken<'brand>) -> Vec<i32> {
+    forest.iter_depth_first(token).copied().collect()
+}
+
+#[test]
+fn cursor_navigation() {
+    with_forest(|token, forest| {
+        let mut cursor = forest.cursor(token);
+
+        assert_eq!(Some(&0), cursor.current());
+        assert_eq!(None, cursor.peek_parent());
+        assert_eq!(Some(&1), cursor.peek_first_child());
+        assert_eq!(Some(&6), cursor.peek_next_sibling());
+
+        cursor.move_to_first_child();
+        cursor.move_to_next_sibling();
+
+        assert_eq!(Some(&4), cursor.current());
+        assert_eq!(Some(&0), cursor.peek_parent());
+        assert_eq!(Some(&1), cursor.peek_prev_sibling());
+        assert_eq!(None, cursor.peek_next_sibling());
+
+        cursor.move_to_first_child();
+        cursor.move_to_parent();
+
+        assert_eq!(Some(&4), cursor.current());
+
+        cursor.move_to_parent();
+        cursor.move_to_parent();
+
+        assert_eq!(None, cursor.current());
+
+        cursor.move_to_first_child();
+        cursor.move_to_next_sibling();
+        cursor.move_to_next_sibling();
+
+        assert_eq!(Some(&7), cursor.current());
+        assert_eq!(Some(&6), cursor.peek_prev_sibling());
+        assert_eq!(None, cursor.peek_parent());
+    });
+}
+
+#[test]
+fn cursor_mut_navigation() {
+    with_forest(|token, forest| {
+        let mut cursor = forest.cursor_mut(token);
+
+        cursor.move_to_first_child();
+        cursor.move_to_next_sibling();
+        cursor.move_to_first_child();
+
+        assert_eq!(Some(&mut 5), cursor.current());
+        assert_eq!(Some(&4), cursor.peek_parent());
+
+        cursor.move_to_parent();
+        cursor.move_to_prev_sibling();
+
+        assert_eq!(Some(&mut 1), cursor.current());
+        assert_eq!(Some(&2), cursor.peek_first_child());
+
+        cursor.move_to_prev_sibling();
+
+        assert_eq!(None, cursor.current());
+    });
+}
+
+#[test]
+fn cursor_mut_insert() {
+    with_forest(|token, forest| {
+        {
+            let mut cursor = forest.cursor_mut(token);
+
+            cursor.move_to_first_child();
+
+            cursor.insert(Position::Before, 10);
+            cursor.insert(Position::After, 11);
+            cursor.insert(Position::FirstChild, 12);
+            cursor.insert(Position::LastChild, 13);
+
+            assert_eq!(Some(&mut 1), cursor.current());
+
+            cursor.move_to_parent();
+            cursor.move_to_parent();
+
+            cursor.insert(Position::After, 14);
+            cursor.insert(Position::Before, 15);
+        }
+
+        assert_eq!(Ok(()), forest.check_invariants(token));
+        assert_eq!(&[14, 0, 10, 1, 12, 2, 3, 13, 11, 4, 5, 6, 7, 15][..], &collect(forest, token)[..]);
+    });
+}
+
+#[test]
+fn cursor_mut_splice() {
+    with_forest(|token, forest| {
+        let mut other = TripodForest::new();
+        other.push_back(10, token);
+        other.push_back(11, token);
+
+        {
+            let mut cursor = forest.cursor_mut(token);
+
+            cursor.move_to_next_sibling();
+            cursor.splice(Position::FirstChild, &mut other);
+
+            assert_eq!(Some(&10), cursor.peek_first_child());
+        }
+
+        assert!(other.is_empty());
+        assert_eq!(Ok(()), forest.check_invariants(token));
+        assert_eq!(&[0, 1, 2, 3, 4, 5, 6, 10, 11, 7][..], &collect(forest, token)[..]);
+    });
+}
+
+#[test]
+fn cursor_mut_detach_subtree() {
+    with_forest(|token, forest| {
+        let mut detached = {
+            let mut cursor = forest.cursor_mut(token);
+
+            cursor.move_to_first_child();
+
+            let detached = cursor.detach_subtree();
+
+            assert_eq!(Some(&mut 4), cursor.current());
+
+            let mut last = cursor.detach_subtree();
+
+            assert_eq!(Some(&mut 0), cursor.current());
+
+            last.clear(cursor.token);
+
+            detached
+        };
+
+        assert_eq!(Ok(()), detached.check_invariants(token));
+        assert_eq!(&[1, 2, 3][..], &collect(&detached, token)[..]);
+
+        assert_eq!(Ok(()), forest.check_invariants(token));
+        assert_eq!(&[0, 6, 7][..], &collect(forest, token)[..]);
+
+        detached.clear(token);
+    });
+}
+
+#[test]
+fn cursor_mut_remove_current() {
+    with_forest(|token, forest| {
+        {
+            let mut cursor = forest.cursor_mut(token);
+
+            assert_eq!(Some(0), cursor.remove_current());
+            assert_eq!(Some(&mut 1), cursor.current());
+            assert_eq!(None, cursor.peek_parent());
+
+            cursor.move_to_first_child();
+            cursor.move_to_next_sibling();
+
+            assert_eq!(Some(3), cursor.remove_current());
+            assert_eq!(Some(&mut 1), cursor.current());
+        }
+
+        assert_eq!(Ok(()), forest.check_invariants(token));
+        assert_eq!(&[1, 2, 4, 5, 6, 7][..], &collect(forest, token)[..]);
+    });
+}
+
+#[test]
+fn cursor_mut_move_subtree() {
+    with_forest(|token, forest| {
+        {
+            let mut cursor = forest.cursor_mut(token);
+
+            cursor.move_to_first_child();
+
+            //  Moves 1 (and 2, 3) under 5.
+            cursor.move_subtree(Position::LastChild, |cursor| cursor.move_to_first_child());
+
+            assert_eq!(Some(&mut 5), cursor.current());
+            assert_eq!(Some(&1), cursor.peek_first_child());
+
+            //  Moves 5 (and 1, 2, 3) after 7.
+            cursor.move_subtree(Position::After, |cursor| {
+                cursor.move_to_parent();
+                cursor.move_to_next_sibling();
+                cursor.move_to_next_sibling();
+            });
+
+            assert_eq!(Some(&mut 7), cursor.current());
+        }
+
+        assert_eq!(Ok(()), forest.check_invariants(token));
+        assert_eq!(&[0, 4, 6, 7, 5, 1, 2, 3][..], &collect(forest, token)[..]);
+    });
+}
+
+} // mod tests
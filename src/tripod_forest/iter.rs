@@ -0,0 +1,96 @@
+use alloc::collections::VecDeque;
+
+use ghost_cell::GhostToken;
+
+use super::{GhostNode, TripodForest};
+
+/// A depth-first iterator over a TripodForest, self-sufficient once created as it carries its own token.
+///
+/// Each element is yielded before its children, which are themselves yielded before its next sibling.
+pub struct DepthFirst<'a, 'brand, T> {
+    token: &'a GhostToken<'brand>,
+    node: Option<&'a GhostNode<'brand, T>>,
+}
+
+impl<'a, 'brand, T> DepthFirst<'a, 'brand, T> {
+    /// Creates a new instance of the DepthFirst iterator.
+    pub fn new(token: &'a GhostToken<'brand>, forest: &'a TripodForest<'brand, T>) -> Self {
+        let node = forest.root.as_deref();
+
+        Self { token, node, }
+    }
+}
+
+impl<'a, 'brand, T> Iterator for DepthFirst<'a, 'brand, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.node.take()?;
+
+        self.node = super::next_depth_first(node, self.token);
+
+        Some(&node.borrow(self.token).value)
+    }
+}
+
+/// A breadth-first iterator over a TripodForest, self-sufficient once created as it carries its own token.
+///
+/// The elements are yielded by increasing depth, and in order within a given depth.
+pub struct BreadthFirst<'a, 'brand, T> {
+    token: &'a GhostToken<'brand>,
+    //  The next node to yield, within the current sibling chain.
+    node: Option<&'a GhostNode<'brand, T>>,
+    //  The first child of each node already yielded, in order.
+    pending: VecDeque<&'a GhostNode<'brand, T>>,
+}
+
+impl<'a, 'brand, T> BreadthFirst<'a, 'brand, T> {
+    /// Creates a new instance of the BreadthFirst iterator.
+    pub fn new(token: &'a GhostToken<'brand>, forest: &'a TripodForest<'brand, T>) -> Self {
+        let node = forest.root.as_deref();
+
+        Self { token, node, pending: VecDeque::new(), }
+    }
+}
+
+impl<'a, 'brand, T> Iterator for BreadthFirst<'a, 'brand, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.node.take().or_else(|| self.pending.pop_front())?;
+        let current = node.borrow(self.token);
+
+        if let Some(child) = current.first_child() {
+            self.pending.push_back(child);
+        }
+
+        self.node = current.next_sibling();
+
+        Some(&current.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+use std::vec::Vec;
+
+use super::super::tests::with_forest;
+
+#[test]
+fn iter_depth_first() {
+    with_forest(|token, forest| {
+        let elements: Vec<_> = forest.iter_depth_first(token).copied().collect();
+        assert_eq!(&[0, 1, 2, 3, 4, 5, 6, 7][..], &elements[..]);
+    });
+}
+
+#[test]
+fn iter_breadth_first() {
+    with_forest(|token, forest| {
+        let elements: Vec<_> = forest.iter_breadth_first(token).copied().collect();
+        assert_eq!(&[0, 6, 7, 1, 4, 2, 3, 5][..], &elements[..]);
+    });
+}
+
+} // mod tests
@@ -5,20 +5,33 @@
 //! A number of operations normally implemented by traits cannot be successfully implemented on this collection due to
 //! the requirement of supplying the GhostToken externally.
 
+mod conversion;
 mod cursor;
 mod iter;
+mod pool;
+mod rendering;
+
+#[cfg(feature = "rayon")]
+mod par_iter;
 
 pub use cursor::{Cursor, CursorMut};
 pub use iter::Iter;
+pub use pool::NodePool;
+
+#[cfg(feature = "rayon")]
+pub use par_iter::ParIter;
 
 use core::{
     cell::Cell,
     mem,
+    ptr,
 };
 
 use ghost_cell::{GhostCell, GhostToken};
 use static_rc::StaticRc;
 
+use crate::invariant::InvariantError;
+
 /// A safe implementation of a linked-list build upon `GhostCell` and `StaticRc`.
 ///
 /// The `TripodList` contains 3 pointers per node, rather than 2 for a standard doubly linked list, the extra pointer
@@ -66,6 +79,8 @@ impl<'brand, T> TripodList<'brand, T> {
 
         self.length += other.length;
         other.length = 0;
+
+        self.assert_invariants(token);
     }
 
     /// Prepends all elements of `other`, in order, to the front of this list.
@@ -87,6 +102,27 @@ impl<'brand, T> TripodList<'brand, T> {
         Iter::new(token, self)
     }
 
+    /// Creates a parallel iterator over self.
+    ///
+    /// #   Complexity
+    ///
+    /// The complexity of this method itself is O(N) in the number of elements, as references to all elements are
+    /// gathered on the calling thread.
+    ///
+    /// One memory allocation occurs, of N references.
+    ///
+    /// #   Deviation
+    ///
+    /// A parallel iterator would typically split the list by index, and let each thread walk its own part. The tripod
+    /// pointer of each node is held in a `Cell`, however, hence the nodes are not `Sync` and cannot be borrowed from
+    /// other threads, even with a shared token. Instead, the references to the elements are gathered on the calling
+    /// thread, and only those are processed in parallel, which only pays off when processing each element dominates the
+    /// serial walk.
+    #[cfg(feature = "rayon")]
+    pub fn par_iter<'a>(&'a self, token: &'a GhostToken<'brand>) -> ParIter<'a, T> {
+        ParIter::new(token, self)
+    }
+
     /// Creates a cursor pointing to the front element.
     pub fn cursor_front<'a>(&'a self, token: &'a GhostToken<'brand>) -> Cursor<'a, 'brand, T> {
         Cursor::new_front(token, self)
@@ -145,7 +181,223 @@ impl<'brand, T> TripodList<'brand, T> {
     /// Pushes an element to the front of the list.
     pub fn push_front(&mut self, value: T, token: &mut GhostToken<'brand>) {
         let (one, two) = Self::new_thirds(value, token);
+        self.push_front_thirds(one, two, token);
+    }
+
+    /// Removes and returns the front element of the list, if any.
+    pub fn pop_front(&mut self, token: &mut GhostToken<'brand>) -> Option<T> {
+        self.pop_front_thirds(token).map(Self::into_inner)
+    }
+
+    /// Pushes an element to the back of the list.
+    pub fn push_back(&mut self, value: T, token: &mut GhostToken<'brand>) {
+        let (one, two) = Self::new_thirds(value, token);
+        self.push_back_thirds(one, two, token);
+    }
+
+    /// Removes and returns the back element of the list, if any.
+    pub fn pop_back(&mut self, token: &mut GhostToken<'brand>) -> Option<T> {
+        self.pop_back_thirds(token).map(Self::into_inner)
+    }
+
+    /// Splits the list in two at the given index. Returns a list containing everything after the given index, inclusive.
+    ///
+    /// Returns None if the given index is out of bounds.
+    ///
+    /// #   Complexity
+    ///
+    /// This operations is O(min(`at`, N)), where N is the number of elements.
+    ///
+    /// No memory allocation or deallocation occurs.
+    pub fn split_off(&mut self, at: usize, token: &mut GhostToken<'brand>) -> Self {
+        assert!(at < self.length, "at ({}) >= self.len() ({})", at, self.length);
+
+        //  This is not the most optimal implementation, but it works, and respects the promised algorithmic complexity.
+        let mut cursor = self.cursor_front_mut(token);
+
+        if at == 0 {
+            cursor.move_prev();
+        } else {
+            for _ in 0..(at-1) {
+                cursor.move_next();
+            }
+        }
+
+        cursor.split_after()
+    }
+
+    /// Rotates the list in-place such that the first `k` elements move to the back, like `VecDeque::rotate_left`.
+    ///
+    /// #   Panics
+    ///
+    /// Panics if `k > self.len()`.
+    ///
+    /// #   Complexity
+    ///
+    /// This operation is O(min(`k`, N - `k`)), where N is the number of elements, walking from the nearest end.
+    ///
+    /// No memory allocation or deallocation occurs.
+    pub fn rotate_left(&mut self, k: usize, token: &mut GhostToken<'brand>) {
+        assert!(k <= self.length, "k ({}) > len ({})", k, self.length);
+
+        if k == 0 || k == self.length {
+            return;
+        }
+
+        if k <= self.length - k {
+            //  Split after the k-th element, then move the back part in front.
+            let mut back = {
+                let mut cursor = self.cursor_front_mut(token);
+
+                for _ in 1..k {
+                    cursor.move_next();
+                }
+
+                cursor.split_after()
+            };
+
+            self.prepend(&mut back, token);
+        } else {
+            //  Split before the (k+1)-th element, then move the front part in the back.
+            let length = self.length;
+
+            let mut front = {
+                let mut cursor = self.cursor_back_mut(token);
+
+                for _ in (k + 1)..length {
+                    cursor.move_prev();
+                }
+
+                cursor.split_before()
+            };
+
+            self.append(&mut front, token);
+        }
+    }
+
+    /// Rotates the list in-place such that the last `k` elements move to the front, like `VecDeque::rotate_right`.
+    ///
+    /// #   Panics
+    ///
+    /// Panics if `k > self.len()`.
+    ///
+    /// #   Complexity
+    ///
+    /// This operation is O(min(`k`, N - `k`)), where N is the number of elements, walking from the nearest end.
+    ///
+    /// No memory allocation or deallocation occurs.
+    pub fn rotate_right(&mut self, k: usize, token: &mut GhostToken<'brand>) {
+        assert!(k <= self.length, "k ({}) > len ({})", k, self.length);
+
+        self.rotate_left(self.length - k, token);
+    }
+
+    /// Removes the element at the given index, and returns it, if the index is within bounds.
+    ///
+    /// #   Complexity
+    ///
+    /// This operations is O(min(`at`, N)), where N is the number of elements.
+    ///
+    /// No memory allocation occurs, and a single memory deallocation occurs.
+    pub fn remove(&mut self, at: usize, token: &mut GhostToken<'brand>) -> T {
+        assert!(at < self.length, "at ({}) >= self.len() ({})", at, self.length);
+
+        //  This is not the most optimal implementation, but it works, and respects the promised algorithmic complexity.
+        let mut cursor = self.cursor_front_mut(token);
+
+        for _ in 0..at {
+            cursor.move_next();
+        }
+
+        cursor.remove_current().expect("Element, since at < self.length")
+    }
+
+    /// Checks the structural invariants of the list, returning the first violation found, if any.
+    ///
+    /// The following invariants are checked:
+    ///
+    /// -   The length of the list is the number of elements reachable from its head.
+    /// -   The head has no previous element, and the tail is the last element reachable from the head.
+    /// -   The previous pointer of the next element of each element points back to the element.
+    /// -   The tripod of each element is retracted.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(N) in the number of elements.
+    /// -   Space: O(1).
+    pub fn check_invariants(&self, token: &GhostToken<'brand>) -> Result<(), InvariantError> {
+        let (head, tail) = if let Some((head, tail)) = self.head_tail.as_ref() {
+            (&**head, &**tail)
+        } else if self.length == 0 {
+            return Ok(());
+        } else {
+            return Err(InvariantError::Length { expected: self.length, actual: 0 });
+        };
+
+        if head.borrow(token).prev.is_some() {
+            return Err(InvariantError::HeadTail);
+        }
+
+        let mut node = head;
+        let mut index = 0;
+
+        //  O(N) iterations, each checking a single node.
+        loop {
+            let current = node.borrow(token);
+
+            let tripod = current.tripod.take();
+            let retracted = matches!(tripod.as_deref(), Some(tripod) if ptr::eq(tripod, node));
+            current.tripod.set(tripod);
+
+            if !retracted {
+                return Err(InvariantError::Tripod { index });
+            }
+
+            let next = if let Some(next) = current.next.as_deref() {
+                next
+            } else {
+                break;
+            };
+
+            if !matches!(next.borrow(token).prev.as_deref(), Some(prev) if ptr::eq(prev, node)) {
+                return Err(InvariantError::Link { index });
+            }
+
+            node = next;
+            index += 1;
+        }
+
+        if !ptr::eq(node, tail) {
+            return Err(InvariantError::HeadTail);
+        }
+
+        if self.length != index + 1 {
+            return Err(InvariantError::Length { expected: self.length, actual: index + 1 });
+        }
+
+        Ok(())
+    }
+
+    //  Internal; asserts that the invariants hold, when the `paranoid` feature is enabled.
+    #[cfg(feature = "paranoid")]
+    fn assert_invariants(&self, token: &GhostToken<'brand>) {
+        if let Err(error) = self.check_invariants(token) {
+            panic!("TripodList invariant violated: {}", error);
+        }
+    }
+
+    //  Internal (dummy)
+    #[cfg(not(feature = "paranoid"))]
+    fn assert_invariants(&self, _: &GhostToken<'brand>) {}
+
+    //  Internal: returns a reference to the front node, if any.
+    fn front_node(&self) -> Option<&GhostNode<'brand, T>> { self.head_tail.as_ref().map(|ht| &*ht.0) }
+
+    //  Internal: returns a reference to the front node, if any.
+    fn back_node(&self) -> Option<&GhostNode<'brand, T>> { self.head_tail.as_ref().map(|ht| &*ht.1) }
 
+    //  Internal; pushes the node, of which 2/3 of the pointers are passed, to the front of the list.
+    fn push_front_thirds(&mut self, one: ThirdNodePtr<'brand, T>, two: ThirdNodePtr<'brand, T>, token: &mut GhostToken<'brand>) {
         let head_tail = if let Some((head, tail)) = self.head_tail.take() {
             head.borrow_mut(token).prev = Some(one);
             two.borrow_mut(token).next = Some(head);
@@ -157,10 +409,12 @@ impl<'brand, T> TripodList<'brand, T> {
 
         self.length += 1;
         self.head_tail = Some(head_tail);
+
+        self.assert_invariants(token);
     }
 
-    /// Removes and returns the front element of the list, if any.
-    pub fn pop_front(&mut self, token: &mut GhostToken<'brand>) -> Option<T> {
+    //  Internal; removes the front node of the list, if any, and returns all its pointers.
+    fn pop_front_thirds(&mut self, token: &mut GhostToken<'brand>) -> Option<ThirdTuple<'brand, T>> {
         let (head, tail) = self.head_tail.take()?;
         let tripod = head.borrow(token).deploy();
 
@@ -179,13 +433,13 @@ impl<'brand, T> TripodList<'brand, T> {
 
         self.length -= 1;
 
-        Some(Self::into_inner((one, two, tripod)))
-    }
+        self.assert_invariants(token);
 
-    /// Pushes an element to the back of the list.
-    pub fn push_back(&mut self, value: T, token: &mut GhostToken<'brand>) {
-        let (one, two) = Self::new_thirds(value, token);
+        Some((one, two, tripod))
+    }
 
+    //  Internal; pushes the node, of which 2/3 of the pointers are passed, to the back of the list.
+    fn push_back_thirds(&mut self, one: ThirdNodePtr<'brand, T>, two: ThirdNodePtr<'brand, T>, token: &mut GhostToken<'brand>) {
         let head_tail = if let Some((head, tail)) = self.head_tail.take() {
             tail.borrow_mut(token).next = Some(one);
             two.borrow_mut(token).prev = Some(tail);
@@ -197,10 +451,12 @@ impl<'brand, T> TripodList<'brand, T> {
 
         self.length += 1;
         self.head_tail = Some(head_tail);
+
+        self.assert_invariants(token);
     }
 
-    /// Removes and returns the back element of the list, if any.
-    pub fn pop_back(&mut self, token: &mut GhostToken<'brand>) -> Option<T> {
+    //  Internal; removes the back node of the list, if any, and returns all its pointers.
+    fn pop_back_thirds(&mut self, token: &mut GhostToken<'brand>) -> Option<ThirdTuple<'brand, T>> {
         let (head, tail) = self.head_tail.take()?;
         let tripod = tail.borrow(token).deploy();
 
@@ -219,65 +475,21 @@ impl<'brand, T> TripodList<'brand, T> {
 
         self.length -= 1;
 
-        Some(Self::into_inner((one, two, tripod)))
-    }
-
-    /// Splits the list in two at the given index. Returns a list containing everything after the given index, inclusive.
-    ///
-    /// Returns None if the given index is out of bounds.
-    ///
-    /// #   Complexity
-    ///
-    /// This operations is O(min(`at`, N)), where N is the number of elements.
-    ///
-    /// No memory allocation or deallocation occurs.
-    pub fn split_off(&mut self, at: usize, token: &mut GhostToken<'brand>) -> Self {
-        assert!(at < self.length, "at ({}) >= self.len() ({})", at, self.length);
-
-        //  This is not the most optimal implementation, but it works, and respects the promised algorithmic complexity.
-        let mut cursor = self.cursor_front_mut(token);
-
-        if at == 0 {
-            cursor.move_prev();
-        } else {
-            for _ in 0..(at-1) {
-                cursor.move_next();
-            }
-        }
-
-        cursor.split_after()
-    }
-
-    /// Removes the element at the given index, and returns it, if the index is within bounds.
-    ///
-    /// #   Complexity
-    ///
-    /// This operations is O(min(`at`, N)), where N is the number of elements.
-    ///
-    /// No memory allocation occurs, and a single memory deallocation occurs.
-    pub fn remove(&mut self, at: usize, token: &mut GhostToken<'brand>) -> T {
-        assert!(at < self.length, "at ({}) >= self.len() ({})", at, self.length);
-
-        //  This is not the most optimal implementation, but it works, and respects the promised algorithmic complexity.
-        let mut cursor = self.cursor_front_mut(token);
+        self.assert_invariants(token);
 
-        for _ in 0..at {
-            cursor.move_next();
-        }
-
-        cursor.remove_current().expect("Element, since at < self.length")
+        Some((one, two, tripod))
     }
 
-    //  Internal: returns a reference to the front node, if any.
-    fn front_node(&self) -> Option<&GhostNode<'brand, T>> { self.head_tail.as_ref().map(|ht| &*ht.0) }
-
-    //  Internal: returns a reference to the front node, if any.
-    fn back_node(&self) -> Option<&GhostNode<'brand, T>> { self.head_tail.as_ref().map(|ht| &*ht.1) }
-
     //  Internal: creates a node with the value, and returns 2/3 of its pointers, the remaining tucked into its tripod field.
     fn new_thirds(value: T, token: &GhostToken<'brand>) -> (ThirdNodePtr<'brand, T>, ThirdNodePtr<'brand, T>) {
         let node = Node { value, prev: None, next: None, tripod: Cell::new(None), };
         let full = FullNodePtr::new(GhostNode::new(node));
+
+        Self::full_into_thirds(full, token)
+    }
+
+    //  Internal: splits the node, and returns 2/3 of its pointers, the remaining tucked into its tripod field.
+    fn full_into_thirds(full: FullNodePtr<'brand, T>, token: &GhostToken<'brand>) -> (ThirdNodePtr<'brand, T>, ThirdNodePtr<'brand, T>) {
         let (partial, tripod) = StaticRc::split::<2, 1>(full);
 
         partial.borrow(token).retract(tripod);
@@ -287,8 +499,7 @@ impl<'brand, T> TripodList<'brand, T> {
 
     //  Internal: takes 3 1/3 pointers, reassemble them, and return their inner value.
     fn into_inner(thirds: ThirdTuple<'brand, T>) -> T {
-        let partial = TwoThirdsNodePtr::join(thirds.0, thirds.1);
-        let full = FullNodePtr::join(partial, thirds.2);
+        let full = Self::thirds_into_full(thirds);
         let ghost_cell = FullNodePtr::into_inner(full);
         let node = GhostNode::into_inner(ghost_cell);
 
@@ -299,6 +510,78 @@ impl<'brand, T> TripodList<'brand, T> {
 
         node.value
     }
+
+    //  Internal: takes 3 1/3 pointers, and reassemble them.
+    fn thirds_into_full(thirds: ThirdTuple<'brand, T>) -> FullNodePtr<'brand, T> {
+        let partial = TwoThirdsNodePtr::join(thirds.0, thirds.1);
+        FullNodePtr::join(partial, thirds.2)
+    }
+}
+
+impl<'brand, T: Clone> TripodList<'brand, T> {
+    /// Creates a copy of the list.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(N) in the number of elements.
+    /// -   Space: O(1).
+    ///
+    /// Exactly N memory allocations occur.
+    pub fn clone_with(&self, token: &mut GhostToken<'brand>) -> Self {
+        let mut result = Self::new();
+
+        let source = self.head_tail.as_ref().map(|head_tail| head_tail.0.borrow(token).deploy());
+        result.extend_cloned(source, token);
+
+        result
+    }
+
+    /// Turns self into a copy of `source`, reusing the nodes of self where possible.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(N + M) in the number of elements of `source` and self, respectively.
+    /// -   Space: O(1).
+    ///
+    /// At most N memory allocations occur.
+    pub fn clone_from_with(&mut self, source: &Self, token: &mut GhostToken<'brand>) {
+        let mut target = self.head_tail.as_ref().map(|head_tail| head_tail.0.borrow(token).deploy());
+        let mut source = source.head_tail.as_ref().map(|head_tail| head_tail.0.borrow(token).deploy());
+        let mut reused = 0;
+
+        while let (Some(target_tripod), Some(source_tripod)) = (target.as_ref(), source.as_ref()) {
+            let value = source_tripod.borrow(token).value.clone();
+            target_tripod.borrow_mut(token).value = value;
+            reused += 1;
+
+            let next_target = target_tripod.borrow(token).next.as_ref().map(|next| next.borrow(token).deploy());
+            let next_source = source_tripod.borrow(token).next.as_ref().map(|next| next.borrow(token).deploy());
+
+            retract(mem::replace(&mut target, next_target).expect("Target"), token);
+            retract(mem::replace(&mut source, next_source).expect("Source"), token);
+        }
+
+        if let Some(target) = target {
+            retract(target, token);
+
+            while self.length > reused {
+                self.pop_back(token);
+            }
+        }
+
+        self.extend_cloned(source, token);
+    }
+
+    //  Internal; appends a copy of the node, whose tripod is passed, and of all the nodes following it.
+    fn extend_cloned(&mut self, mut source: Option<ThirdNodePtr<'brand, T>>, token: &mut GhostToken<'brand>) {
+        while let Some(tripod) = source {
+            let value = tripod.borrow(token).value.clone();
+            self.push_back(value, token);
+
+            source = tripod.borrow(token).next.as_ref().map(|next| next.borrow(token).deploy());
+            retract(tripod, token);
+        }
+    }
 }
 
 impl<'brand, T> Default for TripodList<'brand, T> {
@@ -421,6 +704,36 @@ fn list_split_off_out_of_bounds() {
     });
 }
 
+#[test]
+fn list_rotate() {
+    for length in 0..5 {
+        for k in 0..=length {
+            with_list(create(0..length), |token, list| {
+                list.rotate_left(k as usize, token);
+
+                let expected = create(0..length);
+                let expected: Vec<_> = expected[k as usize..].iter().chain(&expected[..k as usize]).map(String::as_str).collect();
+
+                assert_eq!(Ok(()), list.check_invariants(token));
+                assert_eq!(expected, collect(list.iter(token)));
+
+                list.rotate_right(k as usize, token);
+
+                assert_eq!(Ok(()), list.check_invariants(token));
+                assert_eq!(create(0..length), collect(list.iter(token)));
+            });
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn list_rotate_out_of_bounds() {
+    with_list(create(0..4), |token, list| {
+        list.rotate_left(5, token);
+    });
+}
+
 #[track_caller]
 fn assert_list_remove(list: Vec<String>, at: usize, expected_list: &[&str], expected_result: &str) {
     with_list(list, |token, list| {
@@ -447,6 +760,67 @@ fn list_remove_out_of_bounds() {
     });
 }
 
+#[test]
+fn list_clone_with() {
+    for length in 0..4 {
+        with_list_duo(create(0..length), vec![], |token, list, clone| {
+            *clone = list.clone_with(token);
+
+            assert_eq!(Ok(()), clone.check_invariants(token));
+            assert_eq!(collect(list.iter(token)), collect(clone.iter(token)));
+            assert_eq!(length as usize, clone.len());
+        });
+    }
+}
+
+#[test]
+fn list_clone_from_with() {
+    for target in 0..4 {
+        for source in 0..4 {
+            with_list_duo(create(10..10 + target), create(0..source), |token, target, source| {
+                target.clone_from_with(source, token);
+
+                assert_eq!(Ok(()), target.check_invariants(token));
+                assert_eq!(collect(source.iter(token)), collect(target.iter(token)));
+                assert_eq!(source.len(), target.len());
+            });
+        }
+    }
+}
+
+#[test]
+fn list_check_invariants() {
+    with_list(create(0..0), |token, list| {
+        assert_eq!(Ok(()), list.check_invariants(token));
+    });
+
+    with_list(create(0..4), |token, list| {
+        assert_eq!(Ok(()), list.check_invariants(token));
+    });
+}
+
+#[test]
+fn list_check_invariants_length() {
+    with_list(create(0..4), |token, list| {
+        list.length += 1;
+
+        assert_eq!(Err(InvariantError::Length { expected: 5, actual: 4 }), list.check_invariants(token));
+
+        list.length -= 1;
+    });
+}
+
+#[test]
+fn list_check_invariants_tripod() {
+    with_list(create(0..4), |token, list| {
+        let tripod = list.back_node().expect("Non-empty").borrow(token).deploy();
+
+        assert_eq!(Err(InvariantError::Tripod { index: 3 }), list.check_invariants(token));
+
+        retract(tripod, token);
+    });
+}
+
 pub(crate) fn create(range: Range<i32>) -> Vec<String> {
     range.map(|n| n.to_string()).collect()
 }
@@ -0,0 +1,186 @@
+use alloc::{collections::VecDeque, vec::Vec};
+
+use ghost_cell::GhostToken;
+
+use crate::{linked_list::LinkedList, tripod_tree::TripodTree};
+
+use super::TripodList;
+
+//  Conversions.
+//
+//  The nodes of the LinkedList, TripodList and TripodTree all have different layouts, hence no conversion can reuse
+//  the nodes of the source collection: each node is released as soon as its element has been moved into the target.
+impl<'brand, T> TripodList<'brand, T> {
+    /// Creates a list from the elements of `vec`, in order.
+    ///
+    /// #   Complexity
+    ///
+    /// This operation is O(N) in the number of elements.
+    pub fn from_vec(vec: Vec<T>, token: &mut GhostToken<'brand>) -> Self {
+        let mut result = Self::new();
+
+        for value in vec {
+            result.push_back(value, token);
+        }
+
+        result
+    }
+
+    /// Creates a list from the elements of `deque`, in order.
+    ///
+    /// #   Complexity
+    ///
+    /// This operation is O(N) in the number of elements.
+    pub fn from_vec_deque(deque: VecDeque<T>, token: &mut GhostToken<'brand>) -> Self {
+        let mut result = Self::new();
+
+        for value in deque {
+            result.push_back(value, token);
+        }
+
+        result
+    }
+
+    /// Creates a list from the elements of `tree`, in order.
+    ///
+    /// #   Complexity
+    ///
+    /// This operation is O(N) in the number of elements.
+    pub fn from_tree(tree: TripodTree<'brand, T>, token: &mut GhostToken<'brand>) -> Self {
+        let mut result = Self::new();
+
+        tree.drain_with(token, |value, token| result.push_back(value, token));
+
+        result
+    }
+
+    /// Creates a list from the elements of `list`, in order.
+    ///
+    /// #   Complexity
+    ///
+    /// This operation is O(N) in the number of elements.
+    pub fn from_linked_list(mut list: LinkedList<'brand, T>, token: &mut GhostToken<'brand>) -> Self {
+        let mut result = Self::new();
+
+        while let Some(value) = list.pop_front(token) {
+            result.push_back(value, token);
+        }
+
+        result
+    }
+
+    /// Moves the elements of the list, in order, into a `Vec`.
+    ///
+    /// #   Complexity
+    ///
+    /// This operation is O(N) in the number of elements.
+    pub fn into_vec(mut self, token: &mut GhostToken<'brand>) -> Vec<T> {
+        let mut result = Vec::with_capacity(self.len());
+
+        while let Some(value) = self.pop_front(token) {
+            result.push(value);
+        }
+
+        result
+    }
+
+    /// Moves the elements of the list, in order, into a `VecDeque`.
+    ///
+    /// #   Complexity
+    ///
+    /// This operation is O(N) in the number of elements.
+    pub fn into_vec_deque(mut self, token: &mut GhostToken<'brand>) -> VecDeque<T> {
+        let mut result = VecDeque::with_capacity(self.len());
+
+        while let Some(value) = self.pop_front(token) {
+            result.push_back(value);
+        }
+
+        result
+    }
+
+    /// Copies the elements of the list, in order, into a `Vec`.
+    ///
+    /// #   Complexity
+    ///
+    /// This operation is O(N) in the number of elements.
+    pub fn to_vec(&self, token: &GhostToken<'brand>) -> Vec<T>
+    where
+        T: Clone,
+    {
+        let mut result = Vec::with_capacity(self.len());
+        result.extend(self.iter(token).cloned());
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+use super::super::tests::{collect, create, with_list};
+use super::*;
+
+#[test]
+fn conversion_vec() {
+    for length in 0..6 {
+        with_list(create(0..0), |token, _| {
+            let expected = create(0..length);
+
+            let list = TripodList::from_vec(expected.clone(), token);
+
+            assert_eq!(length as usize, list.len());
+            assert_eq!(Ok(()), list.check_invariants(token));
+            assert_eq!(expected, list.to_vec(token));
+            assert_eq!(expected, list.into_vec(token));
+        });
+    }
+}
+
+#[test]
+fn conversion_vec_deque() {
+    for length in 0..6 {
+        with_list(create(0..0), |token, _| {
+            let expected: VecDeque<String> = create(0..length).into();
+
+            let list = TripodList::from_vec_deque(expected.clone(), token);
+
+            assert_eq!(length as usize, list.len());
+            assert_eq!(Ok(()), list.check_invariants(token));
+            assert_eq!(expected, list.into_vec_deque(token));
+        });
+    }
+}
+
+#[test]
+fn conversion_tree() {
+    for length in 0..20 {
+        with_list(create(0..0), |token, list| {
+            let tree = TripodTree::from_vec(create(0..length), token);
+
+            *list = TripodList::from_tree(tree, token);
+
+            assert_eq!(Ok(()), list.check_invariants(token));
+            assert_eq!(create(0..length), collect(list.iter(token)));
+        });
+    }
+}
+
+#[test]
+fn conversion_linked_list() {
+    for length in 0..6 {
+        with_list(create(0..0), |token, list| {
+            let mut linked = LinkedList::new();
+
+            for value in create(0..length) {
+                linked.push_back(value, token);
+            }
+
+            *list = TripodList::from_linked_list(linked, token);
+
+            assert_eq!(Ok(()), list.check_invariants(token));
+            assert_eq!(create(0..length), collect(list.iter(token)));
+        });
+    }
+}
+
+} // mod tests
@@ -2,7 +2,7 @@ use core::mem;
 
 use ghost_cell::GhostToken;
 
-use super::{GhostNode, Iter, ThirdNodePtr, TripodList};
+use super::{GhostNode, Iter, NodePool, ThirdNodePtr, TripodList};
 
 /// A Cursor over the TripodList.
 pub struct Cursor<'a, 'brand, T> {
@@ -294,6 +294,49 @@ impl<'a, 'brand, T> CursorMut<'a, 'brand, T> {
         list.pop_front(self.token)
     }
 
+    /// Inserts a new element in the list after the current one, reusing a node from the pool, if any.
+    ///
+    /// See `insert_after` for the details.
+    ///
+    /// A memory allocation occurs only if the pool is empty.
+    pub fn insert_after_pooled(&mut self, item: T, pool: &mut NodePool<'brand, T>) {
+        let mut list = TripodList::new();
+        list.push_front_pooled(item, pool, self.token);
+
+        self.splice_after(&mut list);
+
+        debug_assert!(list.is_empty());
+    }
+
+    /// Inserts a new element in the list before the current one, reusing a node from the pool, if any.
+    ///
+    /// See `insert_before` for the details.
+    ///
+    /// A memory allocation occurs only if the pool is empty.
+    pub fn insert_before_pooled(&mut self, item: T, pool: &mut NodePool<'brand, T>) {
+        let mut list = TripodList::new();
+        list.push_back_pooled(item, pool, self.token);
+
+        self.splice_before(&mut list);
+
+        debug_assert!(list.is_empty());
+    }
+
+    /// Removes the current element from the list, and return it, recycling its node into the pool.
+    ///
+    /// See `remove_current` for the details.
+    ///
+    /// No memory allocation or deallocation occurs.
+    pub fn remove_current_pooled(&mut self, pool: &mut NodePool<'brand, T>) -> Option<T>
+    where
+        T: Default,
+    {
+        let mut list = self.remove_current_as_list()?;
+        debug_assert_eq!(1, list.len());
+
+        list.pop_front_pooled(pool, self.token)
+    }
+
     /// Removes the current element from the list, and return it as a list of its own.
     ///
     /// If the cursor is pointing to the "twilight" non-element, then None is returned and the cursor is left unmodified.
@@ -522,6 +565,8 @@ impl<'a, 'brand, T> Drop for CursorMut<'a, 'brand, T> {
         if let Some(tripod) = self.node.take() {
             super::retract(tripod, self.token);
         }
+
+        self.list.assert_invariants(self.token);
     }
 }
 
@@ -0,0 +1,104 @@
+use std::vec::Vec;
+
+use ghost_cell::GhostToken;
+use rayon::{
+    iter::{
+        IndexedParallelIterator,
+        IntoParallelIterator,
+        ParallelIterator,
+        plumbing::{Consumer, ProducerCallback, UnindexedConsumer},
+    },
+    vec::IntoIter,
+};
+
+use super::TripodList;
+
+/// A parallel iterator over a TripodList.
+///
+/// The nodes of a TripodList cannot be shared across threads, as their tripod pointer is held in a `Cell`, hence the
+/// references to the elements are gathered on the calling thread, and only those are split by index and processed in
+/// parallel.
+pub struct ParIter<'a, T> {
+    elements: Vec<&'a T>,
+}
+
+impl<'a, T> ParIter<'a, T> {
+    /// Creates a new instance, iterating over the entire list.
+    pub fn new<'brand>(token: &'a GhostToken<'brand>, list: &'a TripodList<'brand, T>) -> Self {
+        let mut elements = Vec::with_capacity(list.len());
+        elements.extend(list.iter(token));
+
+        Self { elements, }
+    }
+
+    //  Internal; converts into the underlying parallel iterator.
+    fn into_inner(self) -> IntoIter<&'a T>
+    where
+        T: Sync,
+    {
+        self.elements.into_par_iter()
+    }
+}
+
+impl<'a, T: Sync> ParallelIterator for ParIter<'a, T> {
+    type Item = &'a T;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        self.into_inner().drive_unindexed(consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> { Some(self.elements.len()) }
+}
+
+impl<'a, T: Sync> IndexedParallelIterator for ParIter<'a, T> {
+    fn drive<C>(self, consumer: C) -> C::Result
+    where
+        C: Consumer<Self::Item>,
+    {
+        self.into_inner().drive(consumer)
+    }
+
+    fn len(&self) -> usize { self.elements.len() }
+
+    fn with_producer<CB>(self, callback: CB) -> CB::Output
+    where
+        CB: ProducerCallback<Self::Item>,
+    {
+        self.into_inner().with_producer(callback)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+use super::super::tests::with_list;
+use super::*;
+
+#[test]
+fn par_iter_empty() {
+    with_list(Vec::<u64>::new(), |token, list| {
+        let iter = list.par_iter(token);
+
+        assert_eq!(0, iter.len());
+        assert_eq!(0, iter.count());
+    });
+}
+
+#[test]
+fn par_iter_fold() {
+    with_list((0..10_000u64).collect(), |token, list| {
+        let iter = list.par_iter(token);
+
+        assert_eq!(10_000, iter.len());
+        assert_eq!(49_995_000u64, iter.sum());
+
+        let collected: Vec<u64> = list.par_iter(token).skip(9_997).copied().collect();
+
+        assert_eq!(vec![9_997, 9_998, 9_999], collected);
+    });
+}
+
+} // mod tests
@@ -0,0 +1,264 @@
+use core::{
+    cell::Cell,
+    mem,
+};
+
+use ghost_cell::GhostToken;
+use static_rc::StaticRc;
+
+use super::{FullNodePtr, GhostNode, Node, TripodList, TwoThirdsNodePtr};
+
+/// A pool of nodes, recycled from TripodLists, for reuse by TripodLists of the same brand.
+///
+/// Recycled nodes hold a default value until they are reused, hence recycling requires `T: Default`.
+///
+/// The recycled nodes are chained through their own pointers, hence the pool itself never allocates.
+pub struct NodePool<'brand, T> {
+    length: usize,
+    head: Option<FullNodePtr<'brand, T>>,
+}
+
+impl<'brand, T> NodePool<'brand, T> {
+    /// Creates an empty pool.
+    pub fn new() -> Self { Self::default() }
+
+    /// Returns whether the pool is empty, or not.
+    pub fn is_empty(&self) -> bool { self.head.is_none() }
+
+    /// Returns the number of nodes in the pool.
+    pub fn len(&self) -> usize { self.length }
+
+    /// Allocates `additional` nodes into the pool.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(`additional`).
+    /// -   Space: O(1).
+    ///
+    /// Exactly `additional` memory allocations occur.
+    pub fn reserve(&mut self, additional: usize)
+    where
+        T: Default,
+    {
+        for _ in 0..additional {
+            let node = Self::new_node(T::default());
+            self.push(node);
+        }
+    }
+
+    /// Releases all nodes of the pool.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(N) in the number of nodes.
+    /// -   Space: O(1).
+    pub fn clear(&mut self) {
+        while let Some(node) = self.pop() {
+            mem::drop(node);
+        }
+    }
+
+    //  Internal; takes a node from the pool, or allocates a new one, holding `value`.
+    pub(super) fn allocate(&mut self, value: T) -> FullNodePtr<'brand, T> {
+        if let Some(mut node) = self.pop() {
+            FullNodePtr::get_mut(&mut node).get_mut().value = value;
+            node
+        } else {
+            Self::new_node(value)
+        }
+    }
+
+    //  Internal; keeps the node in the pool, and returns its value.
+    pub(super) fn recycle(&mut self, mut node: FullNodePtr<'brand, T>) -> T
+    where
+        T: Default,
+    {
+        let value = mem::take(&mut FullNodePtr::get_mut(&mut node).get_mut().value);
+        self.push(node);
+
+        value
+    }
+
+    //  Internal; allocates a new node.
+    fn new_node(value: T) -> FullNodePtr<'brand, T> {
+        FullNodePtr::new(GhostNode::new(Node { value, prev: None, next: None, tripod: Cell::new(None), }))
+    }
+
+    //  Internal; pushes the node on top of the pool, tucking the former top node into its pointers.
+    fn push(&mut self, mut node: FullNodePtr<'brand, T>) {
+        let inner = FullNodePtr::get_mut(&mut node).get_mut();
+
+        debug_assert!(inner.prev.is_none());
+        debug_assert!(inner.next.is_none());
+        debug_assert!(inner.tripod.get_mut().is_none());
+
+        if let Some(head) = self.head.take() {
+            let (partial, tripod) = StaticRc::split::<2, 1>(head);
+            let (prev, next) = StaticRc::split::<1, 1>(partial);
+
+            inner.prev = Some(prev);
+            inner.next = Some(next);
+            *inner.tripod.get_mut() = Some(tripod);
+        }
+
+        self.length += 1;
+        self.head = Some(node);
+    }
+
+    //  Internal; pops the node on top of the pool, if any, restoring the former top node.
+    fn pop(&mut self) -> Option<FullNodePtr<'brand, T>> {
+        let mut node = self.head.take()?;
+        let inner = FullNodePtr::get_mut(&mut node).get_mut();
+
+        if let Some(tripod) = inner.tripod.get_mut().take() {
+            let prev = inner.prev.take().expect("Pooled node - prev");
+            let next = inner.next.take().expect("Pooled node - next");
+
+            let partial = TwoThirdsNodePtr::join(prev, next);
+            self.head = Some(FullNodePtr::join(partial, tripod));
+        }
+
+        self.length -= 1;
+
+        Some(node)
+    }
+}
+
+impl<'brand, T> TripodList<'brand, T> {
+    /// Pushes an element to the front of the list, reusing a node from the pool, if any.
+    ///
+    /// #   Complexity
+    ///
+    /// This operation is O(1) in the number of elements.
+    ///
+    /// A memory allocation occurs only if the pool is empty.
+    pub fn push_front_pooled(&mut self, value: T, pool: &mut NodePool<'brand, T>, token: &mut GhostToken<'brand>) {
+        let (one, two) = Self::full_into_thirds(pool.allocate(value), token);
+        self.push_front_thirds(one, two, token);
+    }
+
+    /// Removes and returns the front element of the list, if any, recycling its node into the pool.
+    ///
+    /// #   Complexity
+    ///
+    /// This operation is O(1) in the number of elements.
+    ///
+    /// No memory allocation or deallocation occurs.
+    pub fn pop_front_pooled(&mut self, pool: &mut NodePool<'brand, T>, token: &mut GhostToken<'brand>) -> Option<T>
+    where
+        T: Default,
+    {
+        let thirds = self.pop_front_thirds(token)?;
+        Some(pool.recycle(Self::thirds_into_full(thirds)))
+    }
+
+    /// Pushes an element to the back of the list, reusing a node from the pool, if any.
+    ///
+    /// #   Complexity
+    ///
+    /// This operation is O(1) in the number of elements.
+    ///
+    /// A memory allocation occurs only if the pool is empty.
+    pub fn push_back_pooled(&mut self, value: T, pool: &mut NodePool<'brand, T>, token: &mut GhostToken<'brand>) {
+        let (one, two) = Self::full_into_thirds(pool.allocate(value), token);
+        self.push_back_thirds(one, two, token);
+    }
+
+    /// Removes and returns the back element of the list, if any, recycling its node into the pool.
+    ///
+    /// #   Complexity
+    ///
+    /// This operation is O(1) in the number of elements.
+    ///
+    /// No memory allocation or deallocation occurs.
+    pub fn pop_back_pooled(&mut self, pool: &mut NodePool<'brand, T>, token: &mut GhostToken<'brand>) -> Option<T>
+    where
+        T: Default,
+    {
+        let thirds = self.pop_back_thirds(token)?;
+        Some(pool.recycle(Self::thirds_into_full(thirds)))
+    }
+}
+
+impl<'brand, T> Default for NodePool<'brand, T> {
+    fn default() -> Self { Self { length: 0, head: None, } }
+}
+
+impl<'brand, T> Drop for NodePool<'brand, T> {
+    fn drop(&mut self) { self.clear(); }
+}
+
+#[cfg(test)]
+mod tests {
+
+use super::super::tests::{collect, create, with_list};
+use super::*;
+
+#[test]
+fn pool_reserve_clear() {
+    let mut pool = NodePool::<'_, String>::new();
+
+    assert!(pool.is_empty());
+
+    pool.reserve(3);
+
+    assert!(!pool.is_empty());
+    assert_eq!(3, pool.len());
+
+    pool.clear();
+
+    assert!(pool.is_empty());
+    assert_eq!(0, pool.len());
+
+    pool.reserve(2);
+}
+
+#[test]
+fn pool_push_pop() {
+    with_list(create(0..3), |token, list| {
+        let mut pool = NodePool::new();
+
+        assert_eq!(Some("0".to_string()), list.pop_front_pooled(&mut pool, token));
+        assert_eq!(Some("2".to_string()), list.pop_back_pooled(&mut pool, token));
+
+        assert_eq!(2, pool.len());
+        assert_eq!(&["1"][..], collect(list.iter(token)));
+
+        list.push_back_pooled("3".to_string(), &mut pool, token);
+        list.push_front_pooled("4".to_string(), &mut pool, token);
+        list.push_front_pooled("5".to_string(), &mut pool, token);
+
+        assert_eq!(0, pool.len());
+        assert_eq!(&["5", "4", "1", "3"][..], collect(list.iter(token)));
+        assert_eq!(Ok(()), list.check_invariants(token));
+
+        while list.pop_back_pooled(&mut pool, token).is_some() {}
+
+        assert_eq!(4, pool.len());
+        assert!(list.is_empty());
+    });
+}
+
+#[test]
+fn pool_cursor() {
+    with_list(create(0..3), |token, list| {
+        let mut pool = NodePool::new();
+
+        {
+            let mut cursor = list.cursor_front_mut(token);
+            cursor.move_next();
+
+            assert_eq!(Some("1".to_string()), cursor.remove_current_pooled(&mut pool));
+            assert_eq!(1, pool.len());
+
+            cursor.insert_after_pooled("3".to_string(), &mut pool);
+            cursor.insert_before_pooled("4".to_string(), &mut pool);
+        }
+
+        assert_eq!(0, pool.len());
+        assert_eq!(&["0", "4", "2", "3"][..], collect(list.iter(token)));
+        assert_eq!(Ok(()), list.check_invariants(token));
+    });
+}
+
+} // mod tests
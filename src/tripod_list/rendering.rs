@@ -0,0 +1,76 @@
+use alloc::string::String;
+use core::fmt::{self, Write};
+
+use ghost_cell::GhostToken;
+
+use crate::rendering::write_dot_escaped;
+
+use super::TripodList;
+
+//  Rendering.
+impl<'brand, T> TripodList<'brand, T> {
+    /// Renders the list in the Graphviz DOT format.
+    ///
+    /// Each node is labelled by `label` and annotated with its index, and each node is linked to its neighbours by
+    /// `next` and `prev` edges.
+    ///
+    /// #   Complexity
+    ///
+    /// This operation is O(N) in the number of elements.
+    pub fn to_dot<F, L>(&self, mut label: F, token: &GhostToken<'brand>) -> String
+    where
+        F: FnMut(&T) -> L,
+        L: fmt::Display,
+    {
+        let mut out = String::new();
+
+        out.push_str("digraph TripodList {\n    rankdir=LR;\n    node [shape=box];\n");
+
+        for (index, element) in self.iter(token).enumerate() {
+            write!(out, "    n{} [label=\"", index).expect("Infallible - writing to a String");
+            write_dot_escaped(&mut out, label(element));
+            writeln!(out, "\\nindex: {}\"];", index).expect("Infallible - writing to a String");
+
+            if index > 0 {
+                writeln!(out, "    n{} -> n{} [label=\"next\"];", index - 1, index).expect("Infallible - writing to a String");
+                writeln!(out, "    n{} -> n{} [label=\"prev\", style=dashed];", index, index - 1)
+                    .expect("Infallible - writing to a String");
+            }
+        }
+
+        out.push_str("}\n");
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+use super::super::tests::{create, with_list};
+
+#[test]
+fn rendering_empty() {
+    with_list(create(0..0), |token, list| {
+        assert_eq!("digraph TripodList {\n    rankdir=LR;\n    node [shape=box];\n}\n", list.to_dot(|e| e.clone(), token));
+    });
+}
+
+#[test]
+fn rendering_dot() {
+    with_list(create(0..2), |token, list| {
+        let expected = concat!(
+            "digraph TripodList {\n",
+            "    rankdir=LR;\n",
+            "    node [shape=box];\n",
+            "    n0 [label=\"#0\\nindex: 0\"];\n",
+            "    n1 [label=\"#1\\nindex: 1\"];\n",
+            "    n0 -> n1 [label=\"next\"];\n",
+            "    n1 -> n0 [label=\"prev\", style=dashed];\n",
+            "}\n",
+        );
+
+        assert_eq!(expected, list.to_dot(|e| format!("#{}", e), token));
+    });
+}
+
+} // mod tests
@@ -3,22 +3,43 @@
 //! The `TripodTree` is building block for Binary Trees, out of the box, it provides:
 //!
 //! -   Order-Preservation: the relative order of inserted items is preserved throughout mutations.
-//! -   Balancing: the tree is balanced automatically, so that at any point the left-subtree and right-subtree number
-//!     of elements differ by at most a factor of 2.
+//! -   Balancing: the tree is balanced automatically, according to a pluggable `BalancePolicy`; by default so that at
+//!     any point the left-subtree and right-subtree number of elements differ by at most a factor of 2.
 //! -   Indexing: each element in the tree is indexed by a number in [0, N), where N is the number of elements,
 //!     according to their order.
 //!
 //! The `TripodTree`, however, does not by itself establish any order, it simply preserves the order of insertion.
 
+mod balance;
+mod conversion;
 mod cursor;
 mod iter;
+mod pool;
+mod rendering;
+mod traversal;
 
+#[cfg(feature = "rayon")]
+mod par_iter;
+
+#[cfg(feature = "std")]
+mod snapshot;
+
+pub use balance::{Avl, BalancePolicy, RedBlack, Summary, Treap, Unbalanced, WeightBalanced};
 pub use cursor::{Cursor, CursorMut};
 pub use iter::Iter;
+pub use pool::NodePool;
+pub use traversal::{LevelOrder, PostOrder, PreOrder, Visit, Visitor};
+
+#[cfg(feature = "rayon")]
+pub use par_iter::ParIter;
+
+#[cfg(feature = "std")]
+pub use snapshot::SnapshotCodec;
 
 use core::{
     cell::Cell,
     cmp,
+    marker::PhantomData,
     mem,
     ops::{Bound, Range, RangeBounds},
 };
@@ -26,23 +47,28 @@ use core::{
 use ghost_cell::{GhostCell, GhostToken};
 use static_rc::StaticRc;
 
+use crate::invariant::InvariantError;
+
 #[cfg(feature = "experimental-ghost-cursor")]
 use ghost_cell::GhostCursor;
 
 /// A safe implementation of an indexed balanced binary tree.
 ///
 /// Each node contains 1 element as well as 4 pointers: up, left, right, and the tripod pointer.
-pub struct TripodTree<'brand, T> {
+///
+/// The tree is balanced according to the policy `P`, by default `WeightBalanced`, see `BalancePolicy`.
+pub struct TripodTree<'brand, T, P = WeightBalanced> {
     root: Option<QuarterNodePtr<'brand, T>>,
+    policy: PhantomData<P>,
 }
 
-impl<'brand, T> TripodTree<'brand, T> {
+impl<'brand, T, P: BalancePolicy> TripodTree<'brand, T, P> {
     /// Creates a new, empty, instance.
-    pub const fn new() -> Self { Self { root: None, } }
+    pub const fn new() -> Self { Self { root: None, policy: PhantomData, } }
 
     /// Creates a new instance, with a single value.
     pub fn singleton(value: T, token: &mut GhostToken<'brand>) -> Self {
-        Self { root: Some(Self::from_value(value, token)) }
+        Self { root: Some(Self::from_value(value, token)), policy: PhantomData, }
     }
 
     /// Creates an iterator over the entire tree, from front to back.
@@ -51,7 +77,8 @@ impl<'brand, T> TripodTree<'brand, T> {
     ///
     /// The complexity of this method itself is O(1).
     ///
-    /// The complexity of calling `next` on the resulting iterator is O(log N) in the number of elements.
+    /// The complexity of calling `next` on the resulting iterator is amortized O(1), and O(log N) in the worst case, in
+    /// the number of elements, hence iterating over the entire tree is O(N).
     pub fn iter<'a>(&'a self, token: &'a GhostToken<'brand>) -> Iter<'a, 'brand, T> {
         Iter::new(token, self)
     }
@@ -64,7 +91,8 @@ impl<'brand, T> TripodTree<'brand, T> {
     ///
     /// The complexity of this method itself is O(1).
     ///
-    /// The complexity of calling `next` on the resulting iterator is O(log N) in the number of elements.
+    /// The complexity of calling `next` on the resulting iterator is amortized O(1), and O(log N) in the worst case, in
+    /// the number of elements, hence iterating over a range of k elements is O(log N + k).
     pub fn iter_range<'a, R>(&'a self, range: R, token: &'a GhostToken<'brand>) -> Iter<'a, 'brand, T>
     where
         R: RangeBounds<usize>,
@@ -74,13 +102,79 @@ impl<'brand, T> TripodTree<'brand, T> {
         Iter::range(token, self, range)
     }
 
+    /// Creates a pre-order iterator over the entire tree: each element is yielded before its left sub-tree, itself
+    /// yielded before its right sub-tree.
+    ///
+    /// #   Complexity
+    ///
+    /// The complexity of this method itself is O(1).
+    ///
+    /// The complexity of iterating over the entire tree is O(N) in the number of elements, and no memory allocation
+    /// occurs.
+    pub fn iter_preorder<'a>(&'a self, token: &'a GhostToken<'brand>) -> PreOrder<'a, 'brand, T> {
+        PreOrder::new(token, self)
+    }
+
+    /// Creates a post-order iterator over the entire tree: the left sub-tree of each element is yielded before its
+    /// right sub-tree, itself yielded before the element.
+    ///
+    /// #   Complexity
+    ///
+    /// The complexity of this method itself is O(1).
+    ///
+    /// The complexity of iterating over the entire tree is O(N) in the number of elements, and no memory allocation
+    /// occurs.
+    pub fn iter_postorder<'a>(&'a self, token: &'a GhostToken<'brand>) -> PostOrder<'a, 'brand, T> {
+        PostOrder::new(token, self)
+    }
+
+    /// Creates a level-order, or breadth-first, iterator over the entire tree: the elements are yielded by increasing
+    /// depth, and from front to back within a given depth.
+    ///
+    /// #   Complexity
+    ///
+    /// The complexity of this method itself is O(1).
+    ///
+    /// The complexity of iterating over the entire tree is O(N) in the number of elements for a complete tree, and
+    /// O(N log N) in the worst case, and no memory allocation occurs.
+    ///
+    /// #   Deviation
+    ///
+    /// A breadth-first traversal typically relies on a queue of O(N) nodes. Instead, each depth is reached anew by a
+    /// depth-first walk from the root, which visits all the nodes above this depth once more. Since the number of
+    /// nodes roughly doubles with each depth of a balanced tree, those repeated visits are amortized.
+    pub fn iter_level_order<'a>(&'a self, token: &'a GhostToken<'brand>) -> LevelOrder<'a, 'brand, T> {
+        LevelOrder::new(token, self)
+    }
+
+    /// Creates a parallel iterator over the entire tree, from front to back.
+    ///
+    /// #   Complexity
+    ///
+    /// The complexity of this method itself is O(N) in the number of elements, as references to all elements are
+    /// gathered on the calling thread.
+    ///
+    /// One memory allocation occurs, of N references.
+    ///
+    /// #   Deviation
+    ///
+    /// A parallel iterator would typically split the tree along its sub-trees, and let each thread walk its own part.
+    /// The tripod pointer of each node is held in a `Cell`, however, hence the nodes are not `Sync` and cannot be
+    /// borrowed from other threads, even with a shared token. Instead, the references to the elements are gathered on
+    /// the calling thread, and only those are processed in parallel, which only pays off when processing each element
+    /// dominates the serial walk.
+    #[cfg(feature = "rayon")]
+    pub fn par_iter<'a>(&'a self, token: &'a GhostToken<'brand>) -> ParIter<'a, T> {
+        ParIter::new(token, self)
+    }
+
     /// Creates a cursor pointing to the root element.
     pub fn cursor<'a>(&'a self, token: &'a GhostToken<'brand>) -> Cursor<'a, 'brand, T> {
         Cursor::new(token, self)
     }
 
     /// Creates a mutable cursor pointing to the root element.
-    pub fn cursor_mut<'a>(&'a mut self, token: &'a mut GhostToken<'brand>) -> CursorMut<'a, 'brand, T> {
+    pub fn cursor_mut<'a>(&'a mut self, token: &'a mut GhostToken<'brand>) -> CursorMut<'a, 'brand, T, P> {
         CursorMut::new(token, self)
     }
 
@@ -90,7 +184,7 @@ impl<'brand, T> TripodTree<'brand, T> {
     }
 
     /// Creates a mutable cursor pointing to the front element.
-    pub fn cursor_front_mut<'a>(&'a mut self, token: &'a mut GhostToken<'brand>) -> CursorMut<'a, 'brand, T> {
+    pub fn cursor_front_mut<'a>(&'a mut self, token: &'a mut GhostToken<'brand>) -> CursorMut<'a, 'brand, T, P> {
         CursorMut::new_front(token, self)
     }
 
@@ -100,7 +194,7 @@ impl<'brand, T> TripodTree<'brand, T> {
     }
 
     /// Creates a mutable cursor pointing to the back element.
-    pub fn cursor_back_mut<'a>(&'a mut self, token: &'a mut GhostToken<'brand>) -> CursorMut<'a, 'brand, T> {
+    pub fn cursor_back_mut<'a>(&'a mut self, token: &'a mut GhostToken<'brand>) -> CursorMut<'a, 'brand, T, P> {
         CursorMut::new_back(token, self)
     }
 
@@ -258,7 +352,7 @@ impl<'brand, T> TripodTree<'brand, T> {
     /// -   Space: O(1).
     ///
     /// No memory allocation nor deallocation occurs.
-    pub fn append(&mut self, other: &mut TripodTree<'brand, T>, token: &mut GhostToken<'brand>) {
+    pub fn append(&mut self, other: &mut Self, token: &mut GhostToken<'brand>) {
         let mut cursor = self.cursor_mut(token);
         cursor.move_to_back();
         cursor.splice_after(other);
@@ -272,7 +366,7 @@ impl<'brand, T> TripodTree<'brand, T> {
     /// -   Space: O(1).
     ///
     /// No memory allocation nor deallocation occurs.
-    pub fn prepend(&mut self, other: &mut TripodTree<'brand, T>, token: &mut GhostToken<'brand>) {
+    pub fn prepend(&mut self, other: &mut Self, token: &mut GhostToken<'brand>) {
         let mut cursor = self.cursor_mut(token);
         cursor.move_to_front();
         cursor.splice_before(other);
@@ -290,7 +384,7 @@ impl<'brand, T> TripodTree<'brand, T> {
     /// -   Space: O(1).
     ///
     /// No memory allocation nor deallocation occurs.
-    pub fn split_off(&mut self, at: usize, token: &mut GhostToken<'brand>) -> TripodTree<'brand, T> {
+    pub fn split_off(&mut self, at: usize, token: &mut GhostToken<'brand>) -> Self {
         let length = self.len(token);
         assert!(at <= length, "{} > {}", at, length);
 
@@ -302,23 +396,29 @@ impl<'brand, T> TripodTree<'brand, T> {
 
         mem::swap(self, &mut result);
 
+        self.assert_invariants(token);
+
         result
     }
 
     /// Splits the tree into two according to the given range. Returns everything within the range.
     ///
+    /// #   Panics
+    ///
+    /// Panics if the start of the range is greater than its end.
+    ///
     /// #   Complexity
     ///
     /// -   Time: O(log² N) in the number of elements.
     /// -   Space: O(1).
     ///
     /// No memory allocation nor deallocation occurs.
-    pub fn split<R>(&mut self, range: R, token: &mut GhostToken<'brand>) -> TripodTree<'brand, T>
+    pub fn split<R>(&mut self, range: R, token: &mut GhostToken<'brand>) -> Self
     where
         R: RangeBounds<usize>,
     {
         let length = self.len(token);
-        let range = self.into_range(range, token);
+        let range = self.checked_range(range, token);
 
         //  Full Range, well that's easy.
         if range.start == 0 && range.end == length {
@@ -349,6 +449,288 @@ impl<'brand, T> TripodTree<'brand, T> {
         result
     }
 
+    /// Rotates the tree in-place such that the first `k` elements move to the back, like `VecDeque::rotate_left`.
+    ///
+    /// #   Panics
+    ///
+    /// Panics if `k > self.len()`.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(log N) in the number of elements.
+    /// -   Space: O(1).
+    ///
+    /// No memory allocation nor deallocation occurs.
+    pub fn rotate_left(&mut self, k: usize, token: &mut GhostToken<'brand>) {
+        let length = self.len(token);
+        assert!(k <= length, "k ({}) > len ({})", k, length);
+
+        let mut back = self.split_off_joined(k, token);
+
+        back.append_joined(self, token);
+
+        mem::swap(self, &mut back);
+
+        self.assert_invariants(token);
+    }
+
+    /// Rotates the tree in-place such that the last `k` elements move to the front, like `VecDeque::rotate_right`.
+    ///
+    /// #   Panics
+    ///
+    /// Panics if `k > self.len()`.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(log N) in the number of elements.
+    /// -   Space: O(1).
+    ///
+    /// No memory allocation nor deallocation occurs.
+    pub fn rotate_right(&mut self, k: usize, token: &mut GhostToken<'brand>) {
+        let length = self.len(token);
+        assert!(k <= length, "k ({}) > len ({})", k, length);
+
+        self.rotate_left(length - k, token);
+    }
+
+    /// Removes the elements within the given range, dropping them.
+    ///
+    /// #   Panics
+    ///
+    /// Panics if the start of the range is greater than its end.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(log N + K) in the number of elements N, and the number of elements removed K.
+    /// -   Space: O(1).
+    ///
+    /// K memory deallocations occur.
+    pub fn remove_range<R>(&mut self, range: R, token: &mut GhostToken<'brand>)
+    where
+        R: RangeBounds<usize>,
+    {
+        let range = self.checked_range(range, token);
+
+        let mut after = self.split_off_joined(range.end, token);
+        let mut removed = self.split_off_joined(range.start, token);
+
+        removed.clear(token);
+
+        self.append_joined(&mut after, token);
+
+        self.assert_invariants(token);
+    }
+
+    /// Replaces the elements within the given range by the elements of `other`, leaving `other` empty. Returns the
+    /// elements formerly within the range.
+    ///
+    /// #   Panics
+    ///
+    /// Panics if the start of the range is greater than its end.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(log N) in the total number of elements.
+    /// -   Space: O(1).
+    ///
+    /// No memory allocation nor deallocation occurs.
+    pub fn replace_range<R>(&mut self, range: R, other: &mut Self, token: &mut GhostToken<'brand>) -> Self
+    where
+        R: RangeBounds<usize>,
+    {
+        let range = self.checked_range(range, token);
+
+        let mut after = self.split_off_joined(range.end, token);
+        let removed = self.split_off_joined(range.start, token);
+
+        self.append_joined(other, token);
+        self.append_joined(&mut after, token);
+
+        self.assert_invariants(token);
+
+        removed
+    }
+
+    /// Replaces the elements within the given range by the elements of `iter`, in order, like `Vec::splice` does.
+    /// Returns the elements formerly within the range.
+    ///
+    /// #   Panics
+    ///
+    /// Panics if the start of the range is greater than its end.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(log N + K) in the total number of elements N, and the number of elements inserted K.
+    /// -   Space: O(1).
+    ///
+    /// K memory allocations occur.
+    pub fn splice<R, I>(&mut self, range: R, iter: I, token: &mut GhostToken<'brand>) -> Self
+    where
+        R: RangeBounds<usize>,
+        I: IntoIterator<Item = T>,
+    {
+        let range = self.checked_range(range, token);
+
+        let mut values = iter.into_iter();
+        let mut other = Self::from_iter_vine(token, |_| values.next());
+
+        self.replace_range(range, &mut other, token)
+    }
+
+    /// Checks the structural invariants of the tree, returning the first violation found, if any.
+    ///
+    /// The following invariants are checked, for each node:
+    ///
+    /// -   The size of the node is 1 + the sizes of its left and right sub-trees.
+    /// -   The rank of the node is the rank computed by the balancing policy.
+    /// -   The node is balanced, according to the balancing policy.
+    /// -   The up pointer of each child points back to the node, and the root has no up pointer.
+    /// -   The tripod of the node is retracted.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(N) in the number of elements.
+    /// -   Space: O(1).
+    pub fn check_invariants(&self, token: &GhostToken<'brand>) -> Result<(), InvariantError> {
+        self.check_invariants_impl(true, token)
+    }
+
+    //  Internal; checks the structural invariants of the tree, including its balance if `check_balance` is true.
+    fn check_invariants_impl(&self, check_balance: bool, token: &GhostToken<'brand>) -> Result<(), InvariantError> {
+        let mut node: &GhostNode<'brand, T> = if let Some(root) = self.root.as_ref() {
+            root
+        } else {
+            return Ok(());
+        };
+
+        //  The index of the front element of the sub-tree rooted at `node`.
+        let mut start = 0;
+
+        if node.borrow(token).up.is_some() {
+            return Err(InvariantError::Link { index: node.borrow(token).index(token) });
+        }
+
+        //  O(N) iterations, each visiting a node, or climbing back up from a node, in pre-order.
+        loop {
+            Self::check_node(node, start, check_balance, token)?;
+
+            let current = node.borrow(token);
+
+            if let Some(left) = current.left() {
+                node = left;
+                continue;
+            }
+
+            if let Some(right) = current.right() {
+                start += 1;
+                node = right;
+                continue;
+            }
+
+            //  Leaf, climb until there is an unvisited right sub-tree.
+            loop {
+                let current = node.borrow(token);
+
+                let parent = if let Some(parent) = current.up() {
+                    parent
+                } else {
+                    return Ok(());
+                };
+
+                let parent_node = parent.borrow(token);
+
+                match current.is_child_of(parent_node) {
+                    Some(Side::Left) => {
+                        if let Some(right) = parent_node.right() {
+                            start = start.saturating_add(current.size).saturating_add(1);
+                            node = right;
+                            break;
+                        }
+                    },
+                    Some(Side::Right) => {
+                        start = start.saturating_sub(parent_node.left_size(token)).saturating_sub(1);
+                    },
+                    None => return Err(InvariantError::Link { index: start.saturating_add(current.index(token)) }),
+                }
+
+                node = parent;
+            }
+        }
+    }
+
+    //  Internal; checks the invariants of a single node, whose sub-tree starts at index `start`.
+    fn check_node(node: &GhostNode<'brand, T>, start: usize, check_balance: bool, token: &GhostToken<'brand>) -> Result<(), InvariantError> {
+        let current = node.borrow(token);
+
+        let left = current.left_size(token);
+        let right = current.right_size(token);
+        let index = start.saturating_add(left);
+
+        if current.left.is_none() || current.right.is_none() {
+            return Err(InvariantError::Link { index });
+        }
+
+        for child in current.left().into_iter().chain(current.right()) {
+            if !current.is_aliased(child.borrow(token).up()) {
+                return Err(InvariantError::Link { index });
+            }
+        }
+
+        let tripod = current.tripod.take();
+        let retracted = current.is_aliased(tripod.as_deref());
+        current.tripod.set(tripod);
+
+        if !retracted {
+            return Err(InvariantError::Tripod { index });
+        }
+
+        let actual = left.saturating_add(right).saturating_add(1);
+
+        if current.size != actual {
+            return Err(InvariantError::Size { index, expected: current.size, actual });
+        }
+
+        let summary = current.summary();
+        let left_summary = current.child_summary(Side::Left, token);
+        let right_summary = current.child_summary(Side::Right, token);
+
+        let rank = P::rank(&summary, left_summary.as_ref(), right_summary.as_ref());
+
+        if current.rank != rank {
+            return Err(InvariantError::Rank { index, expected: current.rank, actual: rank });
+        }
+
+        if check_balance && P::rotation(&summary, left_summary.as_ref(), right_summary.as_ref()).is_some() {
+            return Err(InvariantError::Balance { index, left, right });
+        }
+
+        Ok(())
+    }
+
+    //  Internal; asserts that the invariants hold, when the `paranoid` feature is enabled.
+    #[cfg(feature = "paranoid")]
+    fn assert_invariants(&self, token: &GhostToken<'brand>) { self.assert_invariants_impl(true, token) }
+
+    //  Internal (dummy)
+    #[cfg(not(feature = "paranoid"))]
+    fn assert_invariants(&self, _: &GhostToken<'brand>) {}
+
+    //  Internal; asserts that the invariants, bar balance, hold, when the `paranoid` feature is enabled.
+    #[cfg(feature = "paranoid")]
+    fn assert_structure(&self, token: &GhostToken<'brand>) { self.assert_invariants_impl(false, token) }
+
+    //  Internal (dummy)
+    #[cfg(not(feature = "paranoid"))]
+    fn assert_structure(&self, _: &GhostToken<'brand>) {}
+
+    //  Internal; asserts that the invariants hold, including balance if `check_balance` is true.
+    #[cfg(feature = "paranoid")]
+    fn assert_invariants_impl(&self, check_balance: bool, token: &GhostToken<'brand>) {
+        if let Err(error) = self.check_invariants_impl(check_balance, token) {
+            panic!("TripodTree invariant violated: {}", error);
+        }
+    }
+
     //  Internal; constructs a Range<usize> suitable for the tree.
     fn into_range<R>(&self, range: R, token: &GhostToken<'brand>) -> Range<usize>
     where
@@ -371,11 +753,38 @@ impl<'brand, T> TripodTree<'brand, T> {
         start..end
     }
 
+    //  Internal; constructs a Range<usize> suitable for the tree, from a range whose start must not exceed its end.
+    fn checked_range<R>(&self, range: R, token: &GhostToken<'brand>) -> Range<usize>
+    where
+        R: RangeBounds<usize>,
+    {
+        let start = match range.start_bound() {
+            Bound::Included(n) => *n,
+            Bound::Excluded(n) => n.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+
+        let end = match range.end_bound() {
+            Bound::Included(n) => n.saturating_add(1),
+            Bound::Excluded(n) => *n,
+            Bound::Unbounded => usize::MAX,
+        };
+
+        assert!(start <= end, "start ({}) > end ({})", start, end);
+
+        self.into_range(range, token)
+    }
+
     //  Internal; constructs a QuarterNodePtr from a value.
     fn from_value(value: T, token: &mut GhostToken<'brand>) -> QuarterNodePtr<'brand, T> {
         let tripod = Cell::new(None);
-        let node = FullNodePtr::new(GhostCell::new(Node { size: 1, value, up: None, left: None, right: None, tripod, }));
+        let node = FullNodePtr::new(GhostCell::new(Node { size: 1, rank: 0, value, up: None, left: None, right: None, tripod, }));
+
+        Self::from_full(node, token)
+    }
 
+    //  Internal; constructs a QuarterNodePtr from a FullNodePtr, whose pointers are all None.
+    fn from_full(node: FullNodePtr<'brand, T>, token: &mut GhostToken<'brand>) -> QuarterNodePtr<'brand, T> {
         let halves = FullNodePtr::split::<2, 2>(node);
         let (up, tripod) = HalfNodePtr::split::<1, 1>(halves.0);
         let (left, right) = HalfNodePtr::split::<1, 1>(halves.1);
@@ -384,9 +793,29 @@ impl<'brand, T> TripodTree<'brand, T> {
         up.borrow_mut(token).left = Some(left);
         up.borrow_mut(token).right = Some(right);
 
+        let seed = Summary { size: 1, rank: balance::seed_rank(&*up as *const GhostNode<'brand, T> as usize) };
+        up.borrow_mut(token).rank = P::rank(&seed, None, None);
+
         up
     }
 
+    //  Internal; recomputes the size and rank of the node from those of its children.
+    fn adjust_node(node: &GhostNode<'brand, T>, token: &mut GhostToken<'brand>) {
+        let (size, rank) = {
+            let current = node.borrow(token);
+            let left = current.child_summary(Side::Left, token);
+            let right = current.child_summary(Side::Right, token);
+
+            let size = 1 + current.left_size(token) + current.right_size(token);
+
+            (size, P::rank(&current.summary(), left.as_ref(), right.as_ref()))
+        };
+
+        let current = node.borrow_mut(token);
+        current.size = size;
+        current.rank = rank;
+    }
+
     //  Internal; construct a Tree from QuarterNodePtr.
     fn from_quarter(node: QuarterNodePtr<'brand, T>, token: &GhostToken<'brand>) -> Self {
         let _node = node.borrow(token);
@@ -394,7 +823,7 @@ impl<'brand, T> TripodTree<'brand, T> {
         debug_assert!(_node.is_aliased(_node.left.as_ref().map(|node| &**node)));
         debug_assert!(_node.is_aliased(_node.right.as_ref().map(|node| &**node)));
 
-        Self { root: Some(node), }
+        Self { root: Some(node), policy: PhantomData, }
     }
 
     //  Internal;  returns the value contained within.
@@ -432,7 +861,7 @@ impl<'brand, T> TripodTree<'brand, T> {
 }
 
 #[cfg(feature = "experimental-ghost-cursor")]
-impl<'brand, T> TripodTree<'brand, T> {
+impl<'brand, T, P: BalancePolicy> TripodTree<'brand, T, P> {
     /// Returns a mutable reference to the front element, if any.
     ///
     /// #   Complexity
@@ -498,7 +927,317 @@ impl<'brand, T> TripodTree<'brand, T> {
 
 }
 
-impl<'brand, T> Default for TripodTree<'brand, T> {
+impl<'brand, T: Clone, P: BalancePolicy> TripodTree<'brand, T, P> {
+    /// Creates a copy of the tree, with the exact same shape.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(N) in the number of elements.
+    /// -   Space: O(1).
+    ///
+    /// Exactly N memory allocations occur, and no rebalancing.
+    pub fn clone_with(&self, token: &mut GhostToken<'brand>) -> Self {
+        let root = self.root.as_ref().map(|root| {
+            let tripod = root.borrow(token).deploy();
+            let clone = Self::clone_node(&tripod, token);
+            retract(tripod, token);
+
+            clone
+        });
+
+        Self { root, policy: PhantomData, }
+    }
+
+    /// Turns self into a copy of `source`, with the exact same shape, reusing the nodes of self where possible.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(N + M) in the number of elements of `source` and self, respectively.
+    /// -   Space: O(1).
+    ///
+    /// At most N memory allocations occur, and no rebalancing.
+    pub fn clone_from_with(&mut self, source: &Self, token: &mut GhostToken<'brand>) {
+        match (self.root.as_ref(), source.root.as_ref()) {
+            (Some(target), Some(source)) => {
+                let target_tripod = target.borrow(token).deploy();
+                let source_tripod = source.borrow(token).deploy();
+
+                Self::clone_from_node(&target_tripod, &source_tripod, token);
+
+                retract(source_tripod, token);
+                retract(target_tripod, token);
+            },
+            (None, Some(_)) => *self = source.clone_with(token),
+            (Some(_), None) => self.clear(token),
+            (None, None) => (),
+        }
+
+        self.assert_invariants(token);
+    }
+
+    //  Internal; clones the sub-tree rooted at the node, whose tripod is passed.
+    //
+    //  The source and the clone are walked in lockstep, in pre-order, climbing back up through the up pointers rather
+    //  than recursing, whatever the depth of the sub-tree.
+    //
+    //  Complexity: Time O(N), Space O(1).
+    fn clone_node(source: &QuarterNodePtr<'brand, T>, token: &mut GhostToken<'brand>) -> QuarterNodePtr<'brand, T> {
+        let root = Self::clone_value(source, token);
+
+        //  The tripods of the current source and clone nodes, or None when at the root of the sub-tree.
+        let mut current: Option<(QuarterNodePtr<'brand, T>, QuarterNodePtr<'brand, T>)> = None;
+        let mut depth = 0;
+
+        //  O(N) iterations, each descending to a child, or climbing back up from a node.
+        loop {
+            let (source_node, clone_node) = match current.as_ref() {
+                Some((source_node, clone_node)) => (source_node, clone_node),
+                None => (source, &root),
+            };
+
+            //  Descend into the first child not cloned yet, if any.
+            let side = [Side::Left, Side::Right].iter().copied().find(|side| {
+                source_node.borrow(token).child(*side).is_some() && clone_node.borrow(token).child(*side).is_none()
+            });
+
+            if let Some(side) = side {
+                let source_child = source_node.borrow(token).child(side).map(|child| child.borrow(token).deploy()).expect("Child!");
+
+                let child = Self::clone_value(&source_child, token);
+                Self::attach_child(clone_node, side, child, token);
+
+                let clone_child = clone_node.borrow(token).child(side).map(|child| child.borrow(token).deploy()).expect("Child!");
+
+                if let Some((source_tripod, clone_tripod)) = current.replace((source_child, clone_child)) {
+                    retract(source_tripod, token);
+                    retract(clone_tripod, token);
+                }
+
+                depth += 1;
+                continue;
+            }
+
+            //  Both children cloned, climb back up, unless at the root of the sub-tree.
+            let (source_tripod, clone_tripod) = if let Some(tripods) = current.take() { tripods } else { return root };
+
+            depth -= 1;
+
+            if depth > 0 {
+                let source_up = source_tripod.borrow(token).up().map(|up| up.borrow(token).deploy()).expect("Parent!");
+                let clone_up = clone_tripod.borrow(token).up().map(|up| up.borrow(token).deploy()).expect("Parent!");
+
+                current = Some((source_up, clone_up));
+            }
+
+            retract(source_tripod, token);
+            retract(clone_tripod, token);
+        }
+    }
+
+    //  Internal; turns the sub-tree rooted at target into a clone of the sub-tree rooted at source, whose tripods are
+    //  passed.
+    //
+    //  The target and the source are walked in lockstep, in pre-order, climbing back up through the up pointers rather
+    //  than recursing, whatever the depth of the sub-trees.
+    //
+    //  Complexity: Time O(N + M), Space O(1).
+    fn clone_from_node(target: &QuarterNodePtr<'brand, T>, source: &QuarterNodePtr<'brand, T>, token: &mut GhostToken<'brand>) {
+        Self::clone_value_into(target, source, token);
+
+        //  The tripods of the current target and source nodes, or None when at the root of the sub-trees.
+        let mut current: Option<(QuarterNodePtr<'brand, T>, QuarterNodePtr<'brand, T>)> = None;
+        let mut depth = 0;
+
+        //  The side climbed back from, if any, in which case the children up to this side are already cloned.
+        let mut from: Option<Side> = None;
+
+        //  O(N + M) iterations, each descending to a child, or climbing back up from a node.
+        loop {
+            let (target_node, source_node) = match current.as_ref() {
+                Some((target_node, source_node)) => (target_node, source_node),
+                None => (target, source),
+            };
+
+            let sides: &[Side] = match from {
+                None => &[Side::Left, Side::Right],
+                Some(Side::Left) => &[Side::Right],
+                Some(Side::Right) => &[],
+            };
+
+            let mut descend = None;
+
+            for side in sides.iter().copied() {
+                let has_target_child = target_node.borrow(token).child(side).is_some();
+                let has_source_child = source_node.borrow(token).child(side).is_some();
+
+                match (has_target_child, has_source_child) {
+                    (true, true) => {
+                        descend = Some(side);
+                        break;
+                    },
+                    (false, true) => {
+                        let source_child = source_node.borrow(token).child(side).map(|child| child.borrow(token).deploy()).expect("Child!");
+                        let child = Self::clone_node(&source_child, token);
+                        retract(source_child, token);
+
+                        Self::attach_child(target_node, side, child, token);
+                    },
+                    (true, false) => {
+                        let child = target_node.borrow_mut(token).take_child(side).expect("Child!");
+                        let node_from_child = child.borrow_mut(token).up.take().expect("Parent!");
+                        target_node.borrow_mut(token).set_child(side, node_from_child);
+
+                        Self { root: Some(child), policy: PhantomData, }.clear(token);
+                    },
+                    (false, false) => (),
+                }
+            }
+
+            if let Some(side) = descend {
+                let target_child = target_node.borrow(token).child(side).map(|child| child.borrow(token).deploy()).expect("Child!");
+                let source_child = source_node.borrow(token).child(side).map(|child| child.borrow(token).deploy()).expect("Child!");
+
+                Self::clone_value_into(&target_child, &source_child, token);
+
+                if let Some((target_tripod, source_tripod)) = current.replace((target_child, source_child)) {
+                    retract(source_tripod, token);
+                    retract(target_tripod, token);
+                }
+
+                depth += 1;
+                from = None;
+                continue;
+            }
+
+            //  Both children cloned, climb back up, unless at the root of the sub-trees.
+            let (target_tripod, source_tripod) = if let Some(tripods) = current.take() { tripods } else { return };
+
+            from = source_tripod.borrow(token).is_child(token);
+            depth -= 1;
+
+            if depth > 0 {
+                let target_up = target_tripod.borrow(token).up().map(|up| up.borrow(token).deploy()).expect("Parent!");
+                let source_up = source_tripod.borrow(token).up().map(|up| up.borrow(token).deploy()).expect("Parent!");
+
+                current = Some((target_up, source_up));
+            }
+
+            retract(source_tripod, token);
+            retract(target_tripod, token);
+        }
+    }
+
+    //  Internal; creates a new node, with a clone of the value, size, and rank of the source node.
+    fn clone_value(source: &GhostNode<'brand, T>, token: &mut GhostToken<'brand>) -> QuarterNodePtr<'brand, T> {
+        let (value, size, rank) = {
+            let source = source.borrow(token);
+            (source.value.clone(), source.size, source.rank)
+        };
+
+        let node = Self::from_value(value, token);
+        node.borrow_mut(token).size = size;
+        node.borrow_mut(token).rank = rank;
+
+        node
+    }
+
+    //  Internal; overwrites the value, size, and rank of the target node with those of the source node.
+    fn clone_value_into(target: &GhostNode<'brand, T>, source: &GhostNode<'brand, T>, token: &mut GhostToken<'brand>) {
+        let (value, size, rank) = {
+            let source = source.borrow(token);
+            (source.value.clone(), source.size, source.rank)
+        };
+
+        let target = target.borrow_mut(token);
+        target.value = value;
+        target.size = size;
+        target.rank = rank;
+    }
+
+    //  Internal; attaches the child, a root, on the side of the node, which must not have any child on that side.
+    fn attach_child(node: &QuarterNodePtr<'brand, T>, side: Side, child: QuarterNodePtr<'brand, T>, token: &mut GhostToken<'brand>) {
+        let node_from_child = node.borrow_mut(token).child_mut(side).take();
+        child.borrow_mut(token).up = node_from_child;
+        node.borrow_mut(token).set_child(side, child);
+    }
+}
+
+impl<'brand, T, P: BalancePolicy> TripodTree<'brand, T, P> {
+    //  Internal; pushes the value at the back of the tree, as the new root, the former root becoming its left child.
+    //
+    //  The tree is left unbalanced, as a left "vine", until `balance_vine` is called.
+    //
+    //  Complexity: Time O(1), Space O(1).
+    pub(crate) fn push_vine(&mut self, value: T, token: &mut GhostToken<'brand>) {
+        let node = Self::from_value(value, token);
+
+        if let Some(root) = self.root.take() {
+            let node_from_left = node.borrow_mut(token).left.take().expect("Left child - pointing to self");
+
+            root.borrow_mut(token).up = Some(node_from_left);
+
+            node.borrow_mut(token).left = Some(root);
+            Self::adjust_node(&node, token);
+        }
+
+        self.root = Some(node);
+    }
+
+    //  Internal; balances the left "vine" built by `push_vine` into a complete tree.
+    //
+    //  Complexity: Time O(N), expected for a `Treap`, Space O(1).
+    pub(crate) fn balance_vine(&mut self, token: &mut GhostToken<'brand>) {
+        self.cursor_mut(token).balance_vine();
+    }
+
+    //  Internal; splits the tree into two at the given index, keeping everything before the index, and returning
+    //  everything after, including the index.
+    //
+    //  Unlike `split_off`, which rotates the split point up to the root, the tree is split by joins.
+    //
+    //  Requirement: `at` must be at most the length of the tree.
+    //
+    //  Complexity: Time O(log N), Space O(1).
+    fn split_off_joined(&mut self, at: usize, token: &mut GhostToken<'brand>) -> Self {
+        let mut result = Self::new();
+
+        if at == self.len(token) {
+            return result;
+        }
+
+        let (left, middle, right) = self.cursor_mut(token).split_around(at);
+
+        self.root = left;
+
+        result.cursor_mut(token).join(None, middle, right);
+
+        result
+    }
+
+    //  Internal; moves all the elements from `other` to the back of the tree, leaving `other` empty.
+    //
+    //  Unlike `append`, which rebalances the spliced tree from the point of attachment, the trees are joined through the
+    //  front element of `other`.
+    //
+    //  Complexity: Time O(log N), Space O(1).
+    fn append_joined(&mut self, other: &mut Self, token: &mut GhostToken<'brand>) {
+        if other.is_empty() {
+            return;
+        }
+
+        if self.is_empty() {
+            mem::swap(self, other);
+            return;
+        }
+
+        let (_, middle, right) = other.cursor_mut(token).split_around(0);
+        let left = self.root.take();
+
+        self.cursor_mut(token).join(left, middle, right);
+    }
+}
+
+impl<'brand, T, P: BalancePolicy> Default for TripodTree<'brand, T, P> {
     fn default() -> Self { Self::new() }
 }
 
@@ -521,6 +1260,17 @@ impl Side {
     }
 }
 
+/// The direction in which to pursue a descent, see `Cursor::seek_by`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Direction {
+    /// Descend into the left sub-tree, whose elements are "before" the current element.
+    Left,
+    /// Descend into the right sub-tree, whose elements are "after" the current element.
+    Right,
+    /// Stop the descent at the current element.
+    Stop,
+}
+
 //
 //  Implementation
 //
@@ -528,6 +1278,8 @@ impl Side {
 struct Node<'brand, T> {
     //  The size of the subtree rooted at this node.
     size: usize,
+    //  The rank of this node, as computed by the balancing policy.
+    rank: u64,
     value: T,
     up: Option<QuarterNodePtr<'brand, T>>,
     left: Option<QuarterNodePtr<'brand, T>>,
@@ -553,6 +1305,14 @@ impl<'brand, T> Node<'brand, T> {
         self.right().map(|node| node.borrow(token).size).unwrap_or(0)
     }
 
+    //  Internal; returns the summary of the sub-tree rooted at this node.
+    fn summary(&self) -> Summary { Summary { size: self.size, rank: self.rank } }
+
+    //  Internal; returns the summary of the appropriate child, if any.
+    fn child_summary(&self, side: Side, token: &GhostToken<'brand>) -> Option<Summary> {
+        self.child(side).map(|node| node.borrow(token).summary())
+    }
+
     //  Internal; returns a reference to the right node, if any.
     fn child_size(&self, side: Side, token: &GhostToken<'brand>) -> usize {
         //  In practice, the child is not, typically, empty, although this property can be violated during manipulations.
@@ -782,11 +1542,12 @@ fn tree_append() {
     with_tree_duo(ORIGINAL, SPLICE, |token, tree, splice| {
         tree.append(splice, token);
 
-        //         G
-        //     D       4
-        //   B   F   2   6
-        //  A C E - 1 3 5 7
-        assert_tree(&["G", "D", "4", "B", "F", "2", "6", "A", "C", "E", "-", "1", "3", "5", "7"], tree.cursor(token));
+        //                 2
+        //         D               4
+        //     B       F       3       6
+        //   A   C   E   G   -   -   5   7
+        //  - - - - - - - 1 - - - - - - - -
+        assert_tree(&["2", "D", "4", "B", "F", "3", "6", "A", "C", "E", "G", "-", "-", "5", "7", "-", "-", "-", "-", "-", "-", "-", "1"], tree.cursor(token));
         assert_tree(&[], splice.cursor(token));
     });
 }
@@ -799,11 +1560,12 @@ fn tree_prepend() {
     with_tree_duo(ORIGINAL, SPLICE, |token, tree, splice| {
         tree.prepend(splice, token);
 
-        //         A
-        //     4       D
-        //   2   6   B   F
-        //  1 3 5 7 - C E G
-        assert_tree(&["A", "4", "D", "2", "6", "B", "F", "1", "3", "5", "7", "-", "C", "E", "G"], tree.cursor(token));
+        //                 6
+        //         4               D
+        //     2       5       B       F
+        //   1   3   -   -   A   C   E   G
+        //  - - - - - - - - 7 - - - - - - -
+        assert_tree(&["6", "4", "D", "2", "5", "B", "F", "1", "3", "-", "-", "A", "C", "E", "G", "-", "-", "-", "-", "-", "-", "-", "-", "7"], tree.cursor(token));
         assert_tree(&[], splice.cursor(token));
     });
 }
@@ -855,11 +1617,11 @@ fn tree_split() {
 
         *split = tree.split(RANGE, token);
 
-        //         8
-        //     2       C
-        //   1   3   A   E
-        //  - - - 7 9 B D F
-        assert_tree(&["8", "2", "C", "1", "3", "A", "E", "-", "-", "-", "7", "9", "B", "D", "F"], tree.cursor(token));
+        //         A
+        //     3       C
+        //   2   8   B   E
+        //  1 - 7 9 - - D F
+        assert_tree(&["A", "3", "C", "2", "8", "B", "E", "1", "-", "7", "9", "-", "-", "D", "F"], tree.cursor(token));
         //   5
         //  4 6
         assert_tree(&["5", "4", "6"], split.cursor(token));
@@ -888,6 +1650,496 @@ fn tree_split() {
     });
 }
 
+#[test]
+fn tree_rotate() {
+    const ORIGINAL: &[&str] = &["4", "2", "6", "1", "3", "5", "7"];
+
+    for k in 0..=7 {
+        with_tree(ORIGINAL, |token, tree| {
+            tree.rotate_left(k, token);
+
+            assert_eq!(Ok(()), tree.check_invariants(token));
+
+            let elements: Vec<_> = tree.iter(token).cloned().collect();
+            let expected: Vec<_> = (0..7).map(|i| ((i + k) % 7 + 1).to_string()).collect();
+            assert_eq!(expected, elements);
+
+            tree.rotate_right(k, token);
+
+            assert_eq!(Ok(()), tree.check_invariants(token));
+
+            let elements: Vec<_> = tree.iter(token).map(String::as_str).collect();
+            assert_eq!(&["1", "2", "3", "4", "5", "6", "7"][..], &elements[..]);
+        });
+    }
+}
+
+#[test]
+#[should_panic]
+fn tree_rotate_out_of_bounds() {
+    with_tree(&["1"], |token, tree| {
+        tree.rotate_left(2, token);
+    });
+}
+
+#[test]
+fn tree_remove_range() {
+    const ORIGINAL: &[&str] = &["8", "4", "C", "2", "6", "A", "E", "1", "3", "5", "7", "9", "B", "D", "F"];
+
+    with_tree(ORIGINAL, |token, tree| {
+        tree.remove_range(3..11, token);
+
+        assert_eq!(Ok(()), tree.check_invariants(token));
+
+        let elements: Vec<_> = tree.iter(token).map(String::as_str).collect();
+        assert_eq!(&["1", "2", "3", "C", "D", "E", "F"][..], &elements[..]);
+
+        tree.remove_range(..2, token);
+        tree.remove_range(3.., token);
+
+        let elements: Vec<_> = tree.iter(token).map(String::as_str).collect();
+        assert_eq!(&["3", "C", "D"][..], &elements[..]);
+
+        tree.remove_range(.., token);
+
+        assert!(tree.is_empty());
+    });
+}
+
+#[test]
+fn tree_remove_range_reversed() {
+    with_tree(&["4", "2", "6", "1", "3", "5", "7"], |token, tree| {
+        let range = (Bound::Included(5), Bound::Excluded(3));
+        let result = panic::catch_unwind(AssertUnwindSafe(|| tree.remove_range(range, token)));
+
+        let error = result.expect_err("Panic");
+        assert_eq!(Some("start (5) > end (3)"), error.downcast_ref::<String>().map(String::as_str));
+        assert_eq!(7, tree.len(token));
+    });
+}
+
+#[test]
+fn tree_replace_range() {
+    const ORIGINAL: &[&str] = &["8", "4", "C", "2", "6", "A", "E", "1", "3", "5", "7", "9", "B", "D", "F"];
+    const REPLACEMENT: &[&str] = &["y", "x", "z"];
+
+    with_tree_duo(ORIGINAL, REPLACEMENT, |token, tree, other| {
+        let mut removed = tree.replace_range(2..12, other, token);
+
+        assert!(other.is_empty());
+        assert_eq!(Ok(()), tree.check_invariants(token));
+        assert_eq!(Ok(()), removed.check_invariants(token));
+
+        let elements: Vec<_> = tree.iter(token).map(String::as_str).collect();
+        assert_eq!(&["1", "2", "x", "y", "z", "D", "E", "F"][..], &elements[..]);
+
+        let elements: Vec<_> = removed.iter(token).map(String::as_str).collect();
+        assert_eq!(&["3", "4", "5", "6", "7", "8", "9", "A", "B", "C"][..], &elements[..]);
+
+        //  Replacing an empty range inserts.
+        let mut empty = tree.replace_range(8..8, &mut removed, token);
+
+        assert!(empty.is_empty());
+        assert_eq!(18, tree.len(token));
+        assert_eq!(Some("C"), tree.back(token).map(String::as_str));
+
+        empty.clear(token);
+    });
+}
+
+#[test]
+fn tree_replace_range_reversed() {
+    with_tree_duo(&["4", "2", "6", "1", "3", "5", "7"], &["x"], |token, tree, other| {
+        let range = (Bound::Included(5), Bound::Included(2));
+        let result = panic::catch_unwind(AssertUnwindSafe(|| tree.replace_range(range, other, token)));
+
+        let error = result.err().expect("Panic");
+        assert_eq!(Some("start (5) > end (3)"), error.downcast_ref::<String>().map(String::as_str));
+        assert_eq!(7, tree.len(token));
+        assert_eq!(1, other.len(token));
+    });
+}
+
+#[test]
+fn tree_splice() {
+    const ORIGINAL: &[&str] = &["4", "2", "6", "1", "3", "5", "7"];
+
+    with_tree(ORIGINAL, |token, tree| {
+        let mut removed = tree.splice(1..6, ["a", "b"].iter().map(|s| s.to_string()), token);
+
+        assert_eq!(Ok(()), tree.check_invariants(token));
+
+        let elements: Vec<_> = tree.iter(token).map(String::as_str).collect();
+        assert_eq!(&["1", "a", "b", "7"][..], &elements[..]);
+
+        let elements: Vec<_> = removed.iter(token).map(String::as_str).collect();
+        assert_eq!(&["2", "3", "4", "5", "6"][..], &elements[..]);
+
+        removed.clear(token);
+
+        let mut removed = tree.splice(4.., (0..20).map(|i| i.to_string()), token);
+
+        assert!(removed.is_empty());
+        assert_eq!(Ok(()), tree.check_invariants(token));
+        assert_eq!(24, tree.len(token));
+        assert_eq!(Some("19"), tree.back(token).map(String::as_str));
+
+        removed.clear(token);
+    });
+}
+
+#[test]
+fn tree_splice_reversed() {
+    with_tree(&["4", "2", "6", "1", "3", "5", "7"], |token, tree| {
+        let range = (Bound::Excluded(4), Bound::Excluded(3));
+        let result = panic::catch_unwind(AssertUnwindSafe(|| tree.splice(range, None, token)));
+
+        let error = result.err().expect("Panic");
+        assert_eq!(Some("start (5) > end (3)"), error.downcast_ref::<String>().map(String::as_str));
+        assert_eq!(7, tree.len(token));
+    });
+}
+
+#[test]
+fn tree_clone_with() {
+    const SHAPES: &[&[&str]] = &[
+        &[],
+        &["Root"],
+        &["Root", "L", "R", "LL", "-", "-", "RR"],
+        &["8", "4", "C", "2", "6", "A", "E", "1", "3", "5", "7", "9", "B", "D", "F"],
+    ];
+
+    for shape in SHAPES {
+        with_tree_duo(shape, &[], |token, tree, clone| {
+            *clone = tree.clone_with(token);
+
+            assert_tree(shape, clone.cursor(token));
+            assert_tree(shape, tree.cursor(token));
+            assert_eq!(Ok(()), clone.check_invariants(token));
+        });
+    }
+}
+
+#[test]
+fn tree_clone_from_with() {
+    const SHAPES: &[&[&str]] = &[
+        &[],
+        &["Root"],
+        &["Root", "L", "R", "LL", "-", "-", "RR"],
+        &["Root", "L", "R", "-", "LR", "RL"],
+        &["8", "4", "C", "2", "6", "A", "E", "1", "3", "5", "7", "9", "B", "D", "F"],
+    ];
+
+    for target in SHAPES {
+        for source in SHAPES {
+            with_tree_duo(target, source, |token, target, source_tree| {
+                target.clone_from_with(source_tree, token);
+
+                assert_tree(source, target.cursor(token));
+                assert_tree(source, source_tree.cursor(token));
+                assert_eq!(Ok(()), target.check_invariants(token));
+            });
+        }
+    }
+}
+
+#[test]
+fn tree_clone_deep() {
+    const DEPTH: usize = 1_000_000;
+
+    GhostToken::new(|mut token| {
+        //  A left "vine", as deep as it is long.
+        let mut tree: TripodTree<'_, usize, Unbalanced> = TripodTree::new();
+        let mut target: TripodTree<'_, usize, Unbalanced> = TripodTree::new();
+
+        for i in 0..DEPTH {
+            tree.push_vine(i, &mut token);
+        }
+
+        for i in 0..DEPTH / 2 {
+            target.push_vine(DEPTH + i, &mut token);
+        }
+
+        let mut clone = tree.clone_with(&mut token);
+
+        assert_eq!(Ok(()), clone.check_invariants(&token));
+        assert_eq!(DEPTH, clone.len(&token));
+        assert!(clone.iter(&token).copied().eq(0..DEPTH));
+
+        target.clone_from_with(&tree, &mut token);
+
+        assert_eq!(Ok(()), target.check_invariants(&token));
+        assert_eq!(DEPTH, target.len(&token));
+        assert!(target.iter(&token).copied().eq(0..DEPTH));
+
+        tree.clear(&mut token);
+        clone.clear(&mut token);
+        target.clear(&mut token);
+    });
+}
+
+#[test]
+fn tree_check_invariants() {
+    with_tree(&[][..], |token, tree| {
+        assert_eq!(Ok(()), tree.check_invariants(token));
+    });
+
+    with_tree(&["D", "B", "F", "A", "C", "E", "G"], |token, tree| {
+        assert_eq!(Ok(()), tree.check_invariants(token));
+    });
+}
+
+#[test]
+fn tree_check_invariants_size() {
+    with_tree(&["D", "B", "F", "A", "C", "E", "G"], |token, tree| {
+        let root = tree.root.as_ref().expect("Non-empty");
+        root.borrow_mut(token).size += 1;
+
+        assert_eq!(Err(InvariantError::Size { index: 3, expected: 8, actual: 7 }), tree.check_invariants(token));
+
+        let root = tree.root.as_ref().expect("Non-empty");
+        root.borrow_mut(token).size -= 1;
+    });
+}
+
+#[test]
+fn tree_check_invariants_balance() {
+    with_tree(&["A", "B", "-", "C"], |token, tree| {
+        assert_eq!(Err(InvariantError::Balance { index: 2, left: 2, right: 0 }), tree.check_invariants(token));
+    });
+}
+
+#[test]
+fn tree_balance_policies() {
+    fn check<P: BalancePolicy>() {
+        GhostToken::new(|mut token| {
+            let mut tree = TripodTree::<'_, usize, P>::new();
+
+            for i in 0..64 {
+                tree.push_back(2 * i, &mut token);
+                assert_eq!(Ok(()), tree.check_invariants(&token), "push_back {}", i);
+            }
+
+            for i in 0..64 {
+                let mut cursor = tree.cursor_mut(&mut token);
+                cursor.move_to(2 * i + 1);
+                cursor.insert_before(2 * i + 1);
+
+                drop(cursor);
+                assert_eq!(Ok(()), tree.check_invariants(&token), "insert_before {}", i);
+            }
+
+            assert!(tree.iter(&token).copied().eq(0..128));
+
+            for i in 0..64 {
+                let mut cursor = tree.cursor_mut(&mut token);
+                cursor.move_to(i);
+                assert_eq!(Some(2 * i), cursor.remove_current());
+
+                drop(cursor);
+                assert_eq!(Ok(()), tree.check_invariants(&token), "remove_current {}", i);
+            }
+
+            assert!(tree.iter(&token).copied().eq((0..64).map(|i| 2 * i + 1)));
+
+            let mut clone = tree.clone_with(&mut token);
+            assert_eq!(Ok(()), clone.check_invariants(&token), "clone_with");
+
+            let mut converted = TripodTree::<'_, usize, P>::from_vec((0..100).collect(), &mut token);
+            assert_eq!(Ok(()), converted.check_invariants(&token), "from_vec");
+
+            let mut split = converted.split_off(37, &mut token);
+            assert_eq!(Ok(()), converted.check_invariants(&token), "split_off");
+            assert_eq!(Ok(()), split.check_invariants(&token), "split_off");
+
+            split.append(&mut clone, &mut token);
+            assert_eq!(Ok(()), split.check_invariants(&token), "append");
+
+            converted.prepend(&mut split, &mut token);
+            assert_eq!(Ok(()), converted.check_invariants(&token), "prepend");
+            assert_eq!(164, converted.len(&token));
+
+            {
+                let mut cursor = converted.cursor_mut(&mut token);
+                cursor.swap_with_child(Side::Left);
+            }
+
+            assert_eq!(Ok(()), converted.check_invariants(&token), "swap_with_child");
+
+            {
+                let mut cursor = converted.cursor_mut(&mut token);
+                cursor.rotate(Side::Right);
+
+                //  Demoted element.
+                cursor.move_left();
+                cursor.rebalance();
+            }
+
+            assert_eq!(Ok(()), converted.check_invariants(&token), "rotate");
+
+            {
+                let mut cursor = converted.cursor_mut(&mut token);
+
+                let detached = cursor.detach_child(Side::Left);
+                cursor.rebalance();
+
+                cursor.move_to(0);
+                cursor.attach_child(Side::Left, detached);
+                cursor.rebalance();
+            }
+
+            assert_eq!(Ok(()), converted.check_invariants(&token), "detach_child and attach_child");
+            assert_eq!(164, converted.len(&token));
+
+            converted.clear(&mut token);
+            tree.clear(&mut token);
+        });
+    }
+
+    check::<WeightBalanced>();
+    check::<Avl>();
+    check::<RedBlack>();
+    check::<Treap>();
+    check::<Unbalanced>();
+}
+
+#[test]
+fn tree_balance_policies_range_edits() {
+    fn check<P: BalancePolicy>() {
+        GhostToken::new(|mut token| {
+            const LENGTH: u32 = 24;
+
+            for start in 0..=LENGTH {
+                for end in start..=LENGTH {
+                    let range = (start as usize)..(end as usize);
+                    let expected = (0..start).chain(end..LENGTH);
+
+                    let mut tree = TripodTree::<'_, u32, P>::from_vec((0..LENGTH).collect(), &mut token);
+                    tree.remove_range(range.clone(), &mut token);
+
+                    assert_eq!(Ok(()), tree.check_invariants(&token), "remove_range {:?}", range);
+                    assert!(tree.iter(&token).copied().eq(expected.clone()), "remove_range {:?}", range);
+
+                    let mut other = TripodTree::<'_, u32, P>::from_vec((start..end).collect(), &mut token);
+                    let removed = tree.replace_range(start as usize..start as usize, &mut other, &mut token);
+
+                    assert!(removed.is_empty());
+                    assert_eq!(Ok(()), tree.check_invariants(&token), "replace_range {:?}", range);
+                    assert!(tree.iter(&token).copied().eq(0..LENGTH), "replace_range {:?}", range);
+
+                    let mut removed = tree.splice(range.clone(), 100..103, &mut token);
+
+                    assert_eq!(Ok(()), tree.check_invariants(&token), "splice {:?}", range);
+                    assert_eq!(Ok(()), removed.check_invariants(&token), "splice {:?}", range);
+                    assert!(tree.iter(&token).copied().eq((0..start).chain(100..103).chain(end..LENGTH)), "splice {:?}", range);
+                    assert!(removed.iter(&token).copied().eq(start..end), "splice {:?}", range);
+
+                    removed.clear(&mut token);
+                    tree.clear(&mut token);
+                }
+            }
+        });
+    }
+
+    check::<WeightBalanced>();
+    check::<Avl>();
+    check::<RedBlack>();
+    check::<Treap>();
+    check::<Unbalanced>();
+}
+
+#[test]
+fn tree_balance_policies_rotate() {
+    fn check<P: BalancePolicy>() {
+        GhostToken::new(|mut token| {
+            const LENGTH: u32 = 40;
+
+            let mut tree = TripodTree::<'_, u32, P>::from_vec((0..LENGTH).collect(), &mut token);
+
+            for k in 0..=LENGTH {
+                tree.rotate_left(k as usize, &mut token);
+
+                assert_eq!(Ok(()), tree.check_invariants(&token), "rotate_left {}", k);
+                assert!(tree.iter(&token).copied().eq((k..LENGTH).chain(0..k)), "rotate_left {}", k);
+
+                tree.rotate_right(k as usize, &mut token);
+
+                assert_eq!(Ok(()), tree.check_invariants(&token), "rotate_right {}", k);
+                assert!(tree.iter(&token).copied().eq(0..LENGTH), "rotate_right {}", k);
+            }
+
+            tree.clear(&mut token);
+        });
+    }
+
+    check::<WeightBalanced>();
+    check::<Avl>();
+    check::<RedBlack>();
+    check::<Treap>();
+    check::<Unbalanced>();
+}
+
+#[test]
+fn tree_balance_policies_structural_edits() {
+    fn check<P: BalancePolicy>() {
+        GhostToken::new(|mut token| {
+            let mut tree = TripodTree::<'_, u32, P>::from_vec((0..31).collect(), &mut token);
+
+            for at in 0..31 {
+                for &side in &[Side::Left, Side::Right] {
+                    {
+                        let mut cursor = tree.cursor_mut(&mut token);
+                        cursor.move_to(at);
+                        cursor.swap_with_child(side);
+                    }
+
+                    assert_eq!(Ok(()), tree.check_invariants(&token), "swap_with_child {:?} at {}", side, at);
+
+                    {
+                        let mut cursor = tree.cursor_mut(&mut token);
+                        cursor.move_to(at);
+                        cursor.swap_with_child(side);
+                    }
+
+                    assert!(tree.iter(&token).copied().eq(0..31), "swap_with_child {:?} at {}", side, at);
+
+                    {
+                        let mut cursor = tree.cursor_mut(&mut token);
+                        cursor.move_to(at);
+
+                        if cursor.peek_down(side).is_none() {
+                            continue;
+                        }
+
+                        cursor.rotate(side);
+                    }
+
+                    //  Sizes and ranks, but not balance.
+                    assert_eq!(Ok(()), tree.check_invariants_impl(false, &token), "rotate {:?} at {}", side, at);
+
+                    {
+                        //  The demoted element, whose index is unchanged.
+                        let mut cursor = tree.cursor_mut(&mut token);
+                        cursor.move_to(at);
+                        cursor.rebalance();
+                    }
+
+                    assert_eq!(Ok(()), tree.check_invariants(&token), "rotate {:?} at {}", side, at);
+                    assert!(tree.iter(&token).copied().eq(0..31), "rotate {:?} at {}", side, at);
+                }
+            }
+
+            tree.clear(&mut token);
+        });
+    }
+
+    check::<WeightBalanced>();
+    check::<Avl>();
+    check::<RedBlack>();
+    check::<Treap>();
+    check::<Unbalanced>();
+}
+
 pub(super) fn with_tree<R, F>(flat: &[&str], fun: F) -> R
 where
     F: for<'brand> FnOnce(&mut GhostToken<'brand>, &mut TripodTree<'brand, String>) -> R,
@@ -943,7 +2195,7 @@ pub(super) fn inflate<'brand>(flat: &[&str], token: &mut GhostToken<'brand>) ->
             return None;
         }
 
-        let node = TripodTree::from_value(flat[index].to_string(), token);
+        let node = TripodTree::<'brand, String>::from_value(flat[index].to_string(), token);
 
         if let Some(left) = inflate_impl(left_child_index(index), flat, token) {
             set_child(&node, Side::Left, left, token);
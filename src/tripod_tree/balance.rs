@@ -0,0 +1,273 @@
+use super::Side;
+
+/// A summary of a sub-tree, as seen by a `BalancePolicy`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Summary {
+    /// The number of elements of the sub-tree.
+    pub size: usize,
+    /// The rank of the root of the sub-tree, as computed by `BalancePolicy::rank`.
+    pub rank: u64,
+}
+
+/// A balancing policy of the TripodTree.
+///
+/// The policy decides when, and how, to rotate a node so as to keep the tree balanced, the rotations themselves and the
+/// bookkeeping of the sizes being shared across all policies. The policy is consulted, bottom-up, for each node on the
+/// path of an edit, and for the nodes demoted by its own rotations.
+///
+/// Each node caches a rank, computed by the policy from its own summary and the summaries of its children. The rank of
+/// a node is recomputed whenever its children change, hence a rank such as the height is maintained for free.
+pub trait BalancePolicy {
+    /// Computes the rank of a node from its current summary and the summaries of its children, if any.
+    ///
+    /// The rank of a new element is computed from a summary whose rank is pseudo-random, and preserved by clones.
+    fn rank(node: &Summary, left: Option<&Summary>, right: Option<&Summary>) -> u64;
+
+    /// Returns the side of the child to rotate in place of the node, if the node is unbalanced.
+    ///
+    /// The children of the node are themselves balanced.
+    fn rotation(node: &Summary, left: Option<&Summary>, right: Option<&Summary>) -> Option<Side>;
+
+    /// Returns whether, prior to rotating a pivot in place of its parent, the inner child of the pivot should itself be
+    /// rotated in place of the pivot, that is whether a double rotation should be performed.
+    ///
+    /// The inner child of the pivot is the child which is moved under the parent by the rotation, the outer child the
+    /// child which stays under the pivot.
+    fn double_rotation(inner: Option<&Summary>, outer: Option<&Summary>) -> bool;
+}
+
+/// The default balancing policy: the sizes of the left and right sub-trees differ by at most a factor of 2.
+///
+/// A weight-balanced tree requires no rank, and its height is at most 2 log2(N).
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct WeightBalanced;
+
+impl BalancePolicy for WeightBalanced {
+    fn rank(_: &Summary, _: Option<&Summary>, _: Option<&Summary>) -> u64 { 0 }
+
+    fn rotation(_: &Summary, left: Option<&Summary>, right: Option<&Summary>) -> Option<Side> {
+        let (left, right) = (size(left), size(right));
+
+        if left > 2 * right + 1 {
+            Some(Side::Left)
+        } else if right > 2 * left + 1 {
+            Some(Side::Right)
+        } else {
+            None
+        }
+    }
+
+    fn double_rotation(inner: Option<&Summary>, outer: Option<&Summary>) -> bool { size(inner) > size(outer) }
+}
+
+/// An AVL balancing policy: the heights of the left and right sub-trees differ by at most 1.
+///
+/// The rank of a node is its height, minus one, and the height of the tree is at most 1.44 log2(N), making it best
+/// suited to read-heavy workloads.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Avl;
+
+impl BalancePolicy for Avl {
+    fn rank(_: &Summary, left: Option<&Summary>, right: Option<&Summary>) -> u64 { height(left).max(height(right)) }
+
+    fn rotation(_: &Summary, left: Option<&Summary>, right: Option<&Summary>) -> Option<Side> {
+        let (left, right) = (height(left), height(right));
+
+        if left > right + 1 {
+            Some(Side::Left)
+        } else if right > left + 1 {
+            Some(Side::Right)
+        } else {
+            None
+        }
+    }
+
+    fn double_rotation(inner: Option<&Summary>, outer: Option<&Summary>) -> bool { height(inner) > height(outer) }
+}
+
+/// A red-black balancing policy: the longest path from a node to a leaf is at most twice as long as the shortest.
+///
+/// The height of the tree is at most 2 log2(N), while requiring fewer rotations than `Avl` on insertion and removal,
+/// making it best suited to write-heavy workloads.
+///
+/// #   Deviation
+///
+/// Rather than a colour bit, the rank of a node caches the lengths of its longest and shortest paths to a leaf: a tree
+/// can be coloured as a red-black tree if, and only if, the longest path is at most twice as long as the shortest from
+/// every node, and unlike colours those lengths can be maintained by rotations alone, without recolouring passes.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct RedBlack;
+
+impl BalancePolicy for RedBlack {
+    fn rank(_: &Summary, left: Option<&Summary>, right: Option<&Summary>) -> u64 {
+        let longest = longest_path(left).max(longest_path(right));
+        let shortest = shortest_path(left).min(shortest_path(right));
+
+        (longest << 32) | shortest
+    }
+
+    fn rotation(_: &Summary, left: Option<&Summary>, right: Option<&Summary>) -> Option<Side> {
+        let longest = 1 + longest_path(left).max(longest_path(right));
+        let shortest = 1 + shortest_path(left).min(shortest_path(right));
+
+        if longest <= 2 * shortest {
+            return None;
+        }
+
+        if longest_path(left) > longest_path(right) { Some(Side::Left) } else { Some(Side::Right) }
+    }
+
+    fn double_rotation(inner: Option<&Summary>, outer: Option<&Summary>) -> bool {
+        longest_path(inner) > longest_path(outer)
+    }
+}
+
+/// A treap balancing policy: the priority of a node is greater than the priorities of its children.
+///
+/// The rank of a node is its priority, drawn pseudo-randomly on creation, hence the tree is balanced in expectation,
+/// with an expected height of O(log N), while requiring an expected O(1) rotations per insertion or removal.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Treap;
+
+impl BalancePolicy for Treap {
+    fn rank(node: &Summary, _: Option<&Summary>, _: Option<&Summary>) -> u64 { node.rank }
+
+    fn rotation(node: &Summary, left: Option<&Summary>, right: Option<&Summary>) -> Option<Side> {
+        let (left, right) = (priority(left), priority(right));
+
+        if left.max(right) <= Some(node.rank) {
+            None
+        } else if left > right {
+            Some(Side::Left)
+        } else {
+            Some(Side::Right)
+        }
+    }
+
+    fn double_rotation(_: Option<&Summary>, _: Option<&Summary>) -> bool { false }
+}
+
+/// A non-balancing policy: the tree is never rotated, and takes whatever shape the edits give it.
+///
+/// Edits are cheaper, as no rotation ever occurs, at the cost of a height of up to N.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Unbalanced;
+
+impl BalancePolicy for Unbalanced {
+    fn rank(_: &Summary, _: Option<&Summary>, _: Option<&Summary>) -> u64 { 0 }
+
+    fn rotation(_: &Summary, _: Option<&Summary>, _: Option<&Summary>) -> Option<Side> { None }
+
+    fn double_rotation(_: Option<&Summary>, _: Option<&Summary>) -> bool { false }
+}
+
+//
+//  Implementation
+//
+
+//  Internal; computes the pseudo-random seed rank of a new node, from its address.
+pub(super) fn seed_rank(address: usize) -> u64 {
+    //  SplitMix64 finalizer.
+    let mut x = (address as u64).wrapping_add(0x9E37_79B9_7F4A_7C15);
+    x = (x ^ (x >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    x ^ (x >> 31)
+}
+
+fn size(summary: Option<&Summary>) -> usize { summary.map(|s| s.size).unwrap_or(0) }
+
+fn priority(summary: Option<&Summary>) -> Option<u64> { summary.map(|s| s.rank) }
+
+//  The height of a sub-tree, 0 if empty.
+fn height(summary: Option<&Summary>) -> u64 { summary.map(|s| s.rank + 1).unwrap_or(0) }
+
+//  The number of nodes on the longest path from the root of a sub-tree to a leaf, 0 if empty.
+fn longest_path(summary: Option<&Summary>) -> u64 { summary.map(|s| (s.rank >> 32) + 1).unwrap_or(0) }
+
+//  The number of nodes on the shortest path from the root of a sub-tree to a missing child, 0 if empty.
+fn shortest_path(summary: Option<&Summary>) -> u64 { summary.map(|s| (s.rank & 0xFFFF_FFFF) + 1).unwrap_or(0) }
+
+#[cfg(test)]
+mod tests {
+
+use super::*;
+
+fn leaf() -> Summary { Summary { size: 1, rank: 0 } }
+
+fn node<P: BalancePolicy>(left: Option<&Summary>, right: Option<&Summary>) -> Summary {
+    let size = 1 + size(left) + size(right);
+
+    Summary { size, rank: P::rank(&Summary { size, rank: 0 }, left, right) }
+}
+
+#[test]
+fn balance_weight_balanced() {
+    let leaf = leaf();
+    let pair = node::<WeightBalanced>(Some(&leaf), None);
+    let triple = node::<WeightBalanced>(Some(&pair), None);
+
+    assert_eq!(None, WeightBalanced::rotation(&pair, Some(&leaf), None));
+    assert_eq!(Some(Side::Left), WeightBalanced::rotation(&triple, Some(&pair), None));
+    assert_eq!(Some(Side::Right), WeightBalanced::rotation(&triple, None, Some(&pair)));
+
+    assert!(WeightBalanced::double_rotation(Some(&leaf), None));
+    assert!(!WeightBalanced::double_rotation(None, Some(&leaf)));
+}
+
+#[test]
+fn balance_avl() {
+    let leaf = leaf();
+    let pair = node::<Avl>(Some(&leaf), None);
+    let triple = node::<Avl>(Some(&pair), None);
+
+    assert_eq!(0, leaf.rank);
+    assert_eq!(1, pair.rank);
+    assert_eq!(2, triple.rank);
+
+    assert_eq!(None, Avl::rotation(&pair, Some(&leaf), None));
+    assert_eq!(Some(Side::Left), Avl::rotation(&triple, Some(&pair), None));
+    assert_eq!(Some(Side::Right), Avl::rotation(&triple, None, Some(&pair)));
+}
+
+#[test]
+fn balance_red_black() {
+    let leaf = leaf();
+    let pair = node::<RedBlack>(Some(&leaf), None);
+    let triple = node::<RedBlack>(Some(&pair), None);
+
+    assert_eq!((1, 1), (longest_path(Some(&leaf)), shortest_path(Some(&leaf))));
+    assert_eq!((2, 1), (longest_path(Some(&pair)), shortest_path(Some(&pair))));
+    assert_eq!((3, 1), (longest_path(Some(&triple)), shortest_path(Some(&triple))));
+
+    assert_eq!(None, RedBlack::rotation(&pair, Some(&leaf), None));
+    assert_eq!(Some(Side::Left), RedBlack::rotation(&triple, Some(&pair), None));
+
+    //  A perfect tree of 3 elements, below a root whose other child is a leaf: 3 <= 2 * 2.
+    let perfect = node::<RedBlack>(Some(&leaf), Some(&leaf));
+    let root = node::<RedBlack>(Some(&perfect), Some(&leaf));
+
+    assert_eq!(None, RedBlack::rotation(&root, Some(&perfect), Some(&leaf)));
+}
+
+#[test]
+fn balance_treap() {
+    let low = Summary { size: 1, rank: 1 };
+    let mid = Summary { size: 1, rank: 2 };
+    let high = Summary { size: 3, rank: 3 };
+
+    assert_eq!(3, Treap::rank(&high, Some(&low), Some(&mid)));
+
+    assert_eq!(None, Treap::rotation(&high, Some(&low), Some(&mid)));
+    assert_eq!(Some(Side::Right), Treap::rotation(&low, Some(&mid), Some(&high)));
+    assert_eq!(Some(Side::Left), Treap::rotation(&low, Some(&high), None));
+
+    assert!(!Treap::double_rotation(Some(&high), Some(&low)));
+}
+
+#[test]
+fn balance_seed_rank() {
+    assert_ne!(seed_rank(0x1000), seed_rank(0x1008));
+    assert_eq!(seed_rank(0x1000), seed_rank(0x1000));
+}
+
+} // mod tests
@@ -0,0 +1,307 @@
+use alloc::{collections::VecDeque, vec::Vec};
+
+use ghost_cell::GhostToken;
+
+use crate::{linked_list::LinkedList, tripod_list::TripodList};
+
+use super::{retract, BalancePolicy, Side, TripodTree};
+
+//  Conversions.
+//
+//  The nodes of the LinkedList, TripodList and TripodTree all have different layouts, hence no conversion can reuse
+//  the nodes of the source collection: each node is released as soon as its element has been moved into the target.
+impl<'brand, T, P: BalancePolicy> TripodTree<'brand, T, P> {
+    /// Creates a balanced tree from the elements of `vec`, in order.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(N) in the number of elements, and expected O(N) for a `Treap`, whose priorities are heap-ordered
+    ///     once the tree is complete.
+    /// -   Space: O(1).
+    pub fn from_vec(vec: Vec<T>, token: &mut GhostToken<'brand>) -> Self {
+        let mut values = vec.into_iter();
+
+        Self::from_iter_vine(token, |_| values.next())
+    }
+
+    /// Creates a balanced tree from the elements of `deque`, in order.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(N) in the number of elements, and expected O(N) for a `Treap`, whose priorities are heap-ordered
+    ///     once the tree is complete.
+    /// -   Space: O(1).
+    pub fn from_vec_deque(deque: VecDeque<T>, token: &mut GhostToken<'brand>) -> Self {
+        let mut values = deque.into_iter();
+
+        Self::from_iter_vine(token, |_| values.next())
+    }
+
+    /// Creates a balanced tree from the elements of `list`, in order.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(N) in the number of elements, and expected O(N) for a `Treap`, whose priorities are heap-ordered
+    ///     once the tree is complete.
+    /// -   Space: O(1).
+    pub fn from_list(mut list: TripodList<'brand, T>, token: &mut GhostToken<'brand>) -> Self {
+        Self::from_iter_vine(token, |token| list.pop_front(token))
+    }
+
+    /// Creates a balanced tree from the elements of `list`, in order.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(N) in the number of elements, and expected O(N) for a `Treap`, whose priorities are heap-ordered
+    ///     once the tree is complete.
+    /// -   Space: O(1).
+    pub fn from_linked_list(mut list: LinkedList<'brand, T>, token: &mut GhostToken<'brand>) -> Self {
+        Self::from_iter_vine(token, |token| list.pop_front(token))
+    }
+
+    /// Moves the elements of the tree, in order, into a `Vec`.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(N) in the number of elements.
+    /// -   Space: O(N) in the number of elements.
+    pub fn into_vec(self, token: &mut GhostToken<'brand>) -> Vec<T> {
+        let mut result = Vec::with_capacity(self.len(token));
+
+        self.drain_with(token, |value, _| result.push(value));
+
+        result
+    }
+
+    /// Moves the elements of the tree, in order, into a `VecDeque`.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(N) in the number of elements.
+    /// -   Space: O(N) in the number of elements.
+    pub fn into_vec_deque(self, token: &mut GhostToken<'brand>) -> VecDeque<T> {
+        let mut result = VecDeque::with_capacity(self.len(token));
+
+        self.drain_with(token, |value, _| result.push_back(value));
+
+        result
+    }
+
+    /// Copies the elements of the tree, in order, into a `Vec`.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(N) in the number of elements.
+    /// -   Space: O(N) in the number of elements.
+    pub fn to_vec(&self, token: &GhostToken<'brand>) -> Vec<T>
+    where
+        T: Clone,
+    {
+        let mut result = Vec::with_capacity(self.len(token));
+
+        let mut cursor = self.cursor(token);
+        cursor.move_to_front();
+
+        while let Some(element) = cursor.current() {
+            result.push(element.clone());
+            cursor.move_next();
+        }
+
+        result
+    }
+
+    //  Internal; creates a balanced tree from the elements returned by `next`, which is lent the token, in order, until
+    //  it returns None.
+    //
+    //  Rather than balancing the tree on each push, for O(N log N) overall, the elements are pushed as a left "vine",
+    //  which is balanced once, at the end.
+    //
+    //  Complexity: Time O(N), expected for a `Treap`, see `balance_vine`, Space O(1).
+    pub(super) fn from_iter_vine<F>(token: &mut GhostToken<'brand>, mut next: F) -> Self
+    where
+        F: FnMut(&mut GhostToken<'brand>) -> Option<T>,
+    {
+        let mut result = Self::new();
+
+        while let Some(value) = next(token) {
+            result.push_vine(value, token);
+        }
+
+        result.balance_vine(token);
+
+        result
+    }
+
+    //  Internal; moves the elements of the tree, in order, into `fun`, which is lent the token.
+    //
+    //  The front node is repeatedly detached, its right sub-tree, if any, taking its place. Since the front node is only
+    //  ever the root or a left child, the search for the next front node resumes from where the former one was.
+    //
+    //  The sizes of the nodes are not maintained, as the tree is destroyed in the process.
+    //
+    //  Complexity: Time O(N), Space O(1).
+    pub(crate) fn drain_with<F>(mut self, token: &mut GhostToken<'brand>, mut fun: F)
+    where
+        F: FnMut(T, &mut GhostToken<'brand>),
+    {
+        let mut tripod = match self.root.as_ref() {
+            Some(root) => root.borrow(token).deploy(),
+            None => return,
+        };
+
+        //  O(N) iterations, performing O(1) work each.
+        loop {
+            //  Descend to the front node.
+            if let Some(left) = tripod.borrow(token).left() {
+                let left_tripod = left.borrow(token).deploy();
+                retract(tripod, token);
+                tripod = left_tripod;
+                continue;
+            }
+
+            let right = tripod.borrow_mut(token).take_child(Side::Right);
+            let up = tripod.borrow_mut(token).up.take();
+
+            let (node, next) = match (up, right) {
+                (Some(up), Some(right)) => {
+                    let right_tripod = right.borrow(token).deploy();
+
+                    let node = up.borrow_mut(token).replace_child(Side::Left, right).expect("Left child");
+                    let node_from_right = right_tripod.borrow_mut(token).up.replace(up).expect("Parent of right child");
+                    tripod.borrow_mut(token).set_child(Side::Right, node_from_right);
+
+                    (node, Some(right_tripod))
+                },
+                (Some(up), None) => {
+                    let up_tripod = up.borrow(token).deploy();
+
+                    let node = up_tripod.borrow_mut(token).replace_child(Side::Left, up).expect("Left child");
+
+                    (node, Some(up_tripod))
+                },
+                (None, Some(right)) => {
+                    let right_tripod = right.borrow(token).deploy();
+
+                    let node_from_right = right_tripod.borrow_mut(token).up.take().expect("Parent of right child");
+                    tripod.borrow_mut(token).set_child(Side::Right, node_from_right);
+
+                    let node = self.root.replace(right).expect("Root");
+
+                    (node, Some(right_tripod))
+                },
+                (None, None) => (self.root.take().expect("Root"), None),
+            };
+
+            retract(tripod, token);
+            let value = Self::node_into_inner(node, token);
+            fun(value, token);
+
+            match next {
+                Some(next) => tripod = next,
+                None => break,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+use core::mem;
+
+use super::super::tests::*;
+use super::*;
+
+fn create(length: usize) -> Vec<String> { (0..length).map(|i| i.to_string()).collect() }
+
+#[test]
+fn conversion_vec() {
+    for length in 0..20 {
+        let expected = create(length);
+
+        with_tree(&[], |token, _| {
+            let tree: TripodTree<'_, String> = TripodTree::from_vec(expected.clone(), token);
+
+            assert_eq!(length, tree.len(token));
+            assert_eq!(Ok(()), tree.check_invariants(token));
+            assert_eq!(expected, tree.to_vec(token));
+            assert_eq!(expected, tree.into_vec(token));
+        });
+    }
+}
+
+#[test]
+fn conversion_vec_deque() {
+    for length in 0..20 {
+        let expected: VecDeque<String> = create(length).into();
+
+        with_tree(&[], |token, _| {
+            let tree: TripodTree<'_, String> = TripodTree::from_vec_deque(expected.clone(), token);
+
+            assert_eq!(length, tree.len(token));
+            assert_eq!(Ok(()), tree.check_invariants(token));
+            assert_eq!(expected, tree.into_vec_deque(token));
+        });
+    }
+}
+
+#[test]
+fn conversion_into_vec_shapes() {
+    //  Exercise all shapes of the front node: root or left child, with or without right child.
+    let trees: [&[&str]; 2] = [
+        &["0", "-", "2", "-", "-", "1", "3"],
+        &["4", "0", "-", "-", "2", "-", "-", "-", "-", "1", "3"],
+    ];
+
+    for flat in trees.iter() {
+        with_tree(flat, |token, tree| {
+            let expected: Vec<String> = tree.iter(token).cloned().collect();
+
+            let tree = mem::take(tree);
+
+            assert_eq!(expected, tree.into_vec(token));
+        });
+    }
+}
+
+#[test]
+fn conversion_list() {
+    for length in 0..20 {
+        let expected = create(length);
+
+        with_tree(&[], |token, _| {
+            let mut list = TripodList::new();
+
+            for value in expected.iter().cloned() {
+                list.push_back(value, token);
+            }
+
+            let tree: TripodTree<'_, String> = TripodTree::from_list(list, token);
+
+            assert_eq!(Ok(()), tree.check_invariants(token));
+            assert_eq!(expected, tree.into_vec(token));
+        });
+    }
+}
+
+#[test]
+fn conversion_linked_list() {
+    for length in 0..20 {
+        let expected = create(length);
+
+        with_tree(&[], |token, _| {
+            let mut list = LinkedList::new();
+
+            for value in expected.iter().cloned() {
+                list.push_back(value, token);
+            }
+
+            let tree: TripodTree<'_, String> = TripodTree::from_linked_list(list, token);
+
+            assert_eq!(Ok(()), tree.check_invariants(token));
+            assert_eq!(expected, tree.into_vec(token));
+        });
+    }
+}
+
+} // mod tests
@@ -1,12 +1,13 @@
 use core::{
     cmp,
+    marker::PhantomData,
     mem,
     ops::Range,
 };
 
 use ghost_cell::GhostToken;
 
-use super::{GhostNode, QuarterNodePtr, Side, TripodTree};
+use super::{BalancePolicy, Direction, GhostNode, NodePool, QuarterNodePtr, Side, Summary, TripodTree, WeightBalanced};
 
 /// A Cursor over the TripodTree.
 ///
@@ -20,7 +21,7 @@ use super::{GhostNode, QuarterNodePtr, Side, TripodTree};
 /// cursor back to the root.
 pub struct Cursor<'a, 'brand, T> {
     token: &'a GhostToken<'brand>,
-    tree: &'a TripodTree<'brand, T>,
+    root: Option<&'a GhostNode<'brand, T>>,
     node: Option<&'a GhostNode<'brand, T>>,
     index: usize,
 }
@@ -28,10 +29,8 @@ pub struct Cursor<'a, 'brand, T> {
 //  Constant time cursor navigation.
 impl<'a, 'brand, T> Cursor<'a, 'brand, T> {
     /// Creates a new cursor pointing at the root of the tree, if any.
-    pub fn new(token: &'a GhostToken<'brand>, tree: &'a TripodTree<'brand, T>) -> Self {
-        let (node, index) = Self::root_of(token, tree);
-
-        Self { token, index, node, tree, }
+    pub fn new<P>(token: &'a GhostToken<'brand>, tree: &'a TripodTree<'brand, T, P>) -> Self {
+        Self::from_root(token, tree.root.as_ref().map(|node| &**node))
     }
 
     /// Returns the index of the cursor, if any.
@@ -51,8 +50,13 @@ impl<'a, 'brand, T> Cursor<'a, 'brand, T> {
         }).unwrap_or(0..0)
     }
 
+    /// Returns the side of the element the cursor points to, relative to its parent, if any.
+    ///
+    /// If the cursor points to the root, or to the "twilight" non-element, None is returned.
+    pub fn side(&self) -> Option<Side> { self.node.and_then(|node| node.borrow(self.token).is_child(self.token)) }
+
     /// Moves the cursor to the root, if any.
-    pub fn move_to_root(&mut self) { *self = Self::new(self.token, self.tree) }
+    pub fn move_to_root(&mut self) { *self = Self::from_root(self.token, self.root) }
 
     /// Moves the cursor to the parent node, if any.
     ///
@@ -184,9 +188,15 @@ impl<'a, 'brand, T> Cursor<'a, 'brand, T> {
     /// Returns a reference to the child element on the given side, if any.
     pub fn peek_down(&self, side: Side) -> Option<&'a T> { self.peek_down_node(side).0.map(|node| &node.borrow(self.token).value) }
 
-    //  Internal; extract the root and its index from the tree.
-    fn root_of(token: &'a GhostToken<'brand>, tree: &'a TripodTree<'brand, T>) -> (Option<&'a GhostNode<'brand, T>>, usize) {
-        let root = tree.root.as_ref().map(|node| &**node);
+    //  Internal; creates a new cursor pointing at the root, if any.
+    fn from_root(token: &'a GhostToken<'brand>, root: Option<&'a GhostNode<'brand, T>>) -> Self {
+        let (node, index) = Self::root_of(token, root);
+
+        Self { token, index, node, root, }
+    }
+
+    //  Internal; extract the index of the root, if any.
+    fn root_of(token: &'a GhostToken<'brand>, root: Option<&'a GhostNode<'brand, T>>) -> (Option<&'a GhostNode<'brand, T>>, usize) {
         let index = root.map(|node| node.borrow(token).index(token)).unwrap_or(0);
 
         (root, index)
@@ -231,7 +241,7 @@ impl<'a, 'brand, T> Cursor<'a, 'brand, T> {
 
             (dest, index)
         } else {
-            Self::root_of(self.token, self.tree)
+            Self::root_of(self.token, self.root)
         }
     }
 
@@ -249,7 +259,7 @@ impl<'a, 'brand, T> Cursor<'a, 'brand, T> {
 
             (dest, index)
         } else {
-            Self::root_of(self.token, self.tree)
+            Self::root_of(self.token, self.root)
         }
     }
 
@@ -272,7 +282,7 @@ impl<'a, 'brand, T> Cursor<'a, 'brand, T> {
 
             (dest, index)
         } else {
-            Self::root_of(self.token, self.tree)
+            Self::root_of(self.token, self.root)
         }
     }
 }
@@ -285,12 +295,9 @@ impl<'a, 'brand, T> Cursor<'a, 'brand, T> {
     ///
     /// -   Time: O(log N) in the number of element.
     /// -   Space: O(1).
-    pub fn new_front(token: &'a GhostToken<'brand>, tree: &'a TripodTree<'brand, T>) -> Self {
+    pub fn new_front<P>(token: &'a GhostToken<'brand>, tree: &'a TripodTree<'brand, T, P>) -> Self {
         let mut cursor = Self::new(token, tree);
-
-        while let Some(_) = cursor.try_move_left() {}
-
-        debug_assert_eq!(0, cursor.index);
+        cursor.move_to_front();
 
         cursor
     }
@@ -301,12 +308,9 @@ impl<'a, 'brand, T> Cursor<'a, 'brand, T> {
     ///
     /// -   Time: O(log N) in the number of element.
     /// -   Space: O(1).
-    pub fn new_back(token: &'a GhostToken<'brand>, tree: &'a TripodTree<'brand, T>) -> Self {
+    pub fn new_back<P>(token: &'a GhostToken<'brand>, tree: &'a TripodTree<'brand, T, P>) -> Self {
         let mut cursor = Self::new(token, tree);
-
-        while let Some(_) = cursor.try_move_right() {}
-
-        debug_assert_eq!(cursor.len() - 1, cursor.index);
+        cursor.move_to_back();
 
         cursor
     }
@@ -317,7 +321,13 @@ impl<'a, 'brand, T> Cursor<'a, 'brand, T> {
     ///
     /// -   Time: O(log N) in the number of element.
     /// -   Space: O(1).
-    pub fn move_to_front(&mut self) { *self = Self::new_front(self.token, self.tree); }
+    pub fn move_to_front(&mut self) {
+        self.move_to_root();
+
+        while let Some(_) = self.try_move_left() {}
+
+        debug_assert_eq!(0, self.index);
+    }
 
     /// Moves the cursor to the back element, if any.
     ///
@@ -325,7 +335,13 @@ impl<'a, 'brand, T> Cursor<'a, 'brand, T> {
     ///
     /// -   Time: O(log N) in the number of element.
     /// -   Space: O(1).
-    pub fn move_to_back(&mut self) { *self = Self::new_back(self.token, self.tree); }
+    pub fn move_to_back(&mut self) {
+        self.move_to_root();
+
+        while let Some(_) = self.try_move_right() {}
+
+        debug_assert_eq!(self.len().checked_sub(1), self.index());
+    }
 
     /// Moves the cursor to the next element, if any.
     ///
@@ -450,6 +466,98 @@ impl<'a, 'brand, T> Cursor<'a, 'brand, T> {
         }
     }
 
+    /// Moves the cursor by `delta` elements, forward if `delta` is positive and backward if it is negative.
+    ///
+    /// If there is no such element, then the cursor moves to the "twilight" non-element, which exists between the front
+    /// and back element.
+    ///
+    /// If the cursor points to the "twilight" non-element, then moves `delta - 1` elements past the front element if
+    /// `delta` is positive, and `-delta - 1` elements before the back element if `delta` is negative, just like
+    /// `move_next` and `move_prev` do.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(log |delta|) in the distance, when the cursor and the destination are in the same sub-tree of height
+    ///     O(log |delta|), and O(log N) in the number of elements at worst, that is when the path between them goes
+    ///     through a much higher common ancestor, or when the cursor points to the "twilight" non-element.
+    /// -   Space: O(1).
+    pub fn move_by(&mut self, delta: isize) {
+        let (node, index) = self.peek_by_node(delta);
+
+        self.index = index;
+        self.node = node;
+    }
+
+    /// Attempts to move the cursor by `delta` elements, forward if `delta` is positive and backward if it is negative.
+    ///
+    /// Returns a reference to the pointed to element, in case of success.
+    ///
+    /// If there is no such element, or the cursor points to the "twilight" non-element, does not move.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(log |delta|) in the distance, when the cursor and the destination are in the same sub-tree of height
+    ///     O(log |delta|), and O(log N) in the number of elements at worst.
+    /// -   Space: O(1).
+    pub fn try_move_by(&mut self, delta: isize) -> Option<&'a T> {
+        self.node?;
+
+        let (node, index) = self.peek_by_node(delta);
+
+        if node.is_some() {
+            self.index = index;
+            self.node = node;
+            self.current()
+        } else {
+            None
+        }
+    }
+
+    /// Descends from the root, guided by `f`, and moves the cursor to the element the descent stops at.
+    ///
+    /// At each element, `f` is invoked with the element, the range of indices covered by the sub-tree rooted at this
+    /// element, and the sizes of its left and right sub-trees, and returns whether to descend left, right, or stop.
+    ///
+    /// Returns `Ok` with the index of the element at which `f` returned `Direction::Stop`. Otherwise, if `f` directs the
+    /// descent to a missing child, the descent stops at the last element visited and returns `Err` with the index at
+    /// which this child would be, that is the index of this element if left, and the index after it if right.
+    ///
+    /// If the tree is empty, returns `Err(0)` and the cursor points to the "twilight" non-element.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(log N) in the number of elements, and as many invocations of `f`.
+    /// -   Space: O(1).
+    pub fn seek_by<F>(&mut self, mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T, Range<usize>, usize, usize) -> Direction,
+    {
+        self.move_to_root();
+
+        loop {
+            let node = if let Some(node) = self.node { node.borrow(self.token) } else { return Err(0) };
+
+            let left_size = node.left_size(self.token);
+            let right_size = node.right_size(self.token);
+            let range = (self.index - left_size)..(self.index + right_size + 1);
+
+            let side = match f(&node.value, range, left_size, right_size) {
+                Direction::Left => Side::Left,
+                Direction::Right => Side::Right,
+                Direction::Stop => return Ok(self.index),
+            };
+
+            if node.child(side).is_none() {
+                return Err(match side {
+                    Side::Left => self.index,
+                    Side::Right => self.index + 1,
+                });
+            }
+
+            self.move_down(side);
+        }
+    }
+
     /// Returns a reference to the next element, if any.
     ///
     /// #   Complexity
@@ -475,7 +583,7 @@ impl<'a, 'brand, T> Cursor<'a, 'brand, T> {
     pub fn peek_at(&self, at: usize) -> Option<&'a T> { self.peek_at_node(at).map(|node| &node.borrow(self.token).value) }
 
     //  Internal; returns the length of the tree.
-    fn len(&self) -> usize { self.tree.len(self.token) }
+    fn len(&self) -> usize { self.root.map(|root| root.borrow(self.token).size).unwrap_or(0) }
 
     //  Internal; returns a reference to the GhostNode at the next index.
     fn peek_next_node(&self) -> (Option<&'a GhostNode<'brand, T>>, usize) {
@@ -515,20 +623,20 @@ impl<'a, 'brand, T> Cursor<'a, 'brand, T> {
             return self.node;
         }
 
-        let mut cursor = match (self.node, self.tree.root.as_ref().map(|n| &**n)) {
+        let mut cursor = match (self.node, self.root) {
             (Some(_), Some(root)) => {
                 let root_index = root.borrow(self.token).index(self.token);
 
                 if at >= root_index && root_index > self.index {
-                    Cursor::new(self.token, self.tree)
+                    Cursor::from_root(self.token, self.root)
                 } else if at <= root_index && root_index < self.index {
-                    Cursor::new(self.token, self.tree)
+                    Cursor::from_root(self.token, self.root)
                 } else {
                     self.clone()
                 }
             },
             (Some(_), None) => self.clone(),
-            (None, Some(_)) => Cursor::new(self.token, self.tree),
+            (None, Some(_)) => Cursor::from_root(self.token, self.root),
             (None, None) => unreachable!("at >= length, then"),
         };
 
@@ -539,8 +647,46 @@ impl<'a, 'brand, T> Cursor<'a, 'brand, T> {
             }
         }
 
-        //  From then on, we are guaranteed that `cursor` is pointing to an ancestor of `at`, so it's somewhere down.
-        let mut max_iteration = length + 1;
+        cursor.descend_to_node(at)
+    }
+
+    //  Internal; returns a reference to the GhostNode `delta` elements away, and the matching index.
+    //
+    //  Unlike `peek_at_node`, never restarts from the root, but only climbs up as far as the first common ancestor of
+    //  the current node and the destination.
+    fn peek_by_node(&self, delta: isize) -> (Option<&'a GhostNode<'brand, T>>, usize) {
+        let length = self.len();
+        let distance = delta.unsigned_abs();
+
+        let at = match (self.node, delta >= 0) {
+            (Some(_), true) => self.index.checked_add(distance),
+            (Some(_), false) => self.index.checked_sub(distance),
+            (None, true) => distance.checked_sub(1),
+            (None, false) => length.checked_sub(distance),
+        };
+
+        let at = match at {
+            Some(at) if at < length => at,
+            _ => return (None, length),
+        };
+
+        let mut cursor = if self.node.is_some() { *self } else { Cursor::from_root(self.token, self.root) };
+
+        while !cursor.range().contains(&at) {
+            cursor.move_up();
+        }
+
+        (cursor.descend_to_node(at), at)
+    }
+
+    //  Internal; returns a reference to the GhostNode at the specific index, amongst the descendants of the cursor.
+    //
+    //  The cursor must point to an ancestor of the node at index `at`, or to this very node.
+    fn descend_to_node(self, at: usize) -> Option<&'a GhostNode<'brand, T>> {
+        let mut cursor = self;
+
+        //  `cursor` is pointing to an ancestor of `at`, so it's somewhere down.
+        let mut max_iteration = self.len() + 1;
         loop {
             use cmp::Ordering::*;
 
@@ -589,21 +735,23 @@ impl<'a, 'brand, T> Copy for Cursor<'a, 'brand, T> {}
 /// drop of this cursor -- unless it points to the "twilight" non-element -- will leave the tree in an unusable state.
 ///
 /// Any further mutable operation on the tree, including calling `clear`, is at risk of panicking.
-pub struct CursorMut<'a, 'brand, T> {
+pub struct CursorMut<'a, 'brand, T, P: BalancePolicy = WeightBalanced> {
     token: &'a mut GhostToken<'brand>,
-    tree: &'a mut TripodTree<'brand, T>,
+    tree: &'a mut TripodTree<'brand, T, P>,
     node: Option<QuarterNodePtr<'brand, T>>,
     index: usize,
+    //  Whether structural edits may have unbalanced the tree, in which case its balance is not asserted on drop.
+    unbalanced: bool,
 }
 
 //  Constant time cursor navigation.
-impl<'a, 'brand, T> CursorMut<'a, 'brand, T> {
+impl<'a, 'brand, T, P: BalancePolicy> CursorMut<'a, 'brand, T, P> {
     /// Creates a new instance pointing to the front element of the tree, if any.
-    pub fn new(token: &'a mut GhostToken<'brand>, tree: &'a mut TripodTree<'brand, T>) -> Self {
+    pub fn new(token: &'a mut GhostToken<'brand>, tree: &'a mut TripodTree<'brand, T, P>) -> Self {
         let (node, index) = Self::root_of(token, tree);
         let node = node.map(|node| node.borrow(token).deploy());
 
-        Self { token, index, node, tree, }
+        Self { token, index, node, tree, unbalanced: false, }
     }
 
     /// Returns a read-only cursor pointing to the current element.
@@ -611,9 +759,9 @@ impl<'a, 'brand, T> CursorMut<'a, 'brand, T> {
         let token = &*self.token;
         let index = self.index;
         let node = self.node.as_ref().map(|rc| &**rc);
-        let tree = &*self.tree;
+        let root = self.tree.root.as_ref().map(|rc| &**rc);
 
-        Cursor { token, index, node, tree, }
+        Cursor { token, index, node, root, }
     }
 
     /// Returns the index of the element pointed to by the cursor in the tree.
@@ -783,7 +931,7 @@ impl<'a, 'brand, T> CursorMut<'a, 'brand, T> {
     pub fn peek_down(&self, side: Side) -> Option<&T> { self.peek_down_node(side).0.map(|node| &node.borrow(self.token).value) }
 
     //  Internal; extract the root and its index from the tree.
-    fn root_of<'b>(token: &'b GhostToken<'brand>, tree: &'b TripodTree<'brand, T>) -> (Option<&'b GhostNode<'brand, T>>, usize) {
+    fn root_of<'b>(token: &'b GhostToken<'brand>, tree: &'b TripodTree<'brand, T, P>) -> (Option<&'b GhostNode<'brand, T>>, usize) {
         let root = tree.root.as_ref().map(|node| &**node);
         let index = root.map(|node| node.borrow(token).index(token)).unwrap_or(0);
 
@@ -829,14 +977,14 @@ impl<'a, 'brand, T> CursorMut<'a, 'brand, T> {
 }
 
 //  Logarithmic cursor navigation.
-impl<'a, 'brand, T> CursorMut<'a, 'brand, T> {
+impl<'a, 'brand, T, P: BalancePolicy> CursorMut<'a, 'brand, T, P> {
     /// Creates a new cursor pointing at the front element of the tree, if any.
     ///
     /// #   Complexity
     ///
     /// -   Time: O(log N) in the number of element.
     /// -   Space: O(1).
-    pub fn new_front(token: &'a mut GhostToken<'brand>, tree: &'a mut TripodTree<'brand, T>) -> Self {
+    pub fn new_front(token: &'a mut GhostToken<'brand>, tree: &'a mut TripodTree<'brand, T, P>) -> Self {
         let mut cursor = Self::new(token, tree);
 
         while let Some(_) = cursor.try_move_left() {}
@@ -852,7 +1000,7 @@ impl<'a, 'brand, T> CursorMut<'a, 'brand, T> {
     ///
     /// -   Time: O(log N) in the number of element.
     /// -   Space: O(1).
-    pub fn new_back(token: &'a mut GhostToken<'brand>, tree: &'a mut TripodTree<'brand, T>) -> Self {
+    pub fn new_back(token: &'a mut GhostToken<'brand>, tree: &'a mut TripodTree<'brand, T, P>) -> Self {
         let mut cursor = Self::new(token, tree);
 
         while let Some(_) = cursor.try_move_right() {}
@@ -1023,6 +1171,97 @@ impl<'a, 'brand, T> CursorMut<'a, 'brand, T> {
         }
     }
 
+    /// Moves the cursor by `delta` elements, forward if `delta` is positive and backward if it is negative.
+    ///
+    /// If there is no such element, then the cursor moves to the "twilight" non-element, which exists between the front
+    /// and back element.
+    ///
+    /// If the cursor points to the "twilight" non-element, then moves `delta - 1` elements past the front element if
+    /// `delta` is positive, and `-delta - 1` elements before the back element if `delta` is negative, just like
+    /// `move_next` and `move_prev` do.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(log |delta|) in the distance, when the cursor and the destination are in the same sub-tree of height
+    ///     O(log |delta|), and O(log N) in the number of elements at worst, that is when the path between them goes
+    ///     through a much higher common ancestor, or when the cursor points to the "twilight" non-element.
+    /// -   Space: O(1).
+    pub fn move_by(&mut self, delta: isize) {
+        if delta == 0 {
+            return;
+        }
+
+        let (node, index) = self.as_cursor().peek_by_node(delta);
+        let new_tripod = node.map(|node| self.deploy_tripod(node));
+
+        self.switch_tripod(new_tripod, index);
+    }
+
+    /// Attempts to move the cursor by `delta` elements, forward if `delta` is positive and backward if it is negative.
+    ///
+    /// Returns a reference to the pointed to element, in case of success.
+    ///
+    /// If there is no such element, or the cursor points to the "twilight" non-element, does not move.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(log |delta|) in the distance, when the cursor and the destination are in the same sub-tree of height
+    ///     O(log |delta|), and O(log N) in the number of elements at worst.
+    /// -   Space: O(1).
+    pub fn try_move_by(&mut self, delta: isize) -> Option<&mut T> {
+        self.node.as_ref()?;
+
+        if delta == 0 {
+            return self.current();
+        }
+
+        let (node, index) = self.as_cursor().peek_by_node(delta);
+
+        if let Some(node) = node {
+            let new_tripod = Some(self.deploy_tripod(node));
+
+            self.switch_tripod(new_tripod, index);
+            self.current()
+        } else {
+            None
+        }
+    }
+
+    /// Descends from the root, guided by `f`, and moves the cursor to the element the descent stops at.
+    ///
+    /// At each element, `f` is invoked with the element, the range of indices covered by the sub-tree rooted at this
+    /// element, and the sizes of its left and right sub-trees, and returns whether to descend left, right, or stop.
+    ///
+    /// Returns `Ok` with the index of the element at which `f` returned `Direction::Stop`. Otherwise, if `f` directs the
+    /// descent to a missing child, the descent stops at the last element visited and returns `Err` with the index at
+    /// which this child would be, that is the index of this element if left, and the index after it if right.
+    ///
+    /// If the tree is empty, returns `Err(0)` and the cursor points to the "twilight" non-element.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(log N) in the number of elements, and as many invocations of `f`.
+    /// -   Space: O(1).
+    pub fn seek_by<F>(&mut self, f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T, Range<usize>, usize, usize) -> Direction,
+    {
+        let mut cursor = self.as_cursor();
+        let result = cursor.seek_by(f);
+
+        //  Redeploying the current tripod would fail.
+        if self.node.is_some() && cursor.index == self.index {
+            return result;
+        }
+
+        let index = cursor.index;
+        let new_tripod = cursor.node.map(|node| self.deploy_tripod(node));
+
+        self.switch_tripod(new_tripod, index);
+
+        result
+    }
+
     /// Returns a reference to the next element, if any.
     ///
     /// #   Complexity
@@ -1069,7 +1308,7 @@ impl<'a, 'brand, T> CursorMut<'a, 'brand, T> {
 }
 
 //  Edit cursor operations.
-impl<'a, 'brand, T> CursorMut<'a, 'brand, T> {
+impl<'a, 'brand, T, P: BalancePolicy> CursorMut<'a, 'brand, T, P> {
     /// Inserts a new element in the tree after the current one.
     ///
     /// See `splice_after` for the details.
@@ -1099,7 +1338,42 @@ impl<'a, 'brand, T> CursorMut<'a, 'brand, T> {
         let removed = self.remove_current_as_tree();
         debug_assert!(removed.len(self.token) <= 1, "{} > 1", removed.len(self.token));
 
-        removed.root.map(|root| { TripodTree::node_into_inner(root, self.token) })
+        removed.root.map(|root| { TripodTree::<'brand, T, P>::node_into_inner(root, self.token) })
+    }
+
+    /// Inserts a new element in the tree after the current one, reusing a node from the pool, if any.
+    ///
+    /// See `splice_after` for the details.
+    ///
+    /// A memory allocation occurs only if the pool is empty.
+    pub fn insert_after_pooled(&mut self, value: T, pool: &mut NodePool<'brand, T>) {
+        let mut other = TripodTree::singleton_pooled(value, pool, self.token);
+        self.splice_after(&mut other);
+    }
+
+    /// Inserts a new element in the tree before the current one, reusing a node from the pool, if any.
+    ///
+    /// See `splice_before` for the details.
+    ///
+    /// A memory allocation occurs only if the pool is empty.
+    pub fn insert_before_pooled(&mut self, value: T, pool: &mut NodePool<'brand, T>) {
+        let mut other = TripodTree::singleton_pooled(value, pool, self.token);
+        self.splice_before(&mut other);
+    }
+
+    /// Removes the current element from the tree, recycling its node into the pool.
+    ///
+    /// See `remove_current_as_tree` for details.
+    ///
+    /// No memory allocation nor deallocation occurs.
+    pub fn remove_current_pooled(&mut self, pool: &mut NodePool<'brand, T>) -> Option<T>
+    where
+        T: Default,
+    {
+        let removed = self.remove_current_as_tree();
+        debug_assert!(removed.len(self.token) <= 1, "{} > 1", removed.len(self.token));
+
+        removed.root.map(|root| pool.recycle(TripodTree::<'brand, T, P>::node_into_full(root, self.token)))
     }
 
     /// Removes the current element from the tree and returns it as a `TripodTree`.
@@ -1114,7 +1388,7 @@ impl<'a, 'brand, T> CursorMut<'a, 'brand, T> {
     /// -   Space: O(1).
     ///
     /// No memory allocation nor deallocation occur.
-    pub fn remove_current_as_tree(&mut self) -> TripodTree<'brand, T> {
+    pub fn remove_current_as_tree(&mut self) -> TripodTree<'brand, T, P> {
         //  Short circuit if not interesting.
         if self.node.is_none() {
             return TripodTree::new();
@@ -1149,7 +1423,7 @@ impl<'a, 'brand, T> CursorMut<'a, 'brand, T> {
             }
 
             //  O(log N).
-            self.rebalance_tree_single(parent_tripod);
+            self.rebalance_tree_complete(parent_tripod);
 
             self.move_to(index);
 
@@ -1179,7 +1453,7 @@ impl<'a, 'brand, T> CursorMut<'a, 'brand, T> {
     /// -   Space: O(1).
     ///
     /// No memory allocation nor deallocation occur.
-    pub fn splice_after(&mut self, other: &mut TripodTree<'brand, T>) {
+    pub fn splice_after(&mut self, other: &mut TripodTree<'brand, T, P>) {
         //  We'll be getting back to this index.
         let original = self.index();
 
@@ -1203,7 +1477,7 @@ impl<'a, 'brand, T> CursorMut<'a, 'brand, T> {
     /// -   Space: O(1).
     ///
     /// No memory allocation nor deallocation occur.
-    pub fn splice_before(&mut self, other: &mut TripodTree<'brand, T>) {
+    pub fn splice_before(&mut self, other: &mut TripodTree<'brand, T, P>) {
         //  We'll be getting back to this index.
         let original = self.index();
         let other_size = other.len(self.token);
@@ -1227,7 +1501,7 @@ impl<'a, 'brand, T> CursorMut<'a, 'brand, T> {
     /// -   Space: O(1).
     ///
     /// No memory allocation nor deallocation occur.
-    pub fn split_after(&mut self) -> TripodTree<'brand, T> {
+    pub fn split_after(&mut self) -> TripodTree<'brand, T, P> {
         let result = self.split_impl(Side::Right);
 
         self.move_to_back();
@@ -1243,11 +1517,11 @@ impl<'a, 'brand, T> CursorMut<'a, 'brand, T> {
     ///
     /// #   Complexity
     ///
-    /// -   Time: O(log N) in the number of elements.
+    /// -   Time: O(log² N) in the number of elements.
     /// -   Space: O(1).
     ///
     /// No memory allocation nor deallocation occur.
-    pub fn split_before(&mut self) -> TripodTree<'brand, T> {
+    pub fn split_before(&mut self) -> TripodTree<'brand, T, P> {
         let result = self.split_impl(Side::Left);
 
         self.move_to_front();
@@ -1255,7 +1529,9 @@ impl<'a, 'brand, T> CursorMut<'a, 'brand, T> {
         result
     }
 
-    //  Internal; sift down current index, until it's a leaf, by pushing it alongst the deepest path.
+    //  Internal; sift down current index, until it's a leaf, by rotating it down alongst the deepest path.
+    //
+    //  Rotations preserve the order of the elements, hence the index of the current element is unchanged.
     //
     //  Complexity: Time O(log N), Space O(1).
     fn sift_down(&mut self) {
@@ -1276,16 +1552,17 @@ impl<'a, 'brand, T> CursorMut<'a, 'brand, T> {
                 return;
             }
 
-            //  Going down
+            //  Going down: the deepest child becomes the root of the sub-tree, and current its opposite child.
             let side = if left_size > right_size { Side::Left } else { Side::Right };
-            self.swap_child_from(side, current_tripod);
+            self.rotate_child_from(side, current_tripod);
+            self.move_down(side.opposite());
         }
     }
 
     //  Internal; splice_before/after, without any guarantee with regard to the position of the index.
     //
     //  Complexity: Time O(log² N), Space O(1).
-    fn splice_impl(&mut self, side: Side, other: &mut TripodTree<'brand, T>) {
+    fn splice_impl(&mut self, side: Side, other: &mut TripodTree<'brand, T, P>) {
         self.describe_self("splice_impl (begin)");
 
         let other_root = if let Some(other_root) = other.root.take() {
@@ -1334,7 +1611,7 @@ impl<'a, 'brand, T> CursorMut<'a, 'brand, T> {
     //  Internal; splits the tree into two, taking all elements on the given side into the new tree.
     //
     //  Complexity: Time O(log² N), Space O(1).
-    fn split_impl(&mut self, side: Side) -> TripodTree<'brand, T> {
+    fn split_impl(&mut self, side: Side) -> TripodTree<'brand, T, P> {
         if self.node.is_none() {
             self.index = 0;
             return mem::replace(self.tree, TripodTree::new());
@@ -1346,19 +1623,19 @@ impl<'a, 'brand, T> CursorMut<'a, 'brand, T> {
 
             match side {
                 Side::Left if self.range().start == 0 => {
-                    let result = TripodTree { root: Self::take_child(side, node, self.token) };
+                    let result = TripodTree { root: Self::take_child(side, node, self.token), policy: PhantomData, };
                     self.index = 0;
 
                     let current_tripod = self.node.take().expect("Non-empty");
-                    self.rebalance_tree_single(current_tripod);
+                    self.rebalance_tree_complete(current_tripod);
 
                     return result;
                 },
                 Side::Right if self.range().end == self.len() => {
-                    let result = TripodTree { root: Self::take_child(side, node, self.token) };
+                    let result = TripodTree { root: Self::take_child(side, node, self.token), policy: PhantomData, };
 
                     let current_tripod = self.node.take().expect("Non-empty");
-                    self.rebalance_tree_single(current_tripod);
+                    self.rebalance_tree_complete(current_tripod);
 
                     return result;
                 },
@@ -1393,6 +1670,8 @@ impl<'a, 'brand, T> CursorMut<'a, 'brand, T> {
             self.rebalance_child(Side::Left, &parent);
             self.rebalance_child(Side::Right, &parent);
 
+            self.adjust_size(&parent);
+
             self.node = Some(parent);
 
             self.describe_self("split_impl (post incremental rotation)");
@@ -1404,7 +1683,7 @@ impl<'a, 'brand, T> CursorMut<'a, 'brand, T> {
         let result = {
             let node = self.node.as_ref().expect("Non-empty");
 
-            TripodTree { root: Self::take_child(side, node, self.token) }
+            TripodTree { root: Self::take_child(side, node, self.token), policy: PhantomData, }
         };
 
         if side == Side::Left {
@@ -1444,7 +1723,7 @@ impl<'a, 'brand, T> CursorMut<'a, 'brand, T> {
 
         self.retract_tripod(other_tripod);
 
-        root_tripod.borrow_mut(self.token).size += other_size;
+        self.adjust_size(&root_tripod);
 
         if side == Side::Left {
             self.index += other_size;
@@ -1478,54 +1757,61 @@ impl<'a, 'brand, T> CursorMut<'a, 'brand, T> {
         self.describe_self("rebalance_tree_complete (end)");
     }
 
-    //  Internal; rebalances the tree up to the root, of at most 1 single step, adjusting the node size as it goes.
+    //  Internal; rebalances the current sub-tree, if necessary.
     //
-    //  The cursor is left pointing to the root. The index is adjusted accordingly.
+    //  Each rotation may unbalance the nodes it demotes, hence after each rotation both children of the new root are
+    //  themselves rebalanced, left then right, prior to checking the new root once more. This is a depth-first walk,
+    //  using the up pointers to climb back rather than a stack.
+    //
+    //  The cursor is left pointing at the root of the sub-tree, whether it changed or not. The index is adjusted
+    //  accordingly.
     //
     //  Complexity: Time O(log N), Space O(1).
-    fn rebalance_tree_single(&mut self, root_tripod: QuarterNodePtr<'brand, T>) {
-        self.describe_node("rebalance_tree_single (begin)", &root_tripod);
+    fn rebalance_subtree_complete(&mut self, root_tripod: QuarterNodePtr<'brand, T>) {
+        self.describe_node("rebalance_subtree_complete (begin)", &root_tripod);
 
-        self.rebalance_subtree_single(root_tripod);
+        self.node = Some(root_tripod);
 
-        while let Some(_) = self.try_move_up() {
-            self.describe_self("rebalance_tree_single (loop)");
+        //  The depth of the current node, relative to the root of the sub-tree.
+        let mut depth = 0;
 
-            let root_tripod = self.node.take().expect("Not empty");
+        loop {
+            let current_tripod = self.node.take().expect("Non-empty");
 
-            self.adjust_size(&root_tripod);
-            self.rebalance_subtree_single(root_tripod)
-        }
+            //  Unbalanced: rotate, then rebalance the children of the new root, starting from the left.
+            if self.rotation(&current_tripod).is_some() {
+                self.rebalance_subtree_single(current_tripod);
 
-        self.describe_self("rebalance_tree_single (end)");
-    }
+                self.describe_self("rebalance_subtree_complete (rotated)");
 
-    //  Internal; rebalances the current sub-tree, if necessary.
-    //
-    //  The cursor is left pointing at the root of the sub-tree, whether it changed or not. The index is adjusted
-    //  accordingly.
-    //
-    //  Complexity: Time O(log N), Space O(1).
-    fn rebalance_subtree_complete(&mut self, mut root_tripod: QuarterNodePtr<'brand, T>) {
-        self.describe_node("rebalance_subtree_complete (begin)", &root_tripod);
+                if self.try_move_left().is_some() || self.try_move_right().is_some() {
+                    depth += 1;
+                }
 
-        let mut previous_index = self.index;
+                continue;
+            }
 
-        loop {
-            self.rebalance_subtree_single(root_tripod);
+            self.node = Some(current_tripod);
 
-            if previous_index == self.index {
+            //  Balanced, and so are its children: done if back at the root of the sub-tree.
+            if depth == 0 {
                 break;
             }
 
-            previous_index = self.index;
+            //  Otherwise, climb back up, and rebalance the right sibling, or re-check the parent.
+            let side = self.node.as_ref().and_then(|node| node.borrow(self.token).is_child(self.token)).expect("Child!");
 
-            root_tripod = self.node.take().expect("Non-empty");
+            self.move_up();
+            depth -= 1;
 
-            self.rebalance_child(Side::Left, &root_tripod);
-            self.rebalance_child(Side::Right, &root_tripod);
+            //  The rotations below the parent may have changed the rank of the child, hence of the parent.
+            let parent_tripod = self.node.take().expect("Non-empty");
+            self.adjust_size(&parent_tripod);
+            self.node = Some(parent_tripod);
 
-            self.describe_node("rebalance_subtree_complete (loop)", &root_tripod);
+            if side == Side::Left && self.try_move_right().is_some() {
+                depth += 1;
+            }
         }
 
         self.describe_self("rebalance_subtree_complete (end)");
@@ -1540,60 +1826,71 @@ impl<'a, 'brand, T> CursorMut<'a, 'brand, T> {
     fn rebalance_subtree_single(&mut self, root_tripod: QuarterNodePtr<'brand, T>) {
         debug_assert!(self.node.is_none());
 
-        let left_size = root_tripod.borrow(self.token).left_size(self.token);
-        let right_size = root_tripod.borrow(self.token).right_size(self.token);
-
-        if left_size > 2 * right_size + 1 {
-            let root_tripod = self.prepare_rotation(Side::Left, root_tripod);
-            self.rotate_child_from(Side::Left, root_tripod);
-        } else if right_size > 2 * left_size + 1 {
-            let root_tripod = self.prepare_rotation(Side::Right, root_tripod);
-            self.rotate_child_from(Side::Right, root_tripod);
+        if let Some(side) = self.rotation(&root_tripod) {
+            let root_tripod = self.prepare_rotation(side, root_tripod);
+            self.rotate_child_from(side, root_tripod);
         } else {
             self.node = Some(root_tripod);
         }
     }
 
-    //  Internal; rebalances the parent's child on the designated side.
+    //  Internal; returns the side of the child to rotate in place of the node, if the node is unbalanced.
     //
     //  Complexity: Time O(1), Space O(1).
+    fn rotation(&self, node: &GhostNode<'brand, T>) -> Option<Side> {
+        let node = node.borrow(self.token);
+        let left = node.child_summary(Side::Left, self.token);
+        let right = node.child_summary(Side::Right, self.token);
+
+        P::rotation(&node.summary(), left.as_ref(), right.as_ref())
+    }
+
+    //  Internal; rebalances the parent's child on the designated side, completely.
+    //
+    //  Complexity: Time O(log N), Space O(1).
     fn rebalance_child(&mut self, side: Side, parent: &GhostNode<'brand, T>) {
-        if let Some(child) = parent.borrow_mut(self.token).take_child(side) {
-            let child_tripod = child.borrow(self.token).deploy();
-            let child_index = child_tripod.borrow(self.token).index(self.token);
-            let parent_from_child = child_tripod.borrow_mut(self.token).up.take();
+        debug_assert!(self.node.is_none());
+
+        let child_tripod = if let Some(child) = parent.borrow(self.token).child(side) {
+            self.deploy_tripod(child)
+        } else {
+            return;
+        };
 
-            let mut tree = TripodTree { root: Some(child) };
+        //  The index of the parent, to be restored.
+        let parent_index = self.index;
 
-            let child_tripod = {
-                let mut cursor = CursorMut { token: self.token, tree: &mut tree, node: None, index: child_index };
-                cursor.rebalance_subtree_single(child_tripod);
-                cursor.node.take().expect("Non-empty")
-            };
+        self.index = {
+            let opposite_size = child_tripod.borrow(self.token).child_size(side.opposite(), self.token);
+
+            match side {
+                Side::Left => parent_index - 1 - opposite_size,
+                Side::Right => parent_index + 1 + opposite_size,
+            }
+        };
 
-            let child = tree.root.take().expect("Non-empty");
+        self.rebalance_subtree_complete(child_tripod);
 
-            child.borrow(self.token).retract(child_tripod);
-            child.borrow_mut(self.token).up = parent_from_child;
+        let child_tripod = self.node.take().expect("Non-empty");
+        self.retract_tripod(child_tripod);
 
-            parent.borrow_mut(self.token).set_child(side, child);
-        }
+        self.index = parent_index;
     }
 
-    //  Internal; swaps the current root of the sub-tree with its child.
+    //  Internal; rotates the current sub-tree so that the selected child becomes the root.
     //
-    //  The cursor is left pointing at the former root, the index is adjusted accordingly.
+    //  The cursor is left pointing at the new root of the sub-tree (pivot), the index is adjusted accordingly.
     //
     //  Invoked with Side::Left:
     //
     //          Pa                  Pa
     //          |                   |
-    //        Root                 Piv
+    //        Root                  Piv
     //         / \                 / \
     //        /   \               /   \
-    //      Piv    X    =>     Root    X
-    //      / \                / \
-    //     Y   Z              Y   Z
+    //      Piv   X     =>       Y     Root
+    //      / \                        / \
+    //     Y   Piv.OS            Piv.OS   X
     //
     //  Invoked with Side::Right:
     //
@@ -1602,213 +1899,276 @@ impl<'a, 'brand, T> CursorMut<'a, 'brand, T> {
     //        Root                 Piv
     //         / \                 / \
     //        /   \               /   \
-    //       X   Piv   =>        X   Root
-    //           / \                 / \
-    //          Y   Z               Y   Z
+    //       X   Piv     =>    Root    Y
+    //           / \           / \
+    //     Piv.OS   Y         X   Piv.OS
     //
     //  Legend:
     //  -   Pa: Parent, potentially tree.root.
     //  -   Root: the current root of the sub-tree.
     //  -   Piv: the pivot, or future root of the sub-tree post-rotation.
+    //  -   Piv.OS: the child of the pivot, on the opposite side.
     //
     //  Complexity: Time O(1), Space O(1).
-    fn swap_child_from(&mut self, side: Side, root_tripod: QuarterNodePtr<'brand, T>) {
+    fn rotate_child_from(&mut self, side: Side, root_tripod: QuarterNodePtr<'brand, T>) {
         debug_assert!(self.node.is_none());
 
-        self.describe_node("swap_child_from (begin)", &root_tripod);
+        self.describe_node("rotate_child_from (begin)", &root_tripod);
 
         let opposite = side.opposite();
 
         //  Pick out pivot.
-        let pivot = root_tripod.borrow_mut(self.token).take_child(side).expect("Selected child - otherwise we shouldn't attempt to swap");
+        let pivot = root_tripod.borrow_mut(self.token).take_child(side).expect("Selected child - otherwise we shouldn't attempt to rotate");
         let pivot_tripod = self.deploy_tripod(&pivot);
 
-        self.describe_node("swap_child_from (pivot)", &pivot_tripod);
+        let root_from_pivot = pivot_tripod.borrow_mut(self.token).up.take().expect("Parent - root!");
+        debug_assert!(root_tripod.borrow(self.token).is_aliased(Some(&root_from_pivot)), "root == pivot.up");
 
-        //  Swap opposite children, if any.
-        {
-            let opposite_root = root_tripod.borrow_mut(self.token).take_child(opposite);
-            let opposite_pivot = pivot_tripod.borrow_mut(self.token).take_child(opposite);
+        //  Extract pivot pointer from opposite-child up.
+        let pivot_from_opposite_child = {
+            let opposite_child = pivot_tripod.borrow_mut(self.token).child_mut(opposite).take().expect("Either child or pivot-self");
 
-            match (opposite_root, opposite_pivot) {
-                (Some(opposite_root), Some(opposite_pivot)) => {
-                    let root_from_opposite = opposite_root.borrow_mut(self.token).up.take();
-                    let pivot_from_opposite = opposite_pivot.borrow_mut(self.token).up.take();
+            //  Aliased => actual self-points to pivot, which didn't have an opposite-side child.
+            let (pivot, child) = if pivot_tripod.borrow(self.token).is_aliased(Some(&opposite_child)) {
+                (opposite_child, root_from_pivot)
+            } else {
+                let pivot = opposite_child.borrow_mut(self.token).up.replace(root_from_pivot).expect("opposite_child.up == pivot");
 
-                    opposite_pivot.borrow_mut(self.token).up = root_from_opposite;
-                    opposite_root.borrow_mut(self.token).up = pivot_from_opposite;
+                (pivot, opposite_child)
+            };
 
-                    root_tripod.borrow_mut(self.token).set_child(opposite, opposite_pivot);
-                    pivot_tripod.borrow_mut(self.token).set_child(opposite, opposite_root);
-                },
-                (None, Some(opposite_pivot)) => {
-                    let root_from_self = root_tripod.borrow_mut(self.token).child_mut(opposite).take().expect("root.opposite == root");
-                    let pivot_from_opposite = opposite_pivot.borrow_mut(self.token).up.replace(root_from_self).expect("pivot.opposite.up == pivot");
+            debug_assert!(pivot.borrow(self.token).is_aliased(Some(&pivot_tripod)), "pivot.opposite_child.up == pivot");
 
-                    root_tripod.borrow_mut(self.token).set_child(opposite, opposite_pivot);
-                    pivot_tripod.borrow_mut(self.token).set_child(opposite, pivot_from_opposite);
-                },
-                (Some(opposite_root), None) => {
-                    let pivot_from_self = pivot_tripod.borrow_mut(self.token).child_mut(opposite).take().expect("pivot.opposite == pivot");
-                    let root_from_opposite = opposite_root.borrow_mut(self.token).up.replace(pivot_from_self).expect("root.opposite.up == root");
+            root_tripod.borrow_mut(self.token).set_child(side, child);
 
-                    pivot_tripod.borrow_mut(self.token).set_child(opposite, opposite_root);
-                    root_tripod.borrow_mut(self.token).set_child(opposite, root_from_opposite);
-                },
-                (None, None) => (),
-            }
+            pivot
         };
 
-        //  Swap parent.
-        let root_from_pivot = pivot_tripod.borrow_mut(self.token).up.take().expect("pivot.up == root");
-
+        //  Switch pointer-to-root to pointer-to-pivot in parent.
         let root_from_parent = {
-            let parent_root = root_tripod.borrow_mut(self.token).up.take();
+            let parent = root_tripod.borrow_mut(self.token).up.replace(pivot);
 
-            if let Some(parent_root) = parent_root {
-                let parent_side = root_tripod.borrow(self.token).is_child_of(parent_root.borrow(self.token)).expect("Child!");
+            if let Some(parent) = parent {
+                let parent_side = root_tripod.borrow(self.token).is_child_of(parent.borrow(self.token)).expect("root.up == parent!");
 
-                let root_from_parent = parent_root.borrow_mut(self.token).replace_child(parent_side, pivot).expect("parent.child == root");
-                pivot_tripod.borrow_mut(self.token).up = Some(parent_root);
+                let result = parent.borrow_mut(self.token).replace_child(parent_side, pivot_from_opposite_child)
+                    .expect("parent.parent_side_child == root");
+
+                pivot_tripod.borrow_mut(self.token).up = Some(parent);
 
-                root_from_parent
+                result
             } else {
-                //  root_tripod is root.
-                self.tree.root.replace(pivot).expect("root")
+                self.tree.root.replace(pivot_from_opposite_child).expect("tree.root == root")
             }
         };
 
-        //  Swap selected child and parent, if any.
-        {
-            let child_pivot = pivot_tripod.borrow_mut(self.token).take_child(side);
-
-            if let Some(child_pivot) = child_pivot {
-                pivot_tripod.borrow_mut(self.token).set_child(side, root_from_parent);
-
-                let pivot_from_child = child_pivot.borrow_mut(self.token).up.replace(root_from_pivot).expect("child.up == pivot");
-                root_tripod.borrow_mut(self.token).set_child(side, pivot_from_child);
-            } else {
-                //  Pivot.side pointing to self.
-                let pivot_from_self = pivot_tripod.borrow_mut(self.token).replace_child(side, root_from_parent);
+        debug_assert!(root_tripod.borrow(self.token).is_aliased(Some(&root_from_parent)), "root == pivot.up");
 
-                root_tripod.borrow_mut(self.token).up = pivot_from_self;
-                root_tripod.borrow_mut(self.token).set_child(side, root_from_pivot);
-            }
-        }
+        pivot_tripod.borrow_mut(self.token).set_child(opposite, root_from_parent);
 
         self.adjust_size(&root_tripod);
         self.adjust_size(&pivot_tripod);
 
-        self.retract_tripod(pivot_tripod);
+        let index = {
+            //  Piv.OS size.
+            let opposite_size = root_tripod.borrow(self.token).child_size(side, self.token);
 
-        let new_index = {
-            let opposite_pivot_size = root_tripod.borrow(self.token).child_size(opposite, self.token);
             match side {
-                Side::Left => self.index - 1 - opposite_pivot_size,
-                Side::Right => self.index + 1 + opposite_pivot_size,
+                Side::Left => self.index - 1 - opposite_size,
+                Side::Right => self.index + 1 + opposite_size,
             }
         };
 
-        self.switch_tripod(Some(root_tripod), new_index);
+        self.retract_tripod(root_tripod);
+
+        self.switch_tripod(Some(pivot_tripod), index);
 
-        self.describe_self("swap_child_from (end)");
+        self.describe_self("rotate_child_from (end)");
     }
 
-    //  Internal; rotates the current sub-tree so that the selected child becomes the root.
+    //  Internal; prepare the side child for promotion to root.
     //
-    //  The cursor is left pointing at the new root of the sub-tree (pivot), the index is adjusted accordingly.
+    //  The cursor is not pointing to any element, as the root_tripod is returned. The index is unmodified.
     //
-    //  Invoked with Side::Left:
+    //  During the rotation, the pivot opposite side child is moved to be the side child of the root. If this child is
+    //  deeper than its sibling, the result is not balanced:
     //
-    //          Pa                  Pa
-    //          |                   |
     //        Root                  Piv
     //         / \                 / \
     //        /   \               /   \
     //      Piv   X     =>       Y     Root
     //      / \                        / \
     //     Y   Piv.OS            Piv.OS   X
+    //          \                    \
+    //          A                    A
     //
-    //  Invoked with Side::Right:
-    //
-    //          Pa                  Pa
-    //          |                   |
-    //        Root                 Piv
-    //         / \                 / \
-    //        /   \               /   \
-    //       X   Piv     =>    Root    Y
-    //           / \           / \
-    //     Piv.OS   Y         X   Piv.OS
-    //
-    //  Legend:
-    //  -   Pa: Parent, potentially tree.root.
-    //  -   Root: the current root of the sub-tree.
-    //  -   Piv: the pivot, or future root of the sub-tree post-rotation.
-    //  -   Piv.OS: the child of the pivot, on the opposite side.
+    //  Hence, if necessary, a rotation must be performed to ensure that the pivot "side" child is the root of the
+    //  deepest sub-tree prior to doing the main rotation.
     //
     //  Complexity: Time O(1), Space O(1).
-    fn rotate_child_from(&mut self, side: Side, root_tripod: QuarterNodePtr<'brand, T>) {
+    fn prepare_rotation(&mut self, side: Side, root_tripod: QuarterNodePtr<'brand, T>) -> QuarterNodePtr<'brand, T> {
         debug_assert!(self.node.is_none());
 
-        self.describe_node("rotate_child_from (begin)", &root_tripod);
+        self.describe_node("prepare_rotation (begin)", &root_tripod);
 
-        let opposite = side.opposite();
+        let _original_index = self.index();
+        let _original_address = &*root_tripod as *const _;
 
-        //  Pick out pivot.
-        let pivot = root_tripod.borrow_mut(self.token).take_child(side).expect("Selected child - otherwise we shouldn't attempt to rotate");
-        let pivot_tripod = self.deploy_tripod(&pivot);
+        let double_rotation = {
+            let pivot_node = root_tripod.borrow(self.token).child(side).expect("Pivot!").borrow(self.token);
+            let outer = pivot_node.child_summary(side, self.token);
+            let inner = pivot_node.child_summary(side.opposite(), self.token);
 
-        let root_from_pivot = pivot_tripod.borrow_mut(self.token).up.take().expect("Parent - root!");
-        debug_assert!(root_tripod.borrow(self.token).is_aliased(Some(&root_from_pivot)), "root == pivot.up");
+            P::double_rotation(inner.as_ref(), outer.as_ref())
+        };
 
-        //  Extract pivot pointer from opposite-child up.
-        let pivot_from_opposite_child = {
-            let opposite_child = pivot_tripod.borrow_mut(self.token).child_mut(opposite).take().expect("Either child or pivot-self");
+        if double_rotation {
+            self.node = Some(root_tripod);
 
-            //  Aliased => actual self-points to pivot, which didn't have an opposite-side child.
-            let (pivot, child) = if pivot_tripod.borrow(self.token).is_aliased(Some(&opposite_child)) {
-                (opposite_child, root_from_pivot)
-            } else {
-                let pivot = opposite_child.borrow_mut(self.token).up.replace(root_from_pivot).expect("opposite_child.up == pivot");
+            //  Move to pivot.
+            match side {
+                Side::Left => self.move_left(),
+                Side::Right => self.move_right(),
+            }
 
-                (pivot, opposite_child)
-            };
+            let pivot_tripod = self.node.take().expect("Pivot");
 
-            debug_assert!(pivot.borrow(self.token).is_aliased(Some(&pivot_tripod)), "pivot.opposite_child.up == pivot");
+            self.rotate_child_from(side.opposite(), pivot_tripod);
+            self.move_up();
 
-            root_tripod.borrow_mut(self.token).set_child(side, child);
+            let root_tripod = self.node.take().expect("Root");
 
-            pivot
+            debug_assert_eq!(_original_index, self.index());
+            debug_assert_eq!(_original_address, &*root_tripod as *const _);
+
+            self.describe_node("prepare_rotation (end) (modified)", &root_tripod);
+
+            root_tripod
+        } else {
+            self.describe_node("prepare_rotation (end) (passthrough)", &root_tripod);
+
+            root_tripod
+        }
+    }
+
+    //  Internal; adjusts the size of the node by adding up the size of its children, and its rank.
+    //
+    //  Complexity: Time O(1), Space O(1).
+    fn adjust_size(&mut self, node: &GhostNode<'brand, T>) {
+        TripodTree::<'brand, T, P>::adjust_node(node, self.token);
+
+        self.describe_node("adjust_size (adjusted)", node);
+    }
+
+    //  Internal; pops of the specified child, if any, adjusting size and pointers.
+    //
+    //  Complexity: Time O(1), Space O(1).
+    fn take_child(side: Side, node: &GhostNode<'brand, T>, token: &mut GhostToken<'brand>) -> Option<QuarterNodePtr<'brand, T>> {
+        let child = node.borrow_mut(token).take_child(side)?;
+
+        let node_from_child = child.borrow_mut(token).up.take().expect("child.up == node");
+        node.borrow_mut(token).set_child(side, node_from_child);
+
+        TripodTree::<'brand, T, P>::adjust_node(node, token);
+
+        Some(child)
+    }
+}
+
+//  Structural edit cursor operations.
+//
+//  Unlike the edit cursor operations above, the structural edits never rebalance the tree: they leave the tree in
+//  exactly the shape requested, sizes and links included, so that domain specific trees may be built on top. Restoring
+//  the balance, if desired, is an explicit call to `rebalance`.
+impl<'a, 'brand, T, P: BalancePolicy> CursorMut<'a, 'brand, T, P> {
+    /// Rotates the child on the given `side` of the current element into its place, the current element becoming the
+    /// child of this former child, on the opposite side.
+    ///
+    /// The order of the elements is preserved. The cursor follows the promoted child, pointing to it afterwards.
+    ///
+    /// If the cursor points to the "twilight" non-element, or the current element has no child on the given `side`,
+    /// nothing happens.
+    ///
+    /// #   Balance
+    ///
+    /// The tree is not rebalanced, neither now nor when the cursor is dropped. To restore the balance required by the
+    /// policy, `rebalance` the edited elements, from the deepest up, before any other edit.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(D) in the depth of the current element, as the ranks of its ancestors are adjusted, and O(1) for the
+    ///     policies whose ranks do not depend on the shape of the tree: `WeightBalanced`, `Treap` and `Unbalanced`.
+    /// -   Space: O(1).
+    ///
+    /// Restoring the balance afterwards, if desired, costs one call to `rebalance` on the demoted element.
+    pub fn rotate(&mut self, side: Side) {
+        let current_tripod = if let Some(current_tripod) = self.node.take() {
+            current_tripod
+        } else {
+            return;
         };
 
-        //  Switch pointer-to-root to pointer-to-pivot in parent.
-        let root_from_parent = {
-            let parent = root_tripod.borrow_mut(self.token).up.replace(pivot);
+        if current_tripod.borrow(self.token).child(side).is_none() {
+            self.node = Some(current_tripod);
+            return;
+        }
 
-            if let Some(parent) = parent {
-                let parent_side = root_tripod.borrow(self.token).is_child_of(parent.borrow(self.token)).expect("root.up == parent!");
+        self.unbalanced = true;
 
-                let result = parent.borrow_mut(self.token).replace_child(parent_side, pivot_from_opposite_child)
-                    .expect("parent.parent_side_child == root");
+        self.rotate_child_from(side, current_tripod);
 
-                pivot_tripod.borrow_mut(self.token).up = Some(parent);
+        //  The sizes of the ancestors are unchanged, but not necessarily their ranks, such as heights.
+        self.adjust_ancestors_rank();
+    }
 
-                result
-            } else {
-                self.tree.root.replace(pivot_from_opposite_child).expect("tree.root == root")
-            }
+    /// Swaps the current element with its child on the given `side`, leaving the shape of the tree untouched.
+    ///
+    /// The two elements exchange their positions in the tree, and thus their indices, all other elements being
+    /// unaffected. The cursor follows the current element, pointing to it, in its new position, afterwards.
+    ///
+    /// If the cursor points to the "twilight" non-element, or the current element has no child on the given `side`,
+    /// nothing happens.
+    ///
+    /// #   Balance
+    ///
+    /// As the shape of the tree is untouched, so is its balance.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(1).
+    /// -   Space: O(1).
+    pub fn swap_with_child(&mut self, side: Side) {
+        let current_tripod = if let Some(current_tripod) = self.node.take() {
+            current_tripod
+        } else {
+            return;
         };
 
-        debug_assert!(root_tripod.borrow(self.token).is_aliased(Some(&root_from_parent)), "root == pivot.up");
+        let child = if let Some(child) = Self::take_child(side, &current_tripod, self.token) {
+            child
+        } else {
+            self.node = Some(current_tripod);
+            return;
+        };
 
-        pivot_tripod.borrow_mut(self.token).set_child(opposite, root_from_parent);
+        let opposite = side.opposite();
 
-        self.adjust_size(&root_tripod);
-        self.adjust_size(&pivot_tripod);
+        //  Strip both current and child of their other children, leaving current linked to its parent.
+        let child_tripod = self.deploy_tripod(&child);
+
+        let child_side = Self::take_child(side, &child_tripod, self.token);
+        let child_opposite = Self::take_child(opposite, &child_tripod, self.token);
+        let current_opposite = Self::take_child(opposite, &current_tripod, self.token);
+
+        //  Exchange the ranks, alongside the positions, so that the ranks of a `Treap` stay where they were.
+        {
+            let current_rank = current_tripod.borrow(self.token).rank;
+            let child_rank = mem::replace(&mut child_tripod.borrow_mut(self.token).rank, current_rank);
+            current_tripod.borrow_mut(self.token).rank = child_rank;
+        }
 
         let index = {
-            //  Piv.OS size.
-            let opposite_size = root_tripod.borrow(self.token).child_size(side, self.token);
+            let opposite_size = child_opposite.as_ref().map(|node| node.borrow(self.token).size).unwrap_or(0);
 
             match side {
                 Side::Left => self.index - 1 - opposite_size,
@@ -1816,109 +2176,508 @@ impl<'a, 'brand, T> CursorMut<'a, 'brand, T> {
             }
         };
 
-        self.retract_tripod(root_tripod);
+        //  Substitute child for current in the parent, or root.
+        let current = if let Some(parent) = current_tripod.borrow_mut(self.token).up.take() {
+            let parent_side = current_tripod.borrow(self.token).is_child_of(parent.borrow(self.token)).expect("current.up == parent");
 
-        self.switch_tripod(Some(pivot_tripod), index);
+            let current = parent.borrow_mut(self.token).replace_child(parent_side, child).expect("parent.parent_side_child == current");
+            child_tripod.borrow_mut(self.token).up = Some(parent);
 
-        self.describe_self("rotate_child_from (end)");
+            current
+        } else {
+            self.tree.root.replace(child).expect("tree.root == current")
+        };
+
+        //  Reattach, bottom-up, so that sizes are accumulated correctly.
+        if let Some(child_side) = child_side {
+            Self::put_child(side, &current_tripod, child_side, self.token);
+        }
+
+        if let Some(child_opposite) = child_opposite {
+            Self::put_child(opposite, &current_tripod, child_opposite, self.token);
+        }
+
+        Self::put_child(side, &child_tripod, current, self.token);
+
+        if let Some(current_opposite) = current_opposite {
+            Self::put_child(opposite, &child_tripod, current_opposite, self.token);
+        }
+
+        self.retract_tripod(child_tripod);
+
+        self.node = Some(current_tripod);
+        self.index = index;
+
+        self.adjust_ancestors_rank();
     }
 
-    //  Internal; prepare the side child for promotion to root.
+    /// Detaches the sub-tree rooted at the child on the given `side` of the current element, and returns it.
+    ///
+    /// The sizes of the current element and all its ancestors are adjusted accordingly. The cursor still points to the
+    /// current element afterwards, whose index is adjusted if `side` is `Left`.
+    ///
+    /// If the cursor points to the "twilight" non-element, or the current element has no child on the given `side`,
+    /// nothing happens and an empty tree is returned.
+    ///
+    /// #   Balance
+    ///
+    /// The tree is not rebalanced, neither now nor when the cursor is dropped. To restore the balance required by the
+    /// policy, `rebalance` the edited elements, from the deepest up, before any other edit.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(D) in the depth of the current element, as the sizes of its ancestors are adjusted.
+    /// -   Space: O(1).
+    ///
+    /// Restoring the balance afterwards, if desired, costs one call to `rebalance` on the current element.
+    pub fn detach_child(&mut self, side: Side) -> TripodTree<'brand, T, P> {
+        let child = if let Some(current) = self.node.as_ref() {
+            Self::take_child(side, current, self.token)
+        } else {
+            None
+        };
+
+        let child = if let Some(child) = child {
+            child
+        } else {
+            return TripodTree::new();
+        };
+
+        self.unbalanced = true;
+
+        if side == Side::Left {
+            self.index -= child.borrow(self.token).size;
+        }
+
+        self.adjust_ancestors_size();
+
+        TripodTree { root: Some(child), policy: PhantomData, }
+    }
+
+    /// Attaches the `other` tree as the child on the given `side` of the current element.
+    ///
+    /// The sizes of the current element and all its ancestors are adjusted accordingly. The cursor still points to the
+    /// current element afterwards, whose index is adjusted if `side` is `Left`.
+    ///
+    /// If `other` is empty, nothing happens.
+    ///
+    /// #   Balance
+    ///
+    /// The tree is not rebalanced, neither now nor when the cursor is dropped. To restore the balance required by the
+    /// policy, `rebalance` the edited elements, from the deepest up, before any other edit.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(D) in the depth of the current element, as the sizes of its ancestors are adjusted.
+    /// -   Space: O(1).
+    ///
+    /// Restoring the balance afterwards, if desired, costs one call to `rebalance` on the current element.
+    ///
+    /// #   Panics
+    ///
+    /// If `other` is not empty, and the cursor points to the "twilight" non-element or the current element already has
+    /// a child on the given `side`.
+    pub fn attach_child(&mut self, side: Side, other: TripodTree<'brand, T, P>) {
+        let mut other = other;
+
+        let other_root = if let Some(other_root) = other.root.take() {
+            other_root
+        } else {
+            return;
+        };
+
+        let current = self.node.as_ref().expect("Non-twilight - to attach a child to");
+        assert!(current.borrow(self.token).child(side).is_none(), "No {:?} child - to attach a child in place of", side);
+
+        self.unbalanced = true;
+
+        if side == Side::Left {
+            self.index += other_root.borrow(self.token).size;
+        }
+
+        Self::put_child(side, current, other_root, self.token);
+
+        self.adjust_ancestors_size();
+    }
+
+    /// Rebalances the current element and its ancestors, up to the root.
+    ///
+    /// Only the current element, its ancestors, and the elements demoted by the rebalancing rotations are rebalanced,
+    /// hence to rebalance the tree after a series of structural edits, each edited element should be rebalanced in
+    /// turn, from the deepest up.
+    ///
+    /// The order of the elements is preserved. The cursor still points to the current element afterwards.
+    ///
+    /// If the cursor points to the "twilight" non-element, nothing happens.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(log² N) in the number of elements, if the sub-trees of the current element were balanced.
+    /// -   Space: O(1).
+    pub fn rebalance(&mut self) {
+        let current_tripod = if let Some(current_tripod) = self.node.take() {
+            current_tripod
+        } else {
+            return;
+        };
+
+        let index = self.index;
+
+        self.rebalance_tree_complete(current_tripod);
+        self.move_to(index);
+    }
+
+    //  Internal; adjusts the size of all ancestors of the current element, up to the root.
     //
-    //  The cursor is not pointing to any element, as the root_tripod is returned. The index is unmodified.
+    //  The cursor is left pointing to the current element, whose index must be correct.
     //
-    //  During the rotation, the pivot opposite side child is moved to be the side child of the root. If this child is
-    //  deeper than its sibling, the result is not balanced:
+    //  Complexity: Time O(log N), Space O(1).
+    fn adjust_ancestors_size(&mut self) {
+        let index = self.index;
+
+        while self.try_move_up().is_some() {
+            let current_tripod = self.node.take().expect("Non-empty");
+            self.adjust_size(&current_tripod);
+            self.node = Some(current_tripod);
+        }
+
+        self.move_to(index);
+    }
+
+    //  Internal; adjusts the rank of the ancestors of the current element, up to the first whose rank is unchanged, as the
+    //  ranks of its own ancestors are then unchanged too.
     //
-    //        Root                  Piv
-    //         / \                 / \
-    //        /   \               /   \
-    //      Piv   X     =>       Y     Root
-    //      / \                        / \
-    //     Y   Piv.OS            Piv.OS   X
-    //          \                    \
-    //          A                    A
+    //  Requirement: the sizes of the ancestors must be correct, and so must the size and rank of the current element.
     //
-    //  Hence, if necessary, a rotation must be performed to ensure that the pivot "side" child is the root of the
-    //  deepest sub-tree prior to doing the main rotation.
+    //  The cursor is left pointing to the current element.
+    //
+    //  Complexity: Time O(D) in the depth of the current element, O(1) if the ranks do not depend on the shape of the
+    //  tree, Space O(1).
+    fn adjust_ancestors_rank(&mut self) {
+        let index = self.index;
+        let mut depth = 0;
+
+        while self.try_move_up().is_some() {
+            depth += 1;
+
+            let current_tripod = self.node.take().expect("Non-empty");
+
+            let rank = current_tripod.borrow(self.token).rank;
+            self.adjust_size(&current_tripod);
+            let unchanged = current_tripod.borrow(self.token).rank == rank;
+
+            self.node = Some(current_tripod);
+
+            if unchanged {
+                break;
+            }
+        }
+
+        //  Back down, along the path just climbed.
+        for _ in 0..depth {
+            if index < self.index {
+                self.move_left();
+            } else {
+                self.move_right();
+            }
+        }
+
+        debug_assert_eq!(index, self.index);
+    }
+
+    //  Internal; pushes the specified child, a root, adjusting size and pointers.
+    //
+    //  Requirement: there must be no such child.
     //
     //  Complexity: Time O(1), Space O(1).
-    fn prepare_rotation(&mut self, side: Side, root_tripod: QuarterNodePtr<'brand, T>) -> QuarterNodePtr<'brand, T> {
+    fn put_child(side: Side, node: &GhostNode<'brand, T>, child: QuarterNodePtr<'brand, T>, token: &mut GhostToken<'brand>) {
+        debug_assert!(node.borrow(token).child(side).is_none());
+        debug_assert!(child.borrow(token).up.is_none());
+
+        let node_from_child = node.borrow_mut(token).child_mut(side).take().expect("Side child - pointing to self");
+        child.borrow_mut(token).up = Some(node_from_child);
+
+        node.borrow_mut(token).set_child(side, child);
+
+        TripodTree::<'brand, T, P>::adjust_node(node, token);
+    }
+}
+
+//  Bulk construction.
+impl<'a, 'brand, T, P: BalancePolicy> CursorMut<'a, 'brand, T, P> {
+    //  Internal; balances a left "vine", that is a tree in which no node has a right child, into a complete tree.
+    //
+    //  This is the Day-Stout-Warren algorithm, mirrored: the vine is first compressed so that the number of nodes left
+    //  in the spine is a power of 2 minus 1, then compressed repeatedly, halving the length of the spine each time.
+    //
+    //  The cursor is left pointing to the root.
+    //
+    //  Complexity: Time O(N), expected for a `Treap`, see `rebalance_bottom_up`, Space O(1).
+    pub(super) fn balance_vine(&mut self) {
+        let length = self.len();
+
+        if length == 0 {
+            return;
+        }
+
+        //  The size of the largest perfect tree with no more than `length` elements.
+        let mut spine = usize::MAX >> ((length + 1).leading_zeros() + 1);
+
+        self.compress_vine(length - spine);
+
+        while spine > 1 {
+            spine /= 2;
+            self.compress_vine(spine);
+        }
+
+        self.rebalance_bottom_up();
+    }
+
+    //  Internal; rebalances every sub-tree, in post-order, so that the children of each sub-tree are balanced by the time
+    //  it is rebalanced itself.
+    //
+    //  A complete tree is balanced for most policies, yet not for a `Treap`, whose priorities are independent of shape.
+    //
+    //  In a `Treap`, each element sinks, one rotation at a time, at most as deep as the treap formed by its sub-tree,
+    //  whose expected depth is O(log S) in the size S of the sub-tree, that is O(H) in the height H of the element in
+    //  the complete tree. There are at most N / 2^H elements of height H, hence the expected rotations sum to O(N).
+    //
+    //  The cursor is left pointing to the root.
+    //
+    //  Complexity: Time O(N) if no rotation is required, expected O(N) for a `Treap`, Space O(1).
+    fn rebalance_bottom_up(&mut self) {
+        self.move_to_root();
+        self.descend_to_first_post_order();
+
+        while let Some(current_tripod) = self.node.take() {
+            self.rebalance_subtree_complete(current_tripod);
+
+            match self.as_cursor().side() {
+                Some(Side::Left) => {
+                    self.move_up();
+
+                    if self.try_move_right().is_some() {
+                        self.descend_to_first_post_order();
+                    }
+                },
+                Some(Side::Right) => self.move_up(),
+                None => break,
+            }
+        }
+    }
+
+    //  Internal; descends to the first element, in post-order, of the current sub-tree.
+    //
+    //  Complexity: Time O(log N), Space O(1).
+    fn descend_to_first_post_order(&mut self) {
+        while self.try_move_left().is_some() || self.try_move_right().is_some() {}
+    }
+
+    //  Internal; rotates `count` nodes of the left spine, every other node, so that each is demoted below its left child.
+    //
+    //  Complexity: Time O(count), Space O(1).
+    fn compress_vine(&mut self, count: usize) {
+        self.move_to_root();
+
+        for _ in 0..count {
+            let spine_tripod = self.node.take().expect("Spine node");
+
+            self.rotate_child_from(Side::Left, spine_tripod);
+            self.move_left();
+        }
+    }
+}
+
+//  Join-based split and join.
+//
+//  A join links two trees through a middle element, descending the spine of the higher ranked tree until the policy
+//  deems the middle element balanced above the remainder, then rebalancing back up. Its cost is the length of the
+//  descent, hence O(1) plus the difference of rank -- or height -- between the two trees, and O(log N) in the worst
+//  case.
+//
+//  A split descends to the split element, then climbs back up, joining each ancestor and its other sub-tree onto the
+//  sub-tree accumulated on the same side. The ranks of the sub-trees joined on each side increase as the split climbs,
+//  hence the costs of the joins telescope to O(log N) overall, rather than O(log² N).
+impl<'a, 'brand, T, P: BalancePolicy> CursorMut<'a, 'brand, T, P> {
+    //  Internal; splits the tree around the element at index `at`, returning the roots of the elements before it, the
+    //  element itself, as a single node, and the roots of the elements after it.
+    //
+    //  The tree is left empty, and the cursor pointing to the "twilight" non-element.
+    //
+    //  Requirement: `at` must be less than the length of the tree.
+    //
+    //  Complexity: Time O(log N), Space O(1).
+    pub(super) fn split_around(&mut self, at: usize)
+        -> (Option<QuarterNodePtr<'brand, T>>, QuarterNodePtr<'brand, T>, Option<QuarterNodePtr<'brand, T>>)
+    {
+        debug_assert!(at < self.len());
+
+        self.move_to(at);
+
+        let mut current_tripod = self.node.take().expect("Non-twilight");
+
+        //  The tree is used as scratch space by the joins, hence its root is set aside.
+        let mut root = self.tree.root.take();
+
+        let mut left = Self::take_child(Side::Left, &current_tripod, self.token);
+        let mut right = Self::take_child(Side::Right, &current_tripod, self.token);
+
+        //  The side of the current node from which the split climbed, None for the split element itself.
+        let mut from: Option<Side> = None;
+        let mut middle = None;
+
+        //  O(log N) iterations, each detaching a node from its parent, and joining it on the side it belongs to.
+        loop {
+            let parent_tripod = current_tripod.borrow(self.token).up().map(|parent| self.deploy_tripod(parent));
+
+            let (current, side) = if let Some(parent_tripod) = parent_tripod.as_ref() {
+                let side = current_tripod.borrow(self.token).is_child_of(parent_tripod.borrow(self.token)).expect("Child!");
+
+                (Self::take_child(side, parent_tripod, self.token).expect("Child!"), Some(side))
+            } else {
+                (root.take().expect("Root!"), None)
+            };
+
+            self.retract_tripod(current_tripod);
+
+            match from {
+                None => middle = Some(current),
+                //  Climbing from the left: the node and its right sub-tree are after the split element.
+                Some(Side::Left) => {
+                    let other = Self::take_child(Side::Right, &current, self.token);
+                    right = Some(self.join_root(right, current, other));
+                },
+                //  Climbing from the right: the node and its left sub-tree are before the split element.
+                Some(Side::Right) => {
+                    let other = Self::take_child(Side::Left, &current, self.token);
+                    left = Some(self.join_root(other, current, left));
+                },
+            }
+
+            current_tripod = if let Some(parent_tripod) = parent_tripod { parent_tripod } else { break };
+            from = side;
+        }
+
+        self.index = 0;
+
+        (left, middle.expect("Split element"), right)
+    }
+
+    //  Internal; joins the elements of `left`, the `middle` element, and the elements of `right`, in this order, into the
+    //  tree.
+    //
+    //  The cursor is left pointing to the root.
+    //
+    //  Requirement: the tree must be empty, `middle` must be a single node, and `left` and `right` must be balanced.
+    //
+    //  Complexity: Time O(1 + |rank(left) - rank(right)|), and O(log N) in the worst case, Space O(1).
+    pub(super) fn join(
+        &mut self,
+        left: Option<QuarterNodePtr<'brand, T>>,
+        middle: QuarterNodePtr<'brand, T>,
+        right: Option<QuarterNodePtr<'brand, T>>,
+    )
+    {
+        debug_assert!(self.tree.root.is_none());
         debug_assert!(self.node.is_none());
+        debug_assert_eq!(1, middle.borrow(self.token).size);
 
-        self.describe_node("prepare_rotation (begin)", &root_tripod);
+        let (mut left, mut right) = (left, right);
 
-        let _original_index = self.index();
-        let _original_address = &*root_tripod as *const _;
+        let index = left.as_ref().map(|node| node.borrow(self.token).size).unwrap_or(0);
+        let middle_rank = middle.borrow(self.token).rank;
 
-        let (pivot_selected, pivot_opposite) = {
-            let pivot_node = root_tripod.borrow(self.token).child(side).expect("Pivot!");
-            let selected = pivot_node.borrow(self.token).child_size(side, self.token);
-            let opposite = pivot_node.borrow(self.token).child_size(side.opposite(), self.token);
+        //  The tripod of the node below which the middle element goes, and on which side, None for the root of the tree.
+        let mut hole: Option<(QuarterNodePtr<'brand, T>, Side)> = None;
 
-            (selected, opposite)
-        };
+        //  O(1 + rank difference) iterations, each descending one level of the higher ranked tree.
+        loop {
+            let side = {
+                let left = left.as_ref().map(|node| node.borrow(self.token).summary());
+                let right = right.as_ref().map(|node| node.borrow(self.token).summary());
 
-        if pivot_opposite >= 2 * pivot_selected {
-            self.node = Some(root_tripod);
+                let size = 1 + left.map(|s| s.size).unwrap_or(0) + right.map(|s| s.size).unwrap_or(0);
+                let rank = P::rank(&Summary { size, rank: middle_rank }, left.as_ref(), right.as_ref());
+
+                P::rotation(&Summary { size, rank }, left.as_ref(), right.as_ref())
+            };
+
+            let side = if let Some(side) = side { side } else { break };
+
+            //  The root of the higher ranked tree stays above the middle element, which goes into its inner sub-tree.
+            let top = match side {
+                Side::Left => left.take(),
+                Side::Right => right.take(),
+            }.expect("Higher ranked tree");
+
+            let inner = Self::take_child(side.opposite(), &top, self.token);
 
-            //  Move to pivot.
             match side {
-                Side::Left => self.move_left(),
-                Side::Right => self.move_right(),
+                Side::Left => left = inner,
+                Side::Right => right = inner,
             }
 
-            let pivot_tripod = self.node.take().expect("Pivot");
+            let top_tripod = self.deploy_tripod(&top);
 
-            self.rotate_child_from(side.opposite(), pivot_tripod);
-            self.move_up();
+            self.fill_hole(hole.take(), top);
 
-            let root_tripod = self.node.take().expect("Root");
+            hole = Some((top_tripod, side.opposite()));
+        }
 
-            debug_assert_eq!(_original_index, self.index());
-            debug_assert_eq!(_original_address, &*root_tripod as *const _);
+        if let Some(left) = left {
+            Self::put_child(Side::Left, &middle, left, self.token);
+        }
 
-            self.describe_node("prepare_rotation (end) (modified)", &root_tripod);
+        if let Some(right) = right {
+            Self::put_child(Side::Right, &middle, right, self.token);
+        }
 
-            root_tripod
-        } else {
-            self.describe_node("prepare_rotation (end) (passthrough)", &root_tripod);
+        let middle_tripod = self.deploy_tripod(&middle);
 
-            root_tripod
-        }
+        self.fill_hole(hole, middle);
+
+        //  The sizes of the ancestors of the middle element are adjusted, and their balance restored, on the way up.
+        self.index = index;
+        self.rebalance_tree_complete(middle_tripod);
     }
 
-    //  Internal; adjusts the size of the node by adding up the size of its children.
+    //  Internal; joins, as per `join`, then returns the root of the tree, leaving it empty.
     //
-    //  Complexity: Time O(1), Space O(1).
-    fn adjust_size(&mut self, node: &GhostNode<'brand, T>) {
-        let left_size = node.borrow(self.token).left_size(self.token);
-        let right_size = node.borrow(self.token).right_size(self.token);
+    //  Complexity: Time O(1 + |rank(left) - rank(right)|), Space O(1).
+    fn join_root(
+        &mut self,
+        left: Option<QuarterNodePtr<'brand, T>>,
+        middle: QuarterNodePtr<'brand, T>,
+        right: Option<QuarterNodePtr<'brand, T>>,
+    )
+        -> QuarterNodePtr<'brand, T>
+    {
+        self.join(left, middle, right);
 
-        node.borrow_mut(self.token).size = 1 + left_size + right_size;
+        if let Some(tripod) = self.node.take() {
+            self.retract_tripod(tripod);
+        }
 
-        self.describe_node("adjust_size (adjusted)", node);
+        self.tree.root.take().expect("Non-empty")
     }
 
-    //  Internal; pops of the specified child, if any, adjusting size and pointers.
+    //  Internal; places the node, a root, in the hole left below the tripod, or as the root of the tree if None.
     //
     //  Complexity: Time O(1), Space O(1).
-    fn take_child(side: Side, node: &GhostNode<'brand, T>, token: &mut GhostToken<'brand>) -> Option<QuarterNodePtr<'brand, T>> {
-        let child = node.borrow_mut(token).take_child(side)?;
-
-        let node_from_child = child.borrow_mut(token).up.take().expect("child.up == node");
-        node.borrow_mut(token).set_child(side, node_from_child);
-
-        let child_size = child.borrow(token).size;
-        node.borrow_mut(token).size -= child_size;
-
-        Some(child)
+    fn fill_hole(&mut self, hole: Option<(QuarterNodePtr<'brand, T>, Side)>, node: QuarterNodePtr<'brand, T>) {
+        if let Some((tripod, side)) = hole {
+            Self::put_child(side, &tripod, node, self.token);
+            self.retract_tripod(tripod);
+        } else {
+            debug_assert!(self.tree.root.is_none());
+            self.tree.root = Some(node);
+        }
     }
 }
 
 //  Debugging code
 #[allow(dead_code)]
-impl<'a, 'brand, T> CursorMut<'a, 'brand, T> {
+impl<'a, 'brand, T, P: BalancePolicy> CursorMut<'a, 'brand, T, P> {
     //  Internal; describe the node: sizes, parent and children, ...
     #[cfg(all(test, feature = "test-tree-debug"))]
     fn describe_node(&self, caller: &str, node: &GhostNode<'brand, T>) {
@@ -1961,11 +2720,17 @@ impl<'a, 'brand, T> CursorMut<'a, 'brand, T> {
     fn describe_self(&self, _: &str) {}
 }
 
-impl<'a, 'brand, T> Drop for CursorMut<'a, 'brand, T> {
+impl<'a, 'brand, T, P: BalancePolicy> Drop for CursorMut<'a, 'brand, T, P> {
     fn drop(&mut self) {
         if let Some(tripod) = self.node.take() {
             super::retract(tripod, self.token);
         }
+
+        if self.unbalanced {
+            self.tree.assert_structure(self.token);
+        } else {
+            self.tree.assert_invariants(self.token);
+        }
     }
 }
 
@@ -1974,6 +2739,8 @@ mod tests {
 
 use std::ops::Range;
 
+use crate::invariant::InvariantError;
+
 use super::super::tests::*;
 use super::*;
 
@@ -2409,6 +3176,170 @@ fn cursor_mut_brush_move_to() {
     });
 }
 
+//  Returns the index `delta` elements away from `from`, `POSITIONS.len()` being the "twilight" non-element.
+fn move_by_index(from: usize, delta: isize, length: usize) -> usize {
+    let from = if from == length && delta > 0 { -1 } else { from as isize };
+    let at = from + delta;
+
+    if at < 0 || at >= length as isize { length } else { at as usize }
+}
+
+#[test]
+fn cursor_brush_move_by() {
+    const LEFT: Position<'static> = Position::new(0, 0..2, "L", Some("Root"), None, Some("LR"), None, Some("LR"));
+    const LR: Position<'static> = Position::new(1, 1..2, "LR", Some("L"), None, None, Some("L"), Some("Root"));
+    const ROOT: Position<'static> = Position::new(2, 0..5, "Root", None, Some("L"), Some("R"), Some("LR"), Some("RL"));
+    const RL: Position<'static> = Position::new(3, 3..4, "RL", Some("R"), None, None, Some("Root"), Some("R"));
+    const RIGHT: Position<'static> = Position::new(4, 3..5, "R", Some("Root"), Some("RL"), None, Some("RL"), None);
+
+    const POSITIONS: [Position<'static>; 5] = [LEFT, LR, ROOT, RL, RIGHT];
+
+    with_tree(&["Root", "L", "R", "", "LR", "RL"], |token, tree| {
+        for from in 0..=POSITIONS.len() {
+            for delta in -6..=6 {
+                let mut cursor = tree.cursor(token);
+                cursor.move_to(from);
+
+                let expected = move_by_index(from, delta, POSITIONS.len());
+                let moved = cursor.try_move_by(delta).map(String::as_str);
+
+                if from == POSITIONS.len() || expected == POSITIONS.len() {
+                    assert_eq!(None, moved);
+                    assert_eq!(from, cursor.index().unwrap_or(POSITIONS.len()));
+                } else {
+                    assert_eq!(Some(POSITIONS[expected].current), moved);
+                }
+
+                cursor.move_to(from);
+                cursor.move_by(delta);
+
+                if expected == POSITIONS.len() {
+                    assert_twilight(cursor);
+                } else {
+                    assert_position(POSITIONS[expected], cursor);
+                }
+            }
+        }
+    });
+}
+
+#[test]
+fn cursor_mut_brush_move_by() {
+    const LEFT: Position<'static> = Position::new(0, 0..2, "L", Some("Root"), None, Some("LR"), None, Some("LR"));
+    const LR: Position<'static> = Position::new(1, 1..2, "LR", Some("L"), None, None, Some("L"), Some("Root"));
+    const ROOT: Position<'static> = Position::new(2, 0..5, "Root", None, Some("L"), Some("R"), Some("LR"), Some("RL"));
+    const RL: Position<'static> = Position::new(3, 3..4, "RL", Some("R"), None, None, Some("Root"), Some("R"));
+    const RIGHT: Position<'static> = Position::new(4, 3..5, "R", Some("Root"), Some("RL"), None, Some("RL"), None);
+
+    const POSITIONS: [Position<'static>; 5] = [LEFT, LR, ROOT, RL, RIGHT];
+
+    with_tree(&["Root", "L", "R", "", "LR", "RL"], |token, tree| {
+        let mut cursor = tree.cursor_mut(token);
+
+        for from in 0..=POSITIONS.len() {
+            for delta in -6..=6 {
+                cursor.move_to(from);
+
+                let expected = move_by_index(from, delta, POSITIONS.len());
+                let moved = cursor.try_move_by(delta).map(|s| s.clone());
+
+                if from == POSITIONS.len() || expected == POSITIONS.len() {
+                    assert_eq!(None, moved);
+                    assert_eq!(from, cursor.index().unwrap_or(POSITIONS.len()));
+                } else {
+                    assert_eq!(Some(POSITIONS[expected].current), moved.as_deref());
+                }
+
+                cursor.move_to(from);
+                cursor.move_by(delta);
+
+                if expected == POSITIONS.len() {
+                    assert_twilight_mut(&mut cursor);
+                } else {
+                    assert_position_mut(POSITIONS[expected], &mut cursor);
+                }
+            }
+        }
+    });
+}
+
+#[test]
+fn cursor_seek_by() {
+    with_tree(&["Root", "L", "R", "", "LR", "RL"], |token, tree| {
+        let mut cursor = tree.cursor(token);
+        cursor.move_to(5);
+
+        //  Seek by rank, from the sizes of the sub-trees.
+        let mut visited = Vec::new();
+
+        let result = cursor.seek_by(|value, range, left, right| {
+            visited.push((value.clone(), range.clone(), left, right));
+
+            match 3.cmp(&(range.start + left)) {
+                cmp::Ordering::Less => Direction::Left,
+                cmp::Ordering::Equal => Direction::Stop,
+                cmp::Ordering::Greater => Direction::Right,
+            }
+        });
+
+        assert_eq!(Ok(3), result);
+        assert_current(3, 3..4, "RL", cursor);
+
+        let expected = [
+            ("Root".to_string(), 0..5, 2, 2),
+            ("R".to_string(), 3..5, 1, 0),
+            ("RL".to_string(), 3..4, 0, 0),
+        ];
+        assert_eq!(&expected[..], &visited[..]);
+
+        //  Descend to a missing child.
+        assert_eq!(Err(0), cursor.seek_by(|_, _, _, _| Direction::Left));
+        assert_current(0, 0..2, "L", cursor);
+
+        assert_eq!(Err(5), cursor.seek_by(|_, _, _, _| Direction::Right));
+        assert_current(4, 3..5, "R", cursor);
+
+        let result = cursor.seek_by(|value, _, _, _| if value == "L" { Direction::Right } else { Direction::Left });
+
+        assert_eq!(Err(1), result);
+        assert_current(1, 1..2, "LR", cursor);
+    });
+
+    with_tree(&[], |token, tree| {
+        let mut cursor = tree.cursor(token);
+
+        assert_eq!(Err(0), cursor.seek_by(|_, _, _, _| Direction::Stop));
+        assert_empty(cursor);
+    });
+}
+
+#[test]
+fn cursor_mut_seek_by() {
+    with_tree(&["Root", "L", "R", "", "LR", "RL"], |token, tree| {
+        let mut cursor = tree.cursor_mut(token);
+
+        //  Leftmost element whose name is at most 2 characters long.
+        let result = cursor.seek_by(|value, _, left, _| {
+            if value.len() <= 2 && left == 0 { Direction::Stop } else { Direction::Left }
+        });
+
+        assert_eq!(Ok(0), result);
+        assert_current_mut(0, 0..2, "L", &mut cursor);
+
+        //  Seeking the current element keeps the cursor in place.
+        let result = cursor.seek_by(|value, _, _, _| if value == "L" { Direction::Stop } else { Direction::Left });
+
+        assert_eq!(Ok(0), result);
+        assert_current_mut(0, 0..2, "L", &mut cursor);
+
+        assert_eq!(Err(5), cursor.seek_by(|_, _, _, _| Direction::Right));
+        assert_current_mut(4, 3..5, "R", &mut cursor);
+
+        assert_eq!(Ok(2), cursor.seek_by(|_, _, _, _| Direction::Stop));
+        assert_current_mut(2, 0..5, "Root", &mut cursor);
+    });
+}
+
 #[test]
 fn cursor_mut_move_to_self() {
     const LEFT: Position<'static> = Position::new(0, 0..2, "L", Some("Root"), None, Some("LR"), None, Some("LR"));
@@ -2475,61 +3406,61 @@ fn cursor_mut_remove_current_root() {
         let mut cursor = tree.cursor_mut(token);
 
         {
-            const POS: Position<'static> = Position::new(2, 0..4, "R", None, Some("L"), Some("RL"), Some("LR"), Some("RL"));
+            const POS: Position<'static> = Position::new(2, 2..3, "RL", Some("R"), None, None, Some("LR"), Some("R"));
 
             eprintln!("===== Remove Root =====");
 
             let removed = cursor.remove_current();
 
             assert_eq!(Some("Root".to_string()), removed);
-            assert_tree(&["R", "L", "RL", "-", "LR"], cursor.as_cursor());
+            assert_tree(&["LR", "L", "R", "-", "-", "RL"], cursor.as_cursor());
             assert_position(POS, cursor.as_cursor());
         }
 
         {
-            const POS: Position<'static> = Position::new(2, 2..3, "RL", Some("L"), None, None, Some("L"), None);
+            const POS: Position<'static> = Position::new(2, 2..3, "R", Some("LR"), None, None, Some("LR"), None);
 
-            eprintln!("===== Remove R =====");
+            eprintln!("===== Remove RL =====");
 
             let removed = cursor.remove_current();
 
-            assert_eq!(Some("R".to_string()), removed);
-            assert_tree(&["L", "LR", "RL"], cursor.as_cursor());
+            assert_eq!(Some("RL".to_string()), removed);
+            assert_tree(&["LR", "L", "R"], cursor.as_cursor());
             assert_position(POS, cursor.as_cursor());
         }
 
         {
-            const POS: Position<'static> = Position::new(1, 0..2, "RL", None, Some("LR"), None, Some("LR"), None);
+            const POS: Position<'static> = Position::new(1, 0..2, "R", None, Some("L"), None, Some("L"), None);
 
-            eprintln!("===== Remove L =====");
+            eprintln!("===== Remove LR =====");
 
             cursor.move_up();
 
             let removed = cursor.remove_current();
 
-            assert_eq!(Some("L".to_string()), removed);
-            assert_tree(&["RL", "LR"], cursor.as_cursor());
+            assert_eq!(Some("LR".to_string()), removed);
+            assert_tree(&["R", "L"], cursor.as_cursor());
             assert_position(POS, cursor.as_cursor());
         }
 
         {
-            eprintln!("===== Remove RL =====");
+            eprintln!("===== Remove R =====");
 
             let removed = cursor.remove_current();
 
-            assert_eq!(Some("RL".to_string()), removed);
-            assert_tree(&["LR"], cursor.as_cursor());
+            assert_eq!(Some("R".to_string()), removed);
+            assert_tree(&["L"], cursor.as_cursor());
             assert_twilight(cursor.as_cursor());
         }
 
         {
-            eprintln!("===== Remove LR =====");
+            eprintln!("===== Remove L =====");
 
             cursor.move_left();
 
             let removed = cursor.remove_current();
 
-            assert_eq!(Some("LR".to_string()), removed);
+            assert_eq!(Some("L".to_string()), removed);
             assert_tree(&[], cursor.as_cursor());
             assert_twilight(cursor.as_cursor());
         }
@@ -2916,12 +3847,12 @@ fn cursor_mut_splice_twilight() {
     });
 
     with_tree_duo(SECOND_HALF_HEX, FIRST_HALF_HEX, |token, tree, splice| {
-        //                 9
+        //                 6
         //         4               C
-        //     2        6      A       E
-        //   1   3   5   8   B   -   D   F
-        //  - - - - - - 7 - - - - - - - - -
-        const RESULT: &[&str] = &["9", "4", "C", "2", "6", "A", "E", "1", "3", "5", "8", "B", "-", "D", "F", "-", "-", "-", "-", "-", "-", "7"];
+        //     2       5       9       E
+        //   1   3   -   -   8   A   D   F
+        //  - - - - - - - - 7 - B - - - - -
+        const RESULT: &[&str] = &["6", "4", "C", "2", "5", "9", "E", "1", "3", "-", "-", "8", "A", "D", "F", "-", "-", "-", "-", "-", "-", "-", "-", "7", "-", "B"];
 
         eprintln!("===== Splice After First in Second =====");
 
@@ -2968,12 +3899,12 @@ fn cursor_mut_splice_after() {
     const SPLICE: &[&str] = &["4", "2", "6", "1", "3", "5", "7"];
 
     const RESULTS: &[&[&str]] = &[
-        //                 4
-        //         2               D
-        //     A       3       B       F
-        //   -   1   -   -   6   C   E   G
-        //  - - - - - - - - 5 7 - - - - - -
-        &["4", "2", "D", "A", "3", "B", "F", "-", "1", "-", "-", "6", "C", "E", "G", "-", "-", "-", "-", "-", "-", "-", "-", "5", "7"],
+        //                 B
+        //         4               D
+        //     2       6       C       F
+        //   A   3   5   7   -   -   E   G
+        //  - 1 - - - - - - - - - - - - - -
+        &["B", "4", "D", "2", "6", "C", "F", "A", "3", "5", "7", "-", "-", "E", "G", "-", "1"],
         //                 4
         //         B               D
         //     A       2       6       F
@@ -2996,17 +3927,18 @@ fn cursor_mut_splice_after() {
         //   A   C   E   3   5   7   -   -
         //  - - - - - 1 - - - - - - - - - -
         &["4", "D", "F", "B", "2", "6", "G", "A", "C", "E", "3", "5", "7", "-", "-", "-", "-", "-", "-", "-", "1"],
-        //                 4
-        //         D               6
-        //     B       F       5       G
-        //   A   C   E   2   -   -   7   -
-        //  - - - - - - 1 3 - - - - - - - -
-        &["4", "D", "6", "B", "F", "5", "G", "A", "C", "E", "2", "-", "-", "7", "-", "-", "-", "-", "-", "-", "-", "1", "3"],
-        //         G
-        //     D       4
-        //   B   F   2   6
-        //  A C E - 1 3 5 7
-        &["G", "D", "4", "B", "F", "2", "6", "A", "C", "E", "-", "1", "3", "5", "7"],
+        //                 F
+        //         D               4
+        //     B       E       2       6
+        //   A   C   -   -   1   3   5   G
+        //  - - - - - - - - - - - - - - 7 -
+        &["F", "D", "4", "B", "E", "2", "6", "A", "C", "-", "-", "1", "3", "5", "G", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "7"],
+        //                 2
+        //         D               4
+        //     B       F       3       6
+        //   A   C   E   G   -   -   5   7
+        //  - - - - - - - 1 - - - - - - - -
+        &["2", "D", "4", "B", "F", "3", "6", "A", "C", "E", "G", "-", "-", "5", "7", "-", "-", "-", "-", "-", "-", "-", "1"],
     ];
 
     for index in 0..ORIGINAL.len() {
@@ -3034,17 +3966,18 @@ fn cursor_mut_splice_before() {
     const SPLICE: &[&str] = &["4", "2", "6", "1", "3", "5", "7"];
 
     const RESULTS: &[&[&str]] = &[
-        //         A
-        //     4       D
-        //   2   6   B   F
-        //  1 3 5 7 - C E G
-        &["A", "4", "D", "2", "6", "B", "F", "1", "3", "5", "7", "-", "C", "E", "G"],
-        //                 4
-        //         2               D
-        //     A       3       B       F
-        //   -   1   -   -   6   C   E   G
-        //  - - - - - - - - 5 7 - - - - - -
-        &["4", "2", "D", "A", "3", "B", "F", "-", "1", "-", "-", "6", "C", "E", "G", "-", "-", "-", "-", "-", "-", "-", "-", "5", "7"],
+        //                 6
+        //         4               D
+        //     2       5       B       F
+        //   1   3   -   -   A   C   E   G
+        //  - - - - - - - - 7 - - - - - - -
+        &["6", "4", "D", "2", "5", "B", "F", "1", "3", "-", "-", "A", "C", "E", "G", "-", "-", "-", "-", "-", "-", "-", "-", "7"],
+        //                 B
+        //         4               D
+        //     2       6       C       F
+        //   A   3   5   7   -   -   E   G
+        //  - 1 - - - - - - - - - - - - - -
+        &["B", "4", "D", "2", "6", "C", "F", "A", "3", "5", "7", "-", "-", "E", "G", "-", "1"],
         //                 4
         //         B               D
         //     A       2       6       F
@@ -3067,12 +4000,12 @@ fn cursor_mut_splice_before() {
         //   A   C   E   3   5   7   -   -
         //  - - - - - 1 - - - - - - - - - -
         &["4", "D", "F", "B", "2", "6", "G", "A", "C", "E", "3", "5", "7", "-", "-", "-", "-", "-", "-", "-", "1"],
-        //                 4
-        //         D               6
-        //     B       F       5       G
-        //   A   C   E   2   -   -   7   -
-        //  - - - - - 1 3 - - - - - - - - -
-        &["4", "D", "6", "B", "F", "5", "G", "A", "C", "E", "2", "-", "-", "7", "-", "-", "-", "-", "-", "-", "-", "1", "3"],
+        //                 F
+        //         D               4
+        //     B       E       2       6
+        //   A   C   -   -   1   3   5   G
+        //  - - - - - - - - - - - - - - 7 -
+        &["F", "D", "4", "B", "E", "2", "6", "A", "C", "-", "-", "1", "3", "5", "G", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "7"],
     ];
 
     for index in 0..ORIGINAL.len() {
@@ -3142,9 +4075,9 @@ fn cursor_mut_split_after() {
         (&["2", "1", "4", "-", "-", "3"], &["8", "6", "C", "5", "7", "A", "E", "-", "-", "-", "-", "9", "B", "D", "F"]),
         (&["4", "2", "5", "1", "3"], &["C", "8", "E", "6", "A", "D", "F", "-", "7", "9", "B"]),
         (&["4", "2", "6", "1", "3", "5"], &["C", "8", "E", "7", "A", "D", "F", "-", "-", "9", "B"]),
-        (&["4", "2", "6", "1", "3", "5", "7"], &["C", "8", "E", "-", "A", "D", "F", "-", "-", "9", "B"]),
-        (&["4", "2", "8", "1", "3", "6", "-", "-", "-", "-", "-", "5", "7"], &["C", "A", "E", "9", "B", "D", "F"]),
-        (&["4", "2", "8", "1", "3", "6", "9", "-", "-", "-", "-", "5", "7"], &["C", "A", "E", "-", "B", "D", "F"]),
+        (&["4", "2", "6", "1", "3", "5", "7"], &["C", "A", "E", "8", "B", "D", "F", "-", "9"]),
+        (&["4", "2", "6", "1", "3", "5", "8", "-", "-", "-", "-", "-", "-", "7"], &["C", "A", "E", "9", "B", "D", "F"]),
+        (&["6", "4", "8", "2", "5", "7", "9", "1", "3"], &["C", "A", "E", "-", "B", "D", "F"]),
         (&["4", "2", "8", "1", "3", "6", "A", "-", "-", "-", "-", "5", "7", "9"], &["C", "B", "E", "-", "-", "D", "F"]),
         (&["8", "4", "A", "2", "6", "9", "B", "1", "3", "5", "7"], &["E", "C", "F", "-", "D"]),
         (&["8", "4", "A", "2", "6", "9", "C", "1", "3", "5", "7", "-", "-", "B"], &["E", "D", "F"]),
@@ -3183,9 +4116,9 @@ fn cursor_mut_split_before() {
         (&["8", "6", "C", "4", "7", "A", "E", "-", "5", "-", "-", "9", "B", "D", "F"], &["2", "1", "3"]),
         (&["8", "6", "C", "5", "7", "A", "E", "-", "-", "-", "-", "9", "B", "D", "F"], &["2", "1", "4", "-", "-", "3"]),
         (&["C", "8", "E", "6", "A", "D", "F", "-", "7", "9", "B"], &["4", "2", "5", "1", "3"]),
-        (&["C", "8", "E", "7", "A", "D", "F", "-", "-", "9", "B"], &["4", "2", "6", "1", "3", "5"]),
-        (&["C", "8", "E", "-", "A", "D", "F", "-", "-", "9", "B"], &["4", "2", "6", "1", "3", "5", "7"]),
-        (&["C", "A", "E", "9", "B", "D", "F"], &["4", "2", "8", "1", "3", "6", "-", "-", "-", "-", "-", "5", "7"]),
+        (&["A", "8", "C", "7", "9", "B", "E", "-", "-", "-", "-", "-", "-", "D", "F"], &["4", "2", "6", "1", "3", "5"]),
+        (&["C", "A", "E", "8", "B", "D", "F", "-", "9"], &["4", "2", "6", "1", "3", "5", "7"]),
+        (&["C", "A", "E", "9", "B", "D", "F"], &["4", "2", "6", "1", "3", "5", "8", "-", "-", "-", "-", "-", "-", "7"]),
         (&["C", "A", "E", "-", "B", "D", "F"], &["4", "2", "8", "1", "3", "6", "9", "-", "-", "-", "-", "5", "7"]),
         (&["C", "B", "E", "-", "-", "D", "F"], &["4", "2", "8", "1", "3", "6", "A", "-", "-", "-", "-", "5", "7", "9"]),
         (&["E", "C", "F", "-", "D"], &["8", "4", "A", "2", "6", "9", "B", "1", "3", "5", "7"]),
@@ -3213,4 +4146,220 @@ fn cursor_mut_split_before() {
     }
 }
 
+
+#[test]
+fn cursor_mut_rotate_noop() {
+    const ORIGINAL: &[&str] = &["2", "1", "3"];
+
+    with_tree(ORIGINAL, |token, tree| {
+        let mut cursor = tree.cursor_mut(token);
+
+        cursor.move_up();
+        cursor.rotate(Side::Right);
+
+        assert_twilight_mut(&mut cursor);
+        assert_tree(ORIGINAL, cursor.as_cursor());
+
+        cursor.move_to(0);
+        cursor.rotate(Side::Left);
+
+        assert_eq!(Some(0), cursor.index());
+        assert_tree(ORIGINAL, cursor.as_cursor());
+    });
+}
+
+#[test]
+fn cursor_mut_rotate_rebalance() {
+    const ORIGINAL: &[&str] = &["4", "2", "6", "1", "3", "5", "7"];
+    const ROTATED: &[&str] = &["2", "1", "4", "-", "-", "3", "6", "-", "-", "-", "-", "-", "-", "5", "7"];
+
+    with_tree(ORIGINAL, |token, tree| {
+        {
+            let mut cursor = tree.cursor_mut(token);
+
+            cursor.rotate(Side::Left);
+
+            assert_eq!(Some("2"), cursor.current().map(|s| &**s));
+            assert_eq!(Some(1), cursor.index());
+            assert_eq!(0..7, cursor.range());
+            assert_tree(ROTATED, cursor.as_cursor());
+        }
+
+        assert_eq!(Err(InvariantError::Balance { index: 1, left: 1, right: 5 }), tree.check_invariants(token));
+
+        {
+            let mut cursor = tree.cursor_mut(token);
+
+            cursor.move_to(1);
+            cursor.rebalance();
+
+            assert_eq!(Some("2"), cursor.current().map(|s| &**s));
+            assert_eq!(Some(1), cursor.index());
+            assert_tree(ORIGINAL, cursor.as_cursor());
+        }
+
+        assert_eq!(Ok(()), tree.check_invariants(token));
+    });
+}
+
+#[test]
+fn cursor_mut_rotate_rebalance_push() {
+    const ORIGINAL: &[&str] = &["4", "2", "6", "1", "3", "5", "7"];
+    const ROTATED: &[&str] = &["2", "1", "4", "-", "-", "3", "6", "-", "-", "-", "-", "-", "-", "5", "7"];
+
+    //  The default policy, `WeightBalanced`, requires balance.
+    with_tree(ORIGINAL, |token, tree| {
+        {
+            let mut cursor = tree.cursor_mut(token);
+
+            cursor.rotate(Side::Left);
+
+            assert_tree(ROTATED, cursor.as_cursor());
+
+            //  Demoted element.
+            cursor.move_right();
+            cursor.rebalance();
+
+            assert_eq!(Some("4"), cursor.current().map(|s| &**s));
+            assert_eq!(Some(3), cursor.index());
+        }
+
+        assert_eq!(Ok(()), tree.check_invariants(token));
+        assert_tree(ORIGINAL, tree.cursor(token));
+
+        //  Asserts the invariants, with the `paranoid` feature.
+        tree.push_back("8".to_string(), token);
+
+        assert_eq!(Ok(()), tree.check_invariants(token));
+        assert_eq!(8, tree.len(token));
+    });
+}
+
+#[test]
+fn cursor_mut_swap_with_child() {
+    const ORIGINAL: &[&str] = &["4", "2", "6", "1", "3", "5", "7"];
+
+    with_tree(ORIGINAL, |token, tree| {
+        {
+            let mut cursor = tree.cursor_mut(token);
+
+            cursor.swap_with_child(Side::Left);
+
+            assert_eq!(Some("4"), cursor.current().map(|s| &**s));
+            assert_eq!(Some(1), cursor.index());
+            assert_eq!(0..3, cursor.range());
+            assert_tree(&["2", "4", "6", "1", "3", "5", "7"], cursor.as_cursor());
+
+            cursor.swap_with_child(Side::Right);
+
+            assert_eq!(Some("4"), cursor.current().map(|s| &**s));
+            assert_eq!(Some(2), cursor.index());
+            assert_eq!(2..3, cursor.range());
+            assert_tree(&["2", "3", "6", "1", "4", "5", "7"], cursor.as_cursor());
+
+            //  Leaf, nothing happens.
+            cursor.swap_with_child(Side::Left);
+
+            assert_eq!(Some(2), cursor.index());
+            assert_tree(&["2", "3", "6", "1", "4", "5", "7"], cursor.as_cursor());
+
+            cursor.move_to_root();
+            cursor.swap_with_child(Side::Right);
+
+            assert_eq!(Some("2"), cursor.current().map(|s| &**s));
+            assert_eq!(Some(5), cursor.index());
+            assert_tree(&["6", "3", "2", "1", "4", "5", "7"], cursor.as_cursor());
+        }
+
+        assert_eq!(Ok(()), tree.check_invariants(token));
+    });
+}
+
+#[test]
+fn cursor_mut_detach_attach_child() {
+    const ORIGINAL: &[&str] = &["4", "2", "6", "1", "3", "5", "7"];
+
+    with_tree_duo(ORIGINAL, &[], |token, tree, detached| {
+        {
+            let mut cursor = tree.cursor_mut(token);
+            cursor.move_to(1);
+
+            *detached = cursor.detach_child(Side::Left);
+
+            assert_eq!(Some("2"), cursor.current().map(|s| &**s));
+            assert_eq!(Some(0), cursor.index());
+            assert_eq!(0..2, cursor.range());
+            assert_tree(&["4", "2", "6", "-", "3", "5", "7"], cursor.as_cursor());
+
+            //  No such child, nothing happens.
+            assert!(cursor.detach_child(Side::Left).is_empty());
+            assert_eq!(Some(0), cursor.index());
+        }
+
+        assert_tree(&["1"], detached.cursor(token));
+        assert_eq!(Ok(()), tree.check_invariants(token));
+
+        {
+            let mut cursor = tree.cursor_mut(token);
+            cursor.move_to(5);
+
+            cursor.attach_child(Side::Left, mem::take(detached));
+
+            assert_eq!(Some("7"), cursor.current().map(|s| &**s));
+            assert_eq!(Some(6), cursor.index());
+            assert_eq!(5..7, cursor.range());
+            assert_tree(&["4", "2", "6", "-", "3", "5", "7", "-", "-", "-", "-", "-", "-", "1"], cursor.as_cursor());
+
+            //  Empty tree, nothing happens.
+            cursor.attach_child(Side::Left, TripodTree::new());
+            assert_eq!(Some(6), cursor.index());
+        }
+
+        assert_eq!(Ok(()), tree.check_invariants(token));
+    });
+}
+
+#[test]
+fn cursor_mut_detach_attach_unbalanced() {
+    const ORIGINAL: &[&str] = &["4", "2", "6", "1", "3", "5", "7"];
+
+    with_tree_duo(ORIGINAL, &[], |token, tree, detached| {
+        {
+            let mut cursor = tree.cursor_mut(token);
+
+            *detached = cursor.detach_child(Side::Right);
+
+            assert_eq!(Some(3), cursor.index());
+            assert_eq!(0..4, cursor.range());
+        }
+
+        assert_tree(&["6", "5", "7"], detached.cursor(token));
+        assert_eq!(Err(InvariantError::Balance { index: 3, left: 3, right: 0 }), tree.check_invariants(token));
+
+        {
+            let mut cursor = tree.cursor_mut(token);
+            cursor.move_to(0);
+
+            cursor.attach_child(Side::Left, mem::take(detached));
+
+            assert_eq!(Some(3), cursor.index());
+            assert_eq!(0..4, cursor.range());
+
+            cursor.move_to_root();
+
+            assert_eq!(0..7, cursor.range());
+            assert_tree(&["4", "2", "-", "1", "3", "-", "-", "6", "-", "-", "-", "-", "-", "-", "-", "5", "7"], cursor.as_cursor());
+
+            cursor.move_to(3);
+            cursor.rebalance();
+            cursor.move_to(1);
+            cursor.rebalance();
+
+            assert_eq!(Some(1), cursor.index());
+        }
+
+        assert_eq!(Ok(()), tree.check_invariants(token));
+    });
+}
+
 } // mod tests
@@ -5,34 +5,40 @@ use core::{
 
 use ghost_cell::GhostToken;
 
-use super::{Cursor, TripodTree};
+use super::{BalancePolicy, Cursor, TripodTree};
 
 /// An iterator over a TripodList, self-sufficient once created as it carries its own token.
+///
+/// The iterator keeps a cursor at either end of its range, each moving from one element to the next through finger
+/// search, so that iterating over the entire tree is O(N) rather than O(N log N).
 pub struct Iter<'a, 'brand, T> {
     range: Range<usize>,
-    cursor: Cursor<'a, 'brand, T>,
+    front: Cursor<'a, 'brand, T>,
+    back: Cursor<'a, 'brand, T>,
 }
 
 impl<'a, 'brand, T> Iter<'a, 'brand, T> {
     /// Creates a new instance, iterating over the entire tree.
-    pub fn new(token: &'a GhostToken<'brand>, tree: &'a TripodTree<'brand, T>) -> Self {
-        let range = 0..tree.len(token);
-        let cursor = tree.cursor(token);
-
-        Self { range, cursor, }
+    pub fn new<P: BalancePolicy>(token: &'a GhostToken<'brand>, tree: &'a TripodTree<'brand, T, P>) -> Self {
+        Self::range(token, tree, 0..tree.len(token))
     }
 
     /// Creates a new instance, iterating over the specified range of the tree.
-    pub fn range(token: &'a GhostToken<'brand>, tree: &'a TripodTree<'brand, T>, range: Range<usize>) -> Self {
-        let cursor = tree.cursor(token);
+    pub fn range<P: BalancePolicy>(token: &'a GhostToken<'brand>, tree: &'a TripodTree<'brand, T, P>, range: Range<usize>) -> Self {
+        let front = tree.cursor(token);
+        let back = front;
 
-        Self { range, cursor, }
+        Self { range, front, back, }
     }
 
-    //  Internal; converts position to element.
-    fn at(&self, index: usize) -> Option<&'a T> {
-        let mut cursor = self.cursor;
-        cursor.move_to(index);
+    //  Internal; converts position to element, moving `cursor` there.
+    fn at(cursor: &mut Cursor<'a, 'brand, T>, index: usize) -> Option<&'a T> {
+        match cursor.index() {
+            Some(current) if index >= current => cursor.move_by((index - current) as isize),
+            Some(current) => cursor.move_by(-((current - index) as isize)),
+            None => cursor.move_to(index),
+        }
+
         cursor.current()
     }
 }
@@ -42,7 +48,7 @@ impl<'a, 'brand, T> Iterator for Iter<'a, 'brand, T> {
 
     fn next(&mut self) -> Option<Self::Item> {
         let next = self.range.next();
-        next.and_then(|index| self.at(index))
+        next.and_then(|index| Self::at(&mut self.front, index))
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) { self.range.size_hint() }
@@ -52,29 +58,29 @@ impl<'a, 'brand, T> Iterator for Iter<'a, 'brand, T> {
     fn last(mut self) -> Option<Self::Item> {
         let range = mem::replace(&mut self.range, 0..0);
         let next = range.last();
-        next.and_then(|index| self.at(index))
+        next.and_then(|index| Self::at(&mut self.back, index))
     }
 
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
         let next = self.range.nth(n);
-        next.and_then(|index| self.at(index))
+        next.and_then(|index| Self::at(&mut self.front, index))
     }
 }
 
 impl<'a, 'brand, T> DoubleEndedIterator for Iter<'a, 'brand, T> {
     fn next_back(&mut self) -> Option<Self::Item> {
         let next = self.range.next_back();
-        next.and_then(|index| self.at(index))
+        next.and_then(|index| Self::at(&mut self.back, index))
     }
 
     fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
         let next = self.range.nth_back(n);
-        next.and_then(|index| self.at(index))
+        next.and_then(|index| Self::at(&mut self.back, index))
     }
 }
 
 impl<'a, 'brand, T> Clone for Iter<'a, 'brand, T> {
-    fn clone(&self) -> Self { Self { range: self.range.clone(), cursor: self.cursor, } }
+    fn clone(&self) -> Self { Self { range: self.range.clone(), front: self.front, back: self.back, } }
 }
 
 #[cfg(test)]
@@ -0,0 +1,130 @@
+use std::vec::Vec;
+
+use ghost_cell::GhostToken;
+use rayon::{
+    iter::{
+        IndexedParallelIterator,
+        IntoParallelIterator,
+        ParallelIterator,
+        plumbing::{Consumer, ProducerCallback, UnindexedConsumer},
+    },
+    vec::IntoIter,
+};
+
+use super::{BalancePolicy, TripodTree};
+
+/// A parallel iterator over a TripodTree.
+///
+/// The nodes of a TripodTree cannot be shared across threads, as their tripod pointer is held in a `Cell`, hence the
+/// references to the elements are gathered on the calling thread, and only those are processed in parallel.
+pub struct ParIter<'a, T> {
+    elements: Vec<&'a T>,
+}
+
+impl<'a, T> ParIter<'a, T> {
+    /// Creates a new instance, iterating over the entire tree.
+    pub fn new<'brand, P: BalancePolicy>(token: &'a GhostToken<'brand>, tree: &'a TripodTree<'brand, T, P>) -> Self {
+        let mut elements = Vec::with_capacity(tree.len(token));
+
+        let mut cursor = tree.cursor(token);
+        cursor.move_to_front();
+
+        while let Some(element) = cursor.current() {
+            elements.push(element);
+            cursor.move_next();
+        }
+
+        Self { elements, }
+    }
+
+    //  Internal; converts into the underlying parallel iterator.
+    fn into_inner(self) -> IntoIter<&'a T>
+    where
+        T: Sync,
+    {
+        self.elements.into_par_iter()
+    }
+}
+
+impl<'a, T: Sync> ParallelIterator for ParIter<'a, T> {
+    type Item = &'a T;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        self.into_inner().drive_unindexed(consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> { Some(self.elements.len()) }
+}
+
+impl<'a, T: Sync> IndexedParallelIterator for ParIter<'a, T> {
+    fn drive<C>(self, consumer: C) -> C::Result
+    where
+        C: Consumer<Self::Item>,
+    {
+        self.into_inner().drive(consumer)
+    }
+
+    fn len(&self) -> usize { self.elements.len() }
+
+    fn with_producer<CB>(self, callback: CB) -> CB::Output
+    where
+        CB: ProducerCallback<Self::Item>,
+    {
+        self.into_inner().with_producer(callback)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+use super::super::tests::*;
+use super::*;
+
+#[test]
+fn par_iter_empty() {
+    with_tree(&[], |token, tree| {
+        let iter = tree.par_iter(token);
+
+        assert_eq!(0, iter.len());
+        assert_eq!(0, iter.count());
+    });
+}
+
+#[test]
+fn par_iter_collect() {
+    const TREE: &[&str] = &["8", "4", "C", "2", "6", "A", "E", "1", "3", "5", "7", "9", "B", "D", "F"];
+
+    with_tree(TREE, |token, tree| {
+        let iter = tree.par_iter(token);
+
+        assert_eq!(15, iter.len());
+
+        let collected: Vec<&str> = iter.map(String::as_str).collect();
+
+        assert_eq!(&["1", "2", "3", "4", "5", "6", "7", "8", "9", "A", "B", "C", "D", "E", "F"][..], collected);
+    });
+}
+
+#[test]
+fn par_iter_fold() {
+    GhostToken::new(|mut token| {
+        let mut tree: TripodTree<'_, u64> = TripodTree::new();
+
+        for i in 0..10_000u64 {
+            tree.push_back(i, &mut token);
+        }
+
+        let sum: u64 = tree.par_iter(&token).sum();
+        let rev: Vec<u64> = tree.par_iter(&token).rev().take(3).copied().collect();
+
+        tree.clear(&mut token);
+
+        assert_eq!(49_995_000, sum);
+        assert_eq!(vec![9_999, 9_998, 9_997], rev);
+    });
+}
+
+} // mod tests
@@ -0,0 +1,292 @@
+use core::{
+    cell::Cell,
+    marker::PhantomData,
+    mem,
+};
+
+use ghost_cell::GhostToken;
+
+use super::{BalancePolicy, FullNodePtr, GhostNode, HalfNodePtr, Node, TripodTree};
+
+/// A pool of nodes, recycled from TripodTrees, for reuse by TripodTrees of the same brand.
+///
+/// Recycled nodes hold a default value until they are reused, hence recycling requires `T: Default`.
+///
+/// The recycled nodes are chained through their own pointers, hence the pool itself never allocates.
+pub struct NodePool<'brand, T> {
+    length: usize,
+    head: Option<FullNodePtr<'brand, T>>,
+}
+
+impl<'brand, T> NodePool<'brand, T> {
+    /// Creates an empty pool.
+    pub fn new() -> Self { Self::default() }
+
+    /// Returns whether the pool is empty, or not.
+    pub fn is_empty(&self) -> bool { self.head.is_none() }
+
+    /// Returns the number of nodes in the pool.
+    pub fn len(&self) -> usize { self.length }
+
+    /// Allocates `additional` nodes into the pool.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(`additional`).
+    /// -   Space: O(1).
+    ///
+    /// Exactly `additional` memory allocations occur.
+    pub fn reserve(&mut self, additional: usize)
+    where
+        T: Default,
+    {
+        for _ in 0..additional {
+            let node = Self::new_node(T::default());
+            self.push(node);
+        }
+    }
+
+    /// Releases all nodes of the pool.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(N) in the number of nodes.
+    /// -   Space: O(1).
+    pub fn clear(&mut self) {
+        while let Some(node) = self.pop() {
+            mem::drop(node);
+        }
+    }
+
+    //  Internal; takes a node from the pool, or allocates a new one, holding `value`.
+    pub(super) fn allocate(&mut self, value: T) -> FullNodePtr<'brand, T> {
+        if let Some(mut node) = self.pop() {
+            let inner = FullNodePtr::get_mut(&mut node).get_mut();
+            inner.size = 1;
+            inner.value = value;
+
+            node
+        } else {
+            Self::new_node(value)
+        }
+    }
+
+    //  Internal; keeps the node in the pool, and returns its value.
+    pub(super) fn recycle(&mut self, mut node: FullNodePtr<'brand, T>) -> T
+    where
+        T: Default,
+    {
+        let value = mem::take(&mut FullNodePtr::get_mut(&mut node).get_mut().value);
+        self.push(node);
+
+        value
+    }
+
+    //  Internal; allocates a new node.
+    fn new_node(value: T) -> FullNodePtr<'brand, T> {
+        let tripod = Cell::new(None);
+        FullNodePtr::new(GhostNode::new(Node { size: 1, rank: 0, value, up: None, left: None, right: None, tripod, }))
+    }
+
+    //  Internal; pushes the node on top of the pool, tucking the former top node into its pointers.
+    fn push(&mut self, mut node: FullNodePtr<'brand, T>) {
+        let inner = FullNodePtr::get_mut(&mut node).get_mut();
+
+        debug_assert!(inner.up.is_none());
+        debug_assert!(inner.left.is_none());
+        debug_assert!(inner.right.is_none());
+        debug_assert!(inner.tripod.get_mut().is_none());
+
+        if let Some(head) = self.head.take() {
+            let halves = FullNodePtr::split::<2, 2>(head);
+            let (up, tripod) = HalfNodePtr::split::<1, 1>(halves.0);
+            let (left, right) = HalfNodePtr::split::<1, 1>(halves.1);
+
+            inner.up = Some(up);
+            inner.left = Some(left);
+            inner.right = Some(right);
+            *inner.tripod.get_mut() = Some(tripod);
+        }
+
+        self.length += 1;
+        self.head = Some(node);
+    }
+
+    //  Internal; pops the node on top of the pool, if any, restoring the former top node.
+    fn pop(&mut self) -> Option<FullNodePtr<'brand, T>> {
+        let mut node = self.head.take()?;
+        let inner = FullNodePtr::get_mut(&mut node).get_mut();
+
+        if let Some(tripod) = inner.tripod.get_mut().take() {
+            let up = inner.up.take().expect("Pooled node - up");
+            let left = inner.left.take().expect("Pooled node - left");
+            let right = inner.right.take().expect("Pooled node - right");
+
+            let main = HalfNodePtr::join(up, tripod);
+            let children = HalfNodePtr::join(left, right);
+
+            self.head = Some(FullNodePtr::join(main, children));
+        }
+
+        self.length -= 1;
+
+        Some(node)
+    }
+}
+
+impl<'brand, T, P: BalancePolicy> TripodTree<'brand, T, P> {
+    /// Creates a tree with a single element, reusing a node from the pool, if any.
+    ///
+    /// A memory allocation occurs only if the pool is empty.
+    pub fn singleton_pooled(value: T, pool: &mut NodePool<'brand, T>, token: &mut GhostToken<'brand>) -> Self {
+        Self { root: Some(Self::from_full(pool.allocate(value), token)), policy: PhantomData, }
+    }
+
+    /// Pushes an element to the front of the tree, reusing a node from the pool, if any.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(log N) in the number of elements.
+    /// -   Space: O(1).
+    ///
+    /// A memory allocation occurs only if the pool is empty.
+    pub fn push_front_pooled(&mut self, value: T, pool: &mut NodePool<'brand, T>, token: &mut GhostToken<'brand>) {
+        let mut cursor = self.cursor_mut(token);
+        cursor.move_to_front();
+        cursor.insert_before_pooled(value, pool);
+    }
+
+    /// Removes and returns the front element of the tree, if any, recycling its node into the pool.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(log N) in the number of elements.
+    /// -   Space: O(1).
+    ///
+    /// No memory allocation nor deallocation occurs.
+    pub fn pop_front_pooled(&mut self, pool: &mut NodePool<'brand, T>, token: &mut GhostToken<'brand>) -> Option<T>
+    where
+        T: Default,
+    {
+        let mut cursor = self.cursor_mut(token);
+        cursor.move_to_front();
+        cursor.remove_current_pooled(pool)
+    }
+
+    /// Pushes an element to the back of the tree, reusing a node from the pool, if any.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(log N) in the number of elements.
+    /// -   Space: O(1).
+    ///
+    /// A memory allocation occurs only if the pool is empty.
+    pub fn push_back_pooled(&mut self, value: T, pool: &mut NodePool<'brand, T>, token: &mut GhostToken<'brand>) {
+        let mut cursor = self.cursor_mut(token);
+        cursor.move_to_back();
+        cursor.insert_after_pooled(value, pool);
+    }
+
+    /// Removes and returns the back element of the tree, if any, recycling its node into the pool.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(log N) in the number of elements.
+    /// -   Space: O(1).
+    ///
+    /// No memory allocation nor deallocation occurs.
+    pub fn pop_back_pooled(&mut self, pool: &mut NodePool<'brand, T>, token: &mut GhostToken<'brand>) -> Option<T>
+    where
+        T: Default,
+    {
+        let mut cursor = self.cursor_mut(token);
+        cursor.move_to_back();
+        cursor.remove_current_pooled(pool)
+    }
+}
+
+impl<'brand, T> Default for NodePool<'brand, T> {
+    fn default() -> Self { Self { length: 0, head: None, } }
+}
+
+impl<'brand, T> Drop for NodePool<'brand, T> {
+    fn drop(&mut self) { self.clear(); }
+}
+
+#[cfg(test)]
+mod tests {
+
+use super::super::tests::*;
+use super::*;
+
+#[test]
+fn pool_reserve_clear() {
+    let mut pool = NodePool::<'_, String>::new();
+
+    assert!(pool.is_empty());
+
+    pool.reserve(3);
+
+    assert!(!pool.is_empty());
+    assert_eq!(3, pool.len());
+
+    pool.clear();
+
+    assert!(pool.is_empty());
+    assert_eq!(0, pool.len());
+
+    pool.reserve(2);
+}
+
+#[test]
+fn pool_push_pop() {
+    with_tree(&["1", "0", "2"], |token, tree| {
+        let mut pool = NodePool::new();
+
+        assert_eq!(Some("0".to_string()), tree.pop_front_pooled(&mut pool, token));
+        assert_eq!(Some("2".to_string()), tree.pop_back_pooled(&mut pool, token));
+
+        assert_eq!(2, pool.len());
+        assert_tree(&["1"], tree.cursor(token));
+
+        tree.push_back_pooled("3".to_string(), &mut pool, token);
+        tree.push_front_pooled("4".to_string(), &mut pool, token);
+        tree.push_front_pooled("5".to_string(), &mut pool, token);
+
+        assert_eq!(0, pool.len());
+        assert_eq!(Ok(()), tree.check_invariants(token));
+
+        let elements: Vec<&str> = tree.iter(token).map(String::as_str).collect();
+        assert_eq!(&["5", "4", "1", "3"][..], elements);
+
+        while tree.pop_back_pooled(&mut pool, token).is_some() {}
+
+        assert_eq!(4, pool.len());
+        assert!(tree.is_empty());
+    });
+}
+
+#[test]
+fn pool_cursor() {
+    with_tree(&["1", "0", "2"], |token, tree| {
+        let mut pool = NodePool::new();
+
+        {
+            let mut cursor = tree.cursor_mut(token);
+
+            assert_eq!(Some("1".to_string()), cursor.remove_current_pooled(&mut pool));
+            assert_eq!(1, pool.len());
+
+            cursor.insert_after_pooled("3".to_string(), &mut pool);
+            cursor.insert_before_pooled("4".to_string(), &mut pool);
+        }
+
+        assert_eq!(0, pool.len());
+        assert_eq!(Ok(()), tree.check_invariants(token));
+
+        let elements: Vec<&str> = tree.iter(token).map(String::as_str).collect();
+        assert_eq!(&["0", "4", "2", "3"][..], elements);
+    });
+}
+
+} // mod tests
@@ -0,0 +1,241 @@
+use alloc::{string::String, vec::Vec};
+use core::{
+    fmt::{self, Write},
+    ops::Range,
+};
+
+use ghost_cell::GhostToken;
+
+use crate::rendering::write_dot_escaped;
+
+use super::{BalancePolicy, Side, TripodTree, Visit, Visitor};
+
+//  Rendering.
+//
+//  The renderings are meant for debugging, notably of balancing issues, hence each node is annotated with its index and
+//  the size of its sub-tree, and each child with its side.
+impl<'brand, T, P: BalancePolicy> TripodTree<'brand, T, P> {
+    /// Renders the tree in the Graphviz DOT format.
+    ///
+    /// Each node is labelled by `label`, annotated with its index and the size of the sub-tree rooted at it, and each
+    /// edge is labelled by the side of the child, `L` or `R`.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(N) in the number of elements.
+    /// -   Space: O(N) in the number of elements, for the output.
+    pub fn to_dot<F, L>(&self, label: F, token: &GhostToken<'brand>) -> String
+    where
+        F: FnMut(&T) -> L,
+        L: fmt::Display,
+    {
+        let mut visitor = DotVisitor { out: String::new(), label, };
+
+        visitor.out.push_str("digraph TripodTree {\n    node [shape=box];\n");
+
+        self.walk(&mut visitor, token);
+
+        visitor.out.push_str("}\n");
+        visitor.out
+    }
+
+    /// Renders the tree as ASCII-art, one element per line, children being indented below their parent.
+    ///
+    /// Each element is annotated with its index and the size of the sub-tree rooted at it, and each child with its
+    /// side, `L` or `R`.
+    ///
+    /// The indentation is capped at 32 levels, so that the output remains proportional to the number of elements even
+    /// for a degenerate tree: the outermost levels of deeper elements are elided, and replaced by `[+K] `, where K is
+    /// the number of levels elided.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(N) in the number of elements.
+    /// -   Space: O(N + H) in the number of elements N and the height H of the tree, for the output.
+    pub fn to_ascii_tree(&self, token: &GhostToken<'brand>) -> String
+    where
+        T: fmt::Display,
+    {
+        let mut visitor = AsciiVisitor { out: String::new(), path: Vec::new(), };
+
+        self.walk(&mut visitor, token);
+
+        visitor.out
+    }
+}
+
+//
+//  Implementation
+//
+
+//  Internal; returns the index of the parent of the visited node, if any.
+fn parent_index<T>(visit: &Visit<'_, T>) -> Option<usize> {
+    match visit.side? {
+        Side::Left => Some(visit.range.end),
+        Side::Right => Some(visit.range.start - 1),
+    }
+}
+
+//  Internal; returns the label of the side.
+fn side_label(side: Side) -> &'static str {
+    match side {
+        Side::Left => "L",
+        Side::Right => "R",
+    }
+}
+
+struct DotVisitor<F> {
+    out: String,
+    label: F,
+}
+
+impl<'a, T, F, L> Visitor<'a, T> for DotVisitor<F>
+where
+    F: FnMut(&T) -> L,
+    L: fmt::Display,
+{
+    fn pre_order(&mut self, visit: Visit<'a, T>) {
+        write!(self.out, "    n{} [label=\"", visit.index).expect("Infallible - writing to a String");
+        write_dot_escaped(&mut self.out, (self.label)(visit.element));
+        writeln!(self.out, "\\nindex: {}, size: {}\"];", visit.index, visit.range.len()).expect("Infallible - writing to a String");
+
+        if let (Some(parent), Some(side)) = (parent_index(&visit), visit.side) {
+            writeln!(self.out, "    n{} -> n{} [label=\"{}\"];", parent, visit.index, side_label(side))
+                .expect("Infallible - writing to a String");
+        }
+    }
+}
+
+//  The maximum number of indentation levels rendered by `to_ascii_tree`.
+const MAX_INDENT: usize = 32;
+
+struct AsciiVisitor {
+    out: String,
+    //  The range of each node from the root to the parent of the visited node, and whether it is its parent's last child.
+    path: Vec<(Range<usize>, bool)>,
+}
+
+impl<'a, T: fmt::Display> Visitor<'a, T> for AsciiVisitor {
+    fn pre_order(&mut self, visit: Visit<'a, T>) {
+        self.path.truncate(visit.depth);
+
+        let is_last = match (parent_index(&visit), visit.side) {
+            (Some(parent), Some(Side::Left)) => {
+                let (parent_range, _) = self.path.last().expect("Parent of a child");
+                parent + 1 == parent_range.end
+            },
+            _ => true,
+        };
+
+        //  The root has no connector, hence neither do its descendants in its column.
+        let columns = self.path.len().saturating_sub(1);
+        let elided = columns.saturating_sub(MAX_INDENT);
+
+        if elided > 0 {
+            write!(self.out, "[+{}] ", elided).expect("Infallible - writing to a String");
+        }
+
+        for (_, last) in self.path.iter().skip(1 + elided) {
+            self.out.push_str(if *last { "    " } else { "|   " });
+        }
+
+        if let Some(side) = visit.side {
+            self.out.push_str(if is_last { "`-- " } else { "|-- " });
+            self.out.push_str(side_label(side));
+            self.out.push_str(": ");
+        }
+
+        writeln!(self.out, "{} (index: {}, size: {})", visit.element, visit.index, visit.range.len())
+            .expect("Infallible - writing to a String");
+
+        self.path.push((visit.range, is_last));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+use super::super::{tests::*, Unbalanced};
+use super::*;
+
+//  A lopsided tree, with missing children on either side:
+//
+//          3
+//      1       4
+//    0   2   -   5
+const LOPSIDED: &[&str] = &["3", "1", "4", "0", "2", "-", "5"];
+
+#[test]
+fn rendering_empty() {
+    with_tree(&[], |token, tree| {
+        assert_eq!("digraph TripodTree {\n    node [shape=box];\n}\n", tree.to_dot(|e| e.clone(), token));
+        assert_eq!("", tree.to_ascii_tree(token));
+    });
+}
+
+#[test]
+fn rendering_dot() {
+    with_tree(&["1", "0", "\"2\""], |token, tree| {
+        let expected = concat!(
+            "digraph TripodTree {\n",
+            "    node [shape=box];\n",
+            "    n1 [label=\"1\\nindex: 1, size: 3\"];\n",
+            "    n0 [label=\"0\\nindex: 0, size: 1\"];\n",
+            "    n1 -> n0 [label=\"L\"];\n",
+            "    n2 [label=\"\\\"2\\\"\\nindex: 2, size: 1\"];\n",
+            "    n1 -> n2 [label=\"R\"];\n",
+            "}\n",
+        );
+
+        assert_eq!(expected, tree.to_dot(|e| e.clone(), token));
+    });
+}
+
+#[test]
+fn rendering_dot_label() {
+    with_tree(&["1", "0", "2"], |token, tree| {
+        let dot = tree.to_dot(|e| e.len(), token);
+
+        assert!(dot.contains("n2 [label=\"1\\nindex: 2, size: 1\"];"), "{}", dot);
+    });
+}
+
+#[test]
+fn rendering_ascii_tree() {
+    with_tree(LOPSIDED, |token, tree| {
+        let expected = concat!(
+            "3 (index: 3, size: 6)\n",
+            "|-- L: 1 (index: 1, size: 3)\n",
+            "|   |-- L: 0 (index: 0, size: 1)\n",
+            "|   `-- R: 2 (index: 2, size: 1)\n",
+            "`-- R: 4 (index: 4, size: 2)\n",
+            "    `-- R: 5 (index: 5, size: 1)\n",
+        );
+
+        assert_eq!(expected, tree.to_ascii_tree(token));
+    });
+}
+
+#[test]
+fn rendering_ascii_tree_deep() {
+    GhostToken::new(|mut token| {
+        //  A left "vine", as deep as it is long.
+        let mut tree: TripodTree<'_, usize, Unbalanced> = TripodTree::new();
+
+        for i in 0..40 {
+            tree.push_vine(i, &mut token);
+        }
+
+        let ascii = tree.to_ascii_tree(&token);
+        let lines: Vec<_> = ascii.lines().collect();
+
+        assert_eq!(40, lines.len());
+        assert_eq!(format!("{}`-- L: 6 (index: 6, size: 7)", "    ".repeat(32)), lines[33]);
+        assert_eq!(format!("[+1] {}`-- L: 5 (index: 5, size: 6)", "    ".repeat(32)), lines[34]);
+        assert_eq!(format!("[+6] {}`-- L: 0 (index: 0, size: 1)", "    ".repeat(32)), lines[39]);
+
+        tree.clear(&mut token);
+    });
+}
+
+} // mod tests
@@ -0,0 +1,418 @@
+//  Snapshots of the tree, preserving its exact shape.
+//
+//  Format:
+//
+//  -   Header: the magic bytes `GCTT`, followed by the version, a single byte, currently 1.
+//  -   Length: the number of elements, N, as a LEB128 variable-length integer.
+//  -   Nodes: the N nodes, in pre-order, each being the size of its left sub-tree as a LEB128 variable-length integer,
+//      followed by its value, as encoded by the codec.
+//
+//  The size of the right sub-tree of each node is implied by the size of the sub-tree the node is the root of, which is
+//  N for the root, and the size recorded for, or implied for, each child.
+
+use core::{
+    convert::TryFrom,
+    marker::PhantomData,
+};
+
+use std::io::{self, Read, Write};
+
+use ghost_cell::GhostToken;
+
+use super::{GhostNode, QuarterNodePtr, Side, TripodTree};
+
+const MAGIC: [u8; 4] = *b"GCTT";
+const VERSION: u8 = 1;
+
+/// A codec, to encode and decode the values of a `TripodTree` snapshot.
+pub trait SnapshotCodec<T> {
+    /// Encodes the value into the writer.
+    fn encode<W: Write>(&mut self, value: &T, writer: &mut W) -> io::Result<()>;
+
+    /// Decodes a value from the reader.
+    fn decode<R: Read>(&mut self, reader: &mut R) -> io::Result<T>;
+}
+
+//  Snapshots are only available with the default `WeightBalanced` policy, the balance of each sub-tree being checked
+//  from its size prior to reading it, which bounds the recursion depth when reading untrusted snapshots.
+impl<'brand, T> TripodTree<'brand, T> {
+    /// Writes a snapshot of the tree, preserving its exact shape, with the values encoded by the codec.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(N) in the number of elements, plus the cost of encoding.
+    /// -   Space: O(1).
+    pub fn write_snapshot<W, C>(&self, writer: &mut W, codec: &mut C, token: &GhostToken<'brand>) -> io::Result<()>
+    where
+        W: Write,
+        C: SnapshotCodec<T>,
+    {
+        writer.write_all(&MAGIC)?;
+        writer.write_all(&[VERSION])?;
+
+        write_length(self.len(token), writer)?;
+
+        if let Some(root) = self.root.as_ref() {
+            write_nodes(root, writer, codec, token)?;
+        }
+
+        Ok(())
+    }
+
+    /// Reads a snapshot written by `write_snapshot`, recreating the exact shape of the tree, with the values decoded by
+    /// the codec.
+    ///
+    /// Returns an error of kind `InvalidData` if the snapshot is not a valid snapshot of a balanced tree.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(N) in the number of elements, plus the cost of decoding.
+    /// -   Space: O(log N) in the number of elements.
+    ///
+    /// Exactly N memory allocations occur, and no rebalancing.
+    pub fn read_snapshot<R, C>(reader: &mut R, codec: &mut C, token: &mut GhostToken<'brand>) -> io::Result<Self>
+    where
+        R: Read,
+        C: SnapshotCodec<T>,
+    {
+        let mut header = [0; 5];
+        reader.read_exact(&mut header)?;
+
+        if header[..4] != MAGIC {
+            return Err(invalid_data("not a TripodTree snapshot"));
+        }
+
+        if header[4] != VERSION {
+            return Err(invalid_data("unsupported TripodTree snapshot version"));
+        }
+
+        let length = read_length(reader)?;
+
+        let root = if length > 0 { Some(read_node(length, reader, codec, token)?) } else { None };
+
+        Ok(Self { root, policy: PhantomData, })
+    }
+}
+
+//
+//  Implementation
+//
+
+//  Internal; writes the nodes of the tree, in pre-order.
+//
+//  The traversal climbs back through the up pointers rather than recursing, hence its space does not depend on the depth
+//  of the tree.
+fn write_nodes<'brand, T, W, C>(root: &GhostNode<'brand, T>, writer: &mut W, codec: &mut C, token: &GhostToken<'brand>)
+    -> io::Result<()>
+where
+    W: Write,
+    C: SnapshotCodec<T>,
+{
+    let mut node = root;
+
+    //  O(N) iterations, each visiting a node, or climbing back up from a node.
+    loop {
+        let current = node.borrow(token);
+
+        write_length(current.left_size(token), writer)?;
+        codec.encode(&current.value, writer)?;
+
+        if let Some(child) = current.left().or_else(|| current.right()) {
+            node = child;
+            continue;
+        }
+
+        //  Leaf, climb until there is an unvisited right sub-tree.
+        loop {
+            let current = node.borrow(token);
+
+            let parent = if let Some(parent) = current.up() { parent } else { return Ok(()) };
+            let parent_node = parent.borrow(token);
+
+            if current.is_child_of(parent_node) == Some(Side::Left) {
+                if let Some(right) = parent_node.right() {
+                    node = right;
+                    break;
+                }
+            }
+
+            node = parent;
+        }
+    }
+}
+
+//  Internal; the recursion depth is bounded by the depth of the tree, which is O(log N) as its balance is checked.
+//
+//  On error, the partially read sub-tree is cleared.
+fn read_node<'brand, T, R, C>(size: usize, reader: &mut R, codec: &mut C, token: &mut GhostToken<'brand>)
+    -> io::Result<QuarterNodePtr<'brand, T>>
+where
+    R: Read,
+    C: SnapshotCodec<T>,
+{
+    debug_assert!(size > 0);
+
+    let left_size = read_length(reader)?;
+
+    if left_size >= size {
+        return Err(invalid_data("sub-tree larger than its parent"));
+    }
+
+    let right_size = size - 1 - left_size;
+
+    if left_size > 2 * right_size + 1 || right_size > 2 * left_size + 1 {
+        return Err(invalid_data("unbalanced sub-tree"));
+    }
+
+    let node = TripodTree::<'brand, T>::from_value(codec.decode(reader)?, token);
+    node.borrow_mut(token).size = size;
+
+    for (side, child_size) in [(Side::Left, left_size), (Side::Right, right_size)].iter().copied() {
+        if child_size == 0 {
+            continue;
+        }
+
+        match read_node(child_size, reader, codec, token) {
+            Ok(child) => {
+                let node_from_child = node.borrow_mut(token).child_mut(side).take();
+                child.borrow_mut(token).up = node_from_child;
+                node.borrow_mut(token).set_child(side, child);
+            },
+            Err(error) => {
+                TripodTree::<'brand, T> { root: Some(node), policy: PhantomData, }.clear(token);
+                return Err(error);
+            },
+        }
+    }
+
+    Ok(node)
+}
+
+fn write_length<W: Write>(length: usize, writer: &mut W) -> io::Result<()> {
+    //  LEB128: 7 bits at a time, least significant first, with the high bit set on all but the last byte.
+    let mut buffer = [0u8; 10];
+    let mut length = length as u64;
+    let mut count = 0;
+
+    loop {
+        let byte = (length & 0x7F) as u8;
+        length >>= 7;
+
+        if length == 0 {
+            buffer[count] = byte;
+            count += 1;
+            break;
+        }
+
+        buffer[count] = byte | 0x80;
+        count += 1;
+    }
+
+    writer.write_all(&buffer[..count])
+}
+
+fn read_length<R: Read>(reader: &mut R) -> io::Result<usize> {
+    let mut length = 0u64;
+
+    for shift in (0..64).step_by(7) {
+        let mut byte = [0u8; 1];
+        reader.read_exact(&mut byte)?;
+
+        let bits = u64::from(byte[0] & 0x7F);
+
+        if shift == 63 && bits > 1 {
+            break;
+        }
+
+        length |= bits << shift;
+
+        if byte[0] & 0x80 == 0 {
+            return usize::try_from(length).map_err(|_| invalid_data("length overflow"));
+        }
+    }
+
+    Err(invalid_data("length overflow"))
+}
+
+fn invalid_data(message: &'static str) -> io::Error { io::Error::new(io::ErrorKind::InvalidData, message) }
+
+#[cfg(test)]
+mod tests {
+
+use std::panic::{self, AssertUnwindSafe};
+
+use super::super::tests::*;
+use super::*;
+
+#[test]
+fn snapshot_empty() {
+    round_trip(&[]);
+}
+
+#[test]
+fn snapshot_single() {
+    round_trip(&["Root"]);
+}
+
+#[test]
+fn snapshot_shapes() {
+    round_trip(&["Root", "L", "R"]);
+    round_trip(&["Root", "L", "R", "LL", "-", "-", "RR"]);
+    round_trip(&["Root", "L", "R", "-", "LR", "RL"]);
+    round_trip(&["Root", "L", "R", "LL", "LR", "RL", "RR"]);
+}
+
+#[test]
+fn snapshot_large() {
+    GhostToken::new(|mut token| {
+        let mut tree = TripodTree::new();
+
+        for i in 0..1000 {
+            tree.push_back(i.to_string(), &mut token);
+        }
+
+        let expected = flatten(tree.cursor(&token));
+
+        let mut snapshot = Vec::new();
+        tree.write_snapshot(&mut snapshot, &mut StringCodec, &token).expect("Success");
+
+        tree.clear(&mut token);
+
+        let mut tree = TripodTree::read_snapshot(&mut &snapshot[..], &mut StringCodec, &mut token).expect("Success");
+
+        assert_eq!(expected, flatten(tree.cursor(&token)));
+        assert_eq!(Ok(()), tree.check_invariants(&token));
+
+        tree.clear(&mut token);
+    });
+}
+
+#[test]
+fn snapshot_truncated() {
+    let snapshot = write(&["Root", "L", "R", "LL", "LR", "RL", "RR"]);
+
+    for length in 0..snapshot.len() {
+        GhostToken::new(|mut token| {
+            let result = TripodTree::<String>::read_snapshot(&mut &snapshot[..length], &mut StringCodec, &mut token);
+
+            assert_eq!(io::ErrorKind::UnexpectedEof, result.err().expect("Error").kind());
+        });
+    }
+}
+
+#[test]
+fn snapshot_invalid_header() {
+    let mut snapshot = write(&["Root"]);
+    snapshot[0] = b'X';
+
+    assert_eq!(io::ErrorKind::InvalidData, read_error(&snapshot));
+
+    let mut snapshot = write(&["Root"]);
+    snapshot[4] = VERSION + 1;
+
+    assert_eq!(io::ErrorKind::InvalidData, read_error(&snapshot));
+}
+
+#[test]
+fn snapshot_invalid_size() {
+    //  Root claims a left sub-tree of 3 elements, out of 3.
+    let mut snapshot = write(&["Root", "L", "R"]);
+    snapshot[6] = 3;
+
+    assert_eq!(io::ErrorKind::InvalidData, read_error(&snapshot));
+}
+
+#[test]
+fn snapshot_unbalanced() {
+    //  Root claims a left sub-tree of 4 elements, out of 5, leaving 0 for its right sub-tree.
+    let snapshot = {
+        let mut snapshot = MAGIC.to_vec();
+        snapshot.push(VERSION);
+        snapshot.push(5);
+
+        for left_size in [4, 3, 2, 1, 0].iter().copied() {
+            snapshot.push(left_size);
+            snapshot.extend_from_slice(b"\x01A");
+        }
+
+        snapshot
+    };
+
+    assert_eq!(io::ErrorKind::InvalidData, read_error(&snapshot));
+}
+
+#[test]
+fn snapshot_length() {
+    for length in [0, 1, 127, 128, 255, 16_383, 16_384, usize::MAX].iter().copied() {
+        let mut buffer = Vec::new();
+        write_length(length, &mut buffer).expect("Success");
+
+        assert_eq!(length, read_length(&mut &buffer[..]).expect("Success"));
+    }
+
+    //  11 bytes is too many.
+    let buffer = [0xFFu8; 11];
+    assert_eq!(io::ErrorKind::InvalidData, read_length(&mut &buffer[..]).expect_err("Error").kind());
+}
+
+//
+//  Helpers
+//
+
+struct StringCodec;
+
+impl SnapshotCodec<String> for StringCodec {
+    fn encode<W: Write>(&mut self, value: &String, writer: &mut W) -> io::Result<()> {
+        let length = u8::try_from(value.len()).map_err(|_| invalid_data("string too long"))?;
+
+        writer.write_all(&[length])?;
+        writer.write_all(value.as_bytes())
+    }
+
+    fn decode<R: Read>(&mut self, reader: &mut R) -> io::Result<String> {
+        let mut length = [0u8; 1];
+        reader.read_exact(&mut length)?;
+
+        let mut buffer = vec![0u8; length[0] as usize];
+        reader.read_exact(&mut buffer)?;
+
+        String::from_utf8(buffer).map_err(|_| invalid_data("not UTF-8"))
+    }
+}
+
+#[track_caller]
+fn round_trip(flat: &[&str]) {
+    let snapshot = write(flat);
+
+    GhostToken::new(|mut token| {
+        let mut tree = TripodTree::read_snapshot(&mut &snapshot[..], &mut StringCodec, &mut token).expect("Success");
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            assert_tree(flat, tree.cursor(&token));
+            assert_eq!(Ok(()), tree.check_invariants(&token));
+        }));
+
+        tree.clear(&mut token);
+
+        result.expect("No Panic")
+    });
+}
+
+fn write(flat: &[&str]) -> Vec<u8> {
+    with_tree(flat, |token, tree| {
+        let mut snapshot = Vec::new();
+        tree.write_snapshot(&mut snapshot, &mut StringCodec, token).expect("Success");
+
+        snapshot
+    })
+}
+
+fn read_error(snapshot: &[u8]) -> io::ErrorKind {
+    GhostToken::new(|mut token| {
+        let result = TripodTree::<String>::read_snapshot(&mut &snapshot[..], &mut StringCodec, &mut token);
+
+        result.err().expect("Error").kind()
+    })
+}
+
+} // mod tests
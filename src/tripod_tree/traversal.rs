@@ -0,0 +1,451 @@
+use core::{
+    fmt,
+    ops::Range,
+};
+
+use ghost_cell::GhostToken;
+
+use super::{BalancePolicy, Cursor, Side, TripodTree};
+
+/// A node of the TripodTree, as visited during a `TripodTree::walk`.
+pub struct Visit<'a, T> {
+    /// The element of the node.
+    pub element: &'a T,
+    /// The side of the node, relative to its parent, or None for the root.
+    pub side: Option<Side>,
+    /// The depth of the node, 0 for the root.
+    pub depth: usize,
+    /// The index of the element in the tree.
+    pub index: usize,
+    /// The range of indices covered by the sub-tree rooted at the node.
+    pub range: Range<usize>,
+}
+
+impl<'a, T> Clone for Visit<'a, T> {
+    fn clone(&self) -> Self {
+        Self { element: self.element, side: self.side, depth: self.depth, index: self.index, range: self.range.clone(), }
+    }
+}
+
+impl<'a, T: fmt::Debug> fmt::Debug for Visit<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Visit")
+            .field("element", self.element)
+            .field("side", &self.side)
+            .field("depth", &self.depth)
+            .field("index", &self.index)
+            .field("range", &self.range)
+            .finish()
+    }
+}
+
+/// A visitor of the nodes of a TripodTree, see `TripodTree::walk`.
+///
+/// Each node is visited thrice: before its children, between its children, and after its children. All callbacks do
+/// nothing by default.
+pub trait Visitor<'a, T> {
+    /// Invoked on reaching the node, prior to visiting its left sub-tree.
+    fn pre_order(&mut self, _visit: Visit<'a, T>) {}
+
+    /// Invoked after visiting the left sub-tree of the node, prior to visiting its right sub-tree.
+    fn in_order(&mut self, _visit: Visit<'a, T>) {}
+
+    /// Invoked after visiting the right sub-tree of the node, prior to leaving it.
+    fn post_order(&mut self, _visit: Visit<'a, T>) {}
+}
+
+/// A pre-order iterator over a TripodTree: each element is yielded before the elements of its left sub-tree, which are
+/// yielded before those of its right sub-tree.
+pub struct PreOrder<'a, 'brand, T> {
+    walker: Walker<'a, 'brand, T>,
+    remaining: usize,
+}
+
+impl<'a, 'brand, T> PreOrder<'a, 'brand, T> {
+    /// Creates a new instance, iterating over the entire tree.
+    pub fn new<P: BalancePolicy>(token: &'a GhostToken<'brand>, tree: &'a TripodTree<'brand, T, P>) -> Self {
+        Self { walker: Walker::new(tree.cursor(token), usize::MAX), remaining: tree.len(token), }
+    }
+}
+
+impl<'a, 'brand, T> Iterator for PreOrder<'a, 'brand, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (order, visit) = self.walker.next()?;
+
+            if order == Order::Pre {
+                self.remaining -= 1;
+                return Some(visit.element);
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) { (self.remaining, Some(self.remaining)) }
+}
+
+impl<'a, 'brand, T> Clone for PreOrder<'a, 'brand, T> {
+    fn clone(&self) -> Self { Self { walker: self.walker, remaining: self.remaining, } }
+}
+
+/// A post-order iterator over a TripodTree: the elements of the left sub-tree of an element are yielded before those
+/// of its right sub-tree, which are yielded before the element itself.
+pub struct PostOrder<'a, 'brand, T> {
+    walker: Walker<'a, 'brand, T>,
+    remaining: usize,
+}
+
+impl<'a, 'brand, T> PostOrder<'a, 'brand, T> {
+    /// Creates a new instance, iterating over the entire tree.
+    pub fn new<P: BalancePolicy>(token: &'a GhostToken<'brand>, tree: &'a TripodTree<'brand, T, P>) -> Self {
+        Self { walker: Walker::new(tree.cursor(token), usize::MAX), remaining: tree.len(token), }
+    }
+}
+
+impl<'a, 'brand, T> Iterator for PostOrder<'a, 'brand, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (order, visit) = self.walker.next()?;
+
+            if order == Order::Post {
+                self.remaining -= 1;
+                return Some(visit.element);
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) { (self.remaining, Some(self.remaining)) }
+}
+
+impl<'a, 'brand, T> Clone for PostOrder<'a, 'brand, T> {
+    fn clone(&self) -> Self { Self { walker: self.walker, remaining: self.remaining, } }
+}
+
+/// A level-order, or breadth-first, iterator over a TripodTree: the elements are yielded by increasing depth, and from
+/// front to back within a given depth.
+pub struct LevelOrder<'a, 'brand, T> {
+    //  A cursor pointing at the root of the tree, from which each walk starts.
+    root: Cursor<'a, 'brand, T>,
+    walker: Walker<'a, 'brand, T>,
+    //  Whether at least one element was found at the depth of the current walk, in which case the next depth is worth
+    //  walking too.
+    found: bool,
+    remaining: usize,
+}
+
+impl<'a, 'brand, T> LevelOrder<'a, 'brand, T> {
+    /// Creates a new instance, iterating over the entire tree.
+    pub fn new<P: BalancePolicy>(token: &'a GhostToken<'brand>, tree: &'a TripodTree<'brand, T, P>) -> Self {
+        let root = tree.cursor(token);
+
+        Self { root, walker: Walker::new(root, 0), found: false, remaining: tree.len(token), }
+    }
+}
+
+impl<'a, 'brand, T> Iterator for LevelOrder<'a, 'brand, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.walker.next() {
+                Some((Order::Pre, visit)) if visit.depth == self.walker.limit => {
+                    self.found = true;
+                    self.remaining -= 1;
+                    return Some(visit.element);
+                },
+                Some(_) => continue,
+                None if self.found => {
+                    self.found = false;
+                    self.walker = Walker::new(self.root, self.walker.limit + 1);
+                },
+                None => return None,
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) { (self.remaining, Some(self.remaining)) }
+}
+
+impl<'a, 'brand, T> Clone for LevelOrder<'a, 'brand, T> {
+    fn clone(&self) -> Self {
+        Self { root: self.root, walker: self.walker, found: self.found, remaining: self.remaining, }
+    }
+}
+
+impl<'brand, T, P: BalancePolicy> TripodTree<'brand, T, P> {
+    /// Walks the tree, depth-first, invoking the callbacks of `visitor` on each node.
+    ///
+    /// The walk relies on the `up` pointers of the nodes to climb back, hence requires no memory allocation.
+    ///
+    /// #   Complexity
+    ///
+    /// -   Time: O(N) in the number of elements.
+    /// -   Space: O(1).
+    pub fn walk<'a, V>(&'a self, visitor: &mut V, token: &'a GhostToken<'brand>)
+    where
+        V: Visitor<'a, T>,
+    {
+        let mut walker = Walker::new(self.cursor(token), usize::MAX);
+
+        while let Some((order, visit)) = walker.next() {
+            match order {
+                Order::Pre => visitor.pre_order(visit),
+                Order::In => visitor.in_order(visit),
+                Order::Post => visitor.post_order(visit),
+            }
+        }
+    }
+}
+
+//
+//  Implementation
+//
+
+//  The order in which a node is visited, relative to its children.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Order {
+    Pre,
+    In,
+    Post,
+}
+
+//  A stack-less depth-first walk of the tree, climbing back through the `up` pointers.
+//
+//  Each node is visited thrice, in order Pre, In and Post, and each edge is traversed twice, once down and once up,
+//  hence a complete walk is O(N).
+//
+//  The walk does not descend below `limit`, treating the nodes at that depth as leaves.
+struct Walker<'a, 'brand, T> {
+    cursor: Cursor<'a, 'brand, T>,
+    depth: usize,
+    order: Order,
+    limit: usize,
+}
+
+impl<'a, 'brand, T> Walker<'a, 'brand, T> {
+    //  Internal; creates a walker starting at the root of the tree, pointed to by `root`.
+    fn new(root: Cursor<'a, 'brand, T>, limit: usize) -> Self {
+        Self { cursor: root, depth: 0, order: Order::Pre, limit, }
+    }
+
+    //  Internal; returns the current visit, if any, then advances to the next.
+    fn next(&mut self) -> Option<(Order, Visit<'a, T>)> {
+        let element = self.cursor.current()?;
+
+        let order = self.order;
+        let side = self.cursor.side();
+        let visit = Visit { element, side, depth: self.depth, index: self.cursor.index()?, range: self.cursor.range(), };
+
+        match order {
+            Order::Pre => {
+                if !self.descend(Side::Left) {
+                    self.order = Order::In;
+                }
+            },
+            Order::In => {
+                if !self.descend(Side::Right) {
+                    self.order = Order::Post;
+                }
+            },
+            Order::Post => {
+                //  Moving up from the root ends the walk, as the cursor then points to the "twilight" non-element.
+                self.cursor.move_up();
+                self.depth = self.depth.saturating_sub(1);
+
+                self.order = match side {
+                    Some(Side::Left) => Order::In,
+                    _ => Order::Post,
+                };
+            },
+        }
+
+        Some((order, visit))
+    }
+
+    //  Internal; descends to the child on the given side, if any, and unless at the limit.
+    fn descend(&mut self, side: Side) -> bool {
+        if self.depth >= self.limit || self.cursor.try_move_down(side).is_none() {
+            return false;
+        }
+
+        self.depth += 1;
+        self.order = Order::Pre;
+
+        true
+    }
+}
+
+impl<'a, 'brand, T> Clone for Walker<'a, 'brand, T> {
+    fn clone(&self) -> Self { *self }
+}
+
+impl<'a, 'brand, T> Copy for Walker<'a, 'brand, T> {}
+
+#[cfg(test)]
+mod tests {
+
+use std::vec::Vec;
+
+use super::super::tests::*;
+use super::*;
+
+const TREE: &[&str] = &["8", "4", "C", "2", "6", "A", "E", "1", "3", "5", "7", "9", "B", "D", "F"];
+
+//  A lopsided tree, with missing children on either side:
+//
+//          3
+//      1       4
+//    0   2   -   5
+const LOPSIDED: &[&str] = &["3", "1", "4", "0", "2", "-", "5"];
+
+fn collect<'a, I>(iterator: I) -> Vec<&'a str>
+where
+    I: Iterator<Item = &'a String>,
+{
+    iterator.map(String::as_str).collect()
+}
+
+#[test]
+fn traversal_empty() {
+    with_tree(&[], |token, tree| {
+        assert_eq!(0, tree.iter_preorder(token).count());
+        assert_eq!(0, tree.iter_postorder(token).count());
+        assert_eq!(0, tree.iter_level_order(token).count());
+
+        let mut recorder = Recorder::default();
+        tree.walk(&mut recorder, token);
+
+        assert!(recorder.events.is_empty());
+    });
+}
+
+#[test]
+fn traversal_preorder() {
+    with_tree(TREE, |token, tree| {
+        let iterator = tree.iter_preorder(token);
+
+        assert_eq!((15, Some(15)), iterator.size_hint());
+        assert_eq!(
+            &["8", "4", "2", "1", "3", "6", "5", "7", "C", "A", "9", "B", "E", "D", "F"][..],
+            collect(iterator)
+        );
+    });
+
+    with_tree(LOPSIDED, |token, tree| {
+        assert_eq!(&["3", "1", "0", "2", "4", "5"][..], collect(tree.iter_preorder(token)));
+    });
+}
+
+#[test]
+fn traversal_postorder() {
+    with_tree(TREE, |token, tree| {
+        let iterator = tree.iter_postorder(token);
+
+        assert_eq!((15, Some(15)), iterator.size_hint());
+        assert_eq!(
+            &["1", "3", "2", "5", "7", "6", "4", "9", "B", "A", "D", "F", "E", "C", "8"][..],
+            collect(iterator)
+        );
+    });
+
+    with_tree(LOPSIDED, |token, tree| {
+        assert_eq!(&["0", "2", "1", "5", "4", "3"][..], collect(tree.iter_postorder(token)));
+    });
+}
+
+#[test]
+fn traversal_level_order() {
+    with_tree(TREE, |token, tree| {
+        let iterator = tree.iter_level_order(token);
+
+        assert_eq!((15, Some(15)), iterator.size_hint());
+        assert_eq!(
+            &["8", "4", "C", "2", "6", "A", "E", "1", "3", "5", "7", "9", "B", "D", "F"][..],
+            collect(iterator)
+        );
+    });
+
+    with_tree(LOPSIDED, |token, tree| {
+        assert_eq!(&["3", "1", "4", "0", "2", "5"][..], collect(tree.iter_level_order(token)));
+    });
+}
+
+//  (order, element, side, depth, index, range)
+type Event = (&'static str, String, Option<Side>, usize, usize, Range<usize>);
+
+#[derive(Default)]
+struct Recorder {
+    events: Vec<Event>,
+}
+
+impl Recorder {
+    fn record(&mut self, order: &'static str, visit: Visit<'_, String>) {
+        self.events.push((order, visit.element.clone(), visit.side, visit.depth, visit.index, visit.range));
+    }
+}
+
+impl<'a> Visitor<'a, String> for Recorder {
+    fn pre_order(&mut self, visit: Visit<'a, String>) { self.record("pre", visit); }
+
+    fn in_order(&mut self, visit: Visit<'a, String>) { self.record("in", visit); }
+
+    fn post_order(&mut self, visit: Visit<'a, String>) { self.record("post", visit); }
+}
+
+#[test]
+fn traversal_walk() {
+    with_tree(LOPSIDED, |token, tree| {
+        let mut recorder = Recorder::default();
+        tree.walk(&mut recorder, token);
+
+        let expected = [
+            ("pre", "3", None, 0, 3, 0..6),
+            ("pre", "1", Some(Side::Left), 1, 1, 0..3),
+            ("pre", "0", Some(Side::Left), 2, 0, 0..1),
+            ("in", "0", Some(Side::Left), 2, 0, 0..1),
+            ("post", "0", Some(Side::Left), 2, 0, 0..1),
+            ("in", "1", Some(Side::Left), 1, 1, 0..3),
+            ("pre", "2", Some(Side::Right), 2, 2, 2..3),
+            ("in", "2", Some(Side::Right), 2, 2, 2..3),
+            ("post", "2", Some(Side::Right), 2, 2, 2..3),
+            ("post", "1", Some(Side::Left), 1, 1, 0..3),
+            ("in", "3", None, 0, 3, 0..6),
+            ("pre", "4", Some(Side::Right), 1, 4, 4..6),
+            ("in", "4", Some(Side::Right), 1, 4, 4..6),
+            ("pre", "5", Some(Side::Right), 2, 5, 5..6),
+            ("in", "5", Some(Side::Right), 2, 5, 5..6),
+            ("post", "5", Some(Side::Right), 2, 5, 5..6),
+            ("post", "4", Some(Side::Right), 1, 4, 4..6),
+            ("post", "3", None, 0, 3, 0..6),
+        ];
+
+        let expected: Vec<_> = expected.iter()
+            .map(|(order, element, side, depth, index, range)| (*order, element.to_string(), *side, *depth, *index, range.clone()))
+            .collect();
+
+        assert_eq!(expected, recorder.events);
+    });
+}
+
+#[test]
+fn traversal_walk_in_order() {
+    struct InOrder(Vec<String>);
+
+    impl<'a> Visitor<'a, String> for InOrder {
+        fn in_order(&mut self, visit: Visit<'a, String>) { self.0.push(visit.element.clone()); }
+    }
+
+    with_tree(TREE, |token, tree| {
+        let mut visitor = InOrder(Vec::new());
+        tree.walk(&mut visitor, token);
+
+        let expected: Vec<String> = tree.iter(token).cloned().collect();
+
+        assert_eq!(expected, visitor.0);
+    });
+}
+
+} // mod tests